@@ -1,14 +1,36 @@
 use std::{
-    collections::HashMap,
+    collections::BTreeMap,
     env, fs,
-    path::{Path, PathBuf},
+    path::{Component, Path, PathBuf},
 };
 
 use anyhow::{Context, Result, bail};
 use serde::Serialize;
+use serde_yaml::{Mapping, Value};
 use toml_edit::{Array, DocumentMut, Item};
 
+#[path = "src/skill/agent_config.rs"]
+mod agent_config;
+#[path = "src/skill/limits.rs"]
+mod limits;
+
+use agent_config::{KNOWN_AGENT_CONFIGS, ensure_agent_config_valid};
+use limits::{MAX_DESCRIPTION_CHARS, MAX_NAME_CHARS, ensure_max_chars};
+
 const BUILTIN_MANIFEST: &str = ".agents/skills/builtin-manifest.toml";
+const ALLOWED_FRONTMATTER_KEYS: [&str; 11] = [
+    "name",
+    "description",
+    "requires",
+    "version",
+    "license",
+    "allowed-tools",
+    "capabilities",
+    "tags",
+    "deprecated",
+    "deprecation_note",
+    "replacement",
+];
 
 fn main() {
     if let Err(error) = run() {
@@ -23,12 +45,13 @@ fn run() -> Result<()> {
     let manifest = load_manifest(manifest_path)?;
 
     let mut catalog_skills = Vec::with_capacity(manifest.len());
-    for name in manifest {
+    for (order, name) in manifest.into_iter().enumerate() {
         validate_skill_name(&name)?;
         let skill_root = Path::new(".agents/skills").join(&name);
         println!("cargo:rerun-if-changed={}", skill_root.display());
 
-        let skill = read_skill_definition(&name, &skill_root)?;
+        let mut skill = read_skill_definition(&name, &skill_root)?;
+        skill.order = order;
         catalog_skills.push(skill);
     }
 
@@ -96,11 +119,13 @@ fn read_skill_definition(name: &str, skill_root: &Path) -> Result<BuiltinSkillJs
 
     let skill_md = skill_root.join("SKILL.md");
     let skill_source = fs::read_to_string(&skill_md)
-        .with_context(|| format!("failed to read `{}`", skill_md.display()))?;
+        .with_context(|| format!("failed to read `{}`", skill_md.display()))?
+        .replace("\r\n", "\n");
     let metadata = parse_skill_metadata(&skill_source)?;
 
     let parsed_name = metadata
         .get("name")
+        .and_then(Value::as_str)
         .ok_or_else(|| anyhow::anyhow!("missing required `name` in frontmatter"))?;
     if parsed_name != name {
         bail!("manifest entry `{name}` does not match SKILL.md frontmatter `name: {parsed_name}`");
@@ -108,21 +133,69 @@ fn read_skill_definition(name: &str, skill_root: &Path) -> Result<BuiltinSkillJs
 
     let description = metadata
         .get("description")
+        .and_then(Value::as_str)
         .ok_or_else(|| anyhow::anyhow!("missing required `description` in frontmatter"))?;
+    let description = resolve_description(skill_root, description)?;
     if description.trim().is_empty() {
         bail!("skill `{name}` frontmatter `description` cannot be empty");
     }
+    ensure_max_chars(
+        &format!("skill `{name}` description"),
+        description.trim(),
+        MAX_DESCRIPTION_CHARS,
+    )?;
+
+    let capabilities = parse_string_list(&metadata, "capabilities")?;
+    let tags = parse_string_list(&metadata, "tags")?;
+    let deprecation = parse_deprecation(&metadata)?;
+    parse_string_list(&metadata, "allowed-tools")?;
+    if let Some(requires) = metadata.get("requires")
+        && !requires
+            .as_sequence()
+            .is_some_and(|entries| entries.iter().all(Value::is_string))
+    {
+        bail!("skill `{name}` frontmatter `requires` must be a list of strings");
+    }
+    if metadata
+        .get("license")
+        .is_some_and(|license| !license.is_string())
+    {
+        bail!("skill `{name}` frontmatter `license` must be a string");
+    }
+    let version = match metadata.get("version") {
+        None | Some(Value::Null) => None,
+        Some(Value::String(version)) => Some(version.trim().to_owned()),
+        Some(_) => bail!("skill `{name}` frontmatter `version` must be a string"),
+    };
+    if let Some(version) = &version
+        && !is_semver(version)
+    {
+        bail!("skill `{name}` has invalid version `{version}`; expected `MAJOR.MINOR.PATCH`");
+    }
+    let mut descriptions = BTreeMap::new();
+    for (key, value) in &metadata {
+        let Some(lang) = key.as_str().and_then(localized_description_lang) else {
+            continue;
+        };
+        let localized = value
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("frontmatter `description.{lang}` must be a string"))?;
+        let localized = resolve_description(skill_root, localized.trim())?;
+        if localized.trim().is_empty() {
+            bail!("skill `{name}` frontmatter `description.{lang}` cannot be empty");
+        }
+        descriptions.insert(lang.to_owned(), localized.trim().to_owned());
+    }
 
-    let openai_yaml = skill_root.join("agents/openai.yaml");
-    if openai_yaml.exists() {
-        let openai_text = fs::read_to_string(&openai_yaml)
-            .with_context(|| format!("failed to read `{}`", openai_yaml.display()))?;
-        if !openai_text.contains("interface:") {
-            bail!(
-                "`{}` exists but does not contain `interface:`",
-                openai_yaml.display()
-            );
+    for config in KNOWN_AGENT_CONFIGS {
+        let config_path = skill_root.join("agents").join(config);
+        if !config_path.exists() {
+            continue;
         }
+        let config_text = fs::read_to_string(&config_path)
+            .with_context(|| format!("failed to read `{}`", config_path.display()))?;
+        ensure_agent_config_valid(&config_text)
+            .with_context(|| format!("invalid agent config `{}`", config_path.display()))?;
     }
 
     let mut files = Vec::new();
@@ -133,12 +206,100 @@ fn read_skill_definition(name: &str, skill_root: &Path) -> Result<BuiltinSkillJs
 
     Ok(BuiltinSkillJson {
         name: name.to_owned(),
-        description: description.to_owned(),
+        order: 0,
+        description: description.trim().to_owned(),
+        version,
+        capabilities,
+        tags,
+        descriptions,
+        deprecation,
         files,
     })
 }
 
-fn parse_skill_metadata(source: &str) -> Result<HashMap<String, String>> {
+/// Language tag of a `description.<lang>` key.
+fn localized_description_lang(key: &str) -> Option<&str> {
+    key.strip_prefix("description.").filter(|lang| {
+        !lang.is_empty()
+            && lang
+                .chars()
+                .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+    })
+}
+
+/// `MAJOR.MINOR.PATCH` with numeric components; mirrors `SkillVersion::parse`.
+fn is_semver(version: &str) -> bool {
+    let parts = version.split('.').collect::<Vec<_>>();
+    parts.len() == 3
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(|ch| ch.is_ascii_digit()))
+}
+
+fn parse_string_list(metadata: &Mapping, key: &str) -> Result<Vec<String>> {
+    let Some(value) = metadata.get(key) else {
+        return Ok(Vec::new());
+    };
+    let Some(entries) = value.as_sequence() else {
+        bail!("frontmatter `{key}` must be a list of strings");
+    };
+    entries
+        .iter()
+        .map(|entry| match entry.as_str().map(str::trim) {
+            Some(entry) if !entry.is_empty() => Ok(entry.to_owned()),
+            _ => bail!("frontmatter `{key}` must contain non-empty strings"),
+        })
+        .collect()
+}
+
+fn parse_deprecation(metadata: &Mapping) -> Result<Option<BuiltinSkillDeprecationJson>> {
+    let deprecated = match metadata.get("deprecated") {
+        None => false,
+        Some(value) => value
+            .as_bool()
+            .ok_or_else(|| anyhow::anyhow!("frontmatter `deprecated` must be a boolean"))?,
+    };
+    let optional = |key: &str| -> Result<Option<String>> {
+        match metadata.get(key) {
+            None => Ok(None),
+            Some(value) => value
+                .as_str()
+                .map(|value| Some(value.trim().to_owned()))
+                .ok_or_else(|| anyhow::anyhow!("frontmatter `{key}` must be a string")),
+        }
+    };
+    let note = optional("deprecation_note")?;
+    let replacement = optional("replacement")?;
+    if !deprecated {
+        if note.is_some() || replacement.is_some() {
+            bail!("frontmatter `deprecation_note` and `replacement` require `deprecated: true`");
+        }
+        return Ok(None);
+    }
+    Ok(Some(BuiltinSkillDeprecationJson { note, replacement }))
+}
+
+fn resolve_description(skill_root: &Path, description: &str) -> Result<String> {
+    let Some(relative) = description.strip_prefix('@') else {
+        return Ok(description.to_owned());
+    };
+    let relative_path = Path::new(relative);
+    if relative.is_empty()
+        || !relative_path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+    {
+        bail!("frontmatter `description` reference `{description}` must be a relative file path");
+    }
+
+    let path = skill_root.join(relative_path);
+    println!("cargo:rerun-if-changed={}", path.display());
+    let text = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read description file `{}`", path.display()))?;
+    Ok(text.trim().to_owned())
+}
+
+fn parse_skill_metadata(source: &str) -> Result<Mapping> {
     let frontmatter = extract_frontmatter(source)?;
     let metadata = parse_frontmatter_map(frontmatter)?;
     validate_frontmatter_keys(&metadata)?;
@@ -161,39 +322,25 @@ fn extract_frontmatter(source: &str) -> Result<&str> {
     bail!("SKILL.md is missing closing YAML frontmatter marker `---`")
 }
 
-fn parse_frontmatter_map(frontmatter: &str) -> Result<HashMap<String, String>> {
-    let mut map = HashMap::new();
-    for (index, raw_line) in frontmatter.lines().enumerate() {
-        let line = raw_line.trim();
-        if line.is_empty() || line.starts_with('#') {
-            continue;
-        }
-
-        let Some((raw_key, raw_value)) = line.split_once(':') else {
-            bail!("invalid frontmatter line {}: `{}`", index + 1, raw_line);
-        };
-        let key = raw_key.trim();
-        let value = raw_value.trim();
-        if key.is_empty() {
-            bail!("invalid frontmatter line {}: empty key", index + 1);
-        }
-        if value.is_empty() {
-            bail!("invalid frontmatter line {}: empty value", index + 1);
-        }
-
-        let value = value.trim_matches('"').trim_matches('\'').trim().to_owned();
-        map.insert(key.to_owned(), value);
+fn parse_frontmatter_map(frontmatter: &str) -> Result<Mapping> {
+    let value = serde_yaml::from_str::<Value>(frontmatter)
+        .context("failed to parse SKILL.md frontmatter as YAML")?;
+    match value {
+        Value::Mapping(map) => Ok(map),
+        Value::Null => Ok(Mapping::new()),
+        _ => bail!("SKILL.md frontmatter must be a YAML mapping"),
     }
-
-    Ok(map)
 }
 
-fn validate_frontmatter_keys(metadata: &HashMap<String, String>) -> Result<()> {
+fn validate_frontmatter_keys(metadata: &Mapping) -> Result<()> {
     for key in metadata.keys() {
-        if key == "name" || key == "description" {
+        let Some(key) = key.as_str() else {
+            bail!("frontmatter keys must be strings");
+        };
+        if ALLOWED_FRONTMATTER_KEYS.contains(&key) || localized_description_lang(key).is_some() {
             continue;
         }
-        bail!("unexpected frontmatter key `{key}`; allowed keys are `name` and `description`");
+        bail!("unexpected frontmatter key `{key}`");
     }
     Ok(())
 }
@@ -233,15 +380,31 @@ fn collect_skill_files(
         files.push(BuiltinSkillFileJson {
             path: relative_path,
             content,
+            executable: is_executable(&path)?,
         });
     }
     Ok(())
 }
 
+#[cfg(unix)]
+fn is_executable(path: &Path) -> Result<bool> {
+    use std::os::unix::fs::PermissionsExt;
+
+    let metadata =
+        fs::metadata(path).with_context(|| format!("failed to stat `{}`", path.display()))?;
+    Ok(metadata.permissions().mode() & 0o111 != 0)
+}
+
+#[cfg(not(unix))]
+fn is_executable(_path: &Path) -> Result<bool> {
+    Ok(false)
+}
+
 fn validate_skill_name(name: &str) -> Result<()> {
-    if name.is_empty() || name.len() > 63 {
-        bail!("skill name must be between 1 and 63 characters");
+    if name.is_empty() {
+        bail!("skill name cannot be empty");
     }
+    ensure_max_chars("skill name", name, MAX_NAME_CHARS)?;
     if name.starts_with('-') || name.ends_with('-') || name.contains("--") {
         bail!("skill name must not start/end with `-` or contain consecutive `-`");
     }
@@ -263,12 +426,30 @@ struct BuiltinCatalogJson {
 #[derive(Debug, Serialize)]
 struct BuiltinSkillJson {
     name: String,
+    /// Index of the skill in `builtin-manifest.toml`.
+    order: usize,
     description: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    version: Option<String>,
+    capabilities: Vec<String>,
+    tags: Vec<String>,
+    descriptions: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    deprecation: Option<BuiltinSkillDeprecationJson>,
     files: Vec<BuiltinSkillFileJson>,
 }
 
+#[derive(Debug, Serialize)]
+struct BuiltinSkillDeprecationJson {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    replacement: Option<String>,
+}
+
 #[derive(Debug, Serialize)]
 struct BuiltinSkillFileJson {
     path: String,
     content: String,
+    executable: bool,
 }
@@ -23,6 +23,7 @@ Use `skill` to initialize/create/validate local skills.",
   agx skill validate\n\
   agx skill validate ask-user-question\n\
   agx skill list --format json\n\
+  agx skill which ask-user-question\n\
   agx skill install ask-user-question"
 )]
 pub struct Cli {
@@ -38,7 +39,9 @@ pub enum Command {
         long_about = "Initialize, create, and revise RFC markdown files.\n\n\
 `rfc init` scaffolds project RFC folders and seeds the RFC template from the binary.\n\
 `rfc new` creates a new RFC from the resolved template source.\n\
-`rfc revise` updates an existing RFC in place."
+`rfc revise` updates an existing RFC in place.\n\
+`rfc validate` checks RFC metadata and references.\n\
+`rfc stats` reports RFC body size metrics."
     )]
     Rfc(RfcArgs),
 
@@ -48,6 +51,7 @@ pub enum Command {
         long_about = "Manage workspace and built-in skills.\n\n\
 Use `skill init` to scaffold `.agents/skills` and seed built-in skills (use `--no-dump` for create-only).\n\
 Use `skill new` to create a new skill scaffold.\n\
+Use `skill edit` to open a workspace skill in `$EDITOR`.\n\
 Use `skill validate` to validate one or more skills.\n\
 Use `skill list` to discover built-in and workspace skills.\n\
 Use `skill dump`, `skill install`, and `skill export` to materialize or package built-in skills."
@@ -78,25 +82,454 @@ Use `agx skill dump --all` to materialize built-in skills first.",
         name = "new",
         about = "Create a new RFC markdown file with TOML metadata",
         long_about = "Create a new RFC markdown file with TOML metadata.\n\n\
-Creates a new RFC file from `rfc/0000-template.md` when present, or falls back to the embedded template.",
+Creates a new RFC file from `rfc/0000-template.md` when present, or falls back to the embedded template.\n\
+Templates can use `{{ project_name }}` and `{{ status }}`; `[rfc] sections` and `[rfc] id_width` in `.agx.toml`\n\
+customize the embedded template body and the zero-padded id width (4 by default).\n\
+Metadata timestamps use the current time unless `AGX_NOW` (RFC 3339) or `SOURCE_DATE_EPOCH` (Unix seconds) is set.",
         after_help = "Examples:\n\
   agx rfc new --author Roger --title \"Add parser support\"\n\
-  agx rfc new --author Roger --title_parts parser support",
+  agx rfc new --author Roger --title_parts parser support\n\
+  agx rfc new --author Roger --abstract \"Parse X with Y.\" \"Add parser support\"\n\
+  agx rfc new --no-template \"Quick draft\"\n\
+  agx rfc new --id 5 \"Reserved proposal\"\n\
+  agx rfc new --from-issue https://github.com/org/repo/issues/42 --fetch\n\
+  agx rfc new --allow-duplicate-title --slug parser-support-cli \"Add parser support\"\n\
+  agx rfc new --draft \"Early idea\"\n\
+  agx rfc new --label parser --label frontend \"Add parser support\"\n\
+  agx rfc new --supersedes latest \"Parser support v2\"\n\
+  agx rfc new --supersedes 3 --link-back \"Parser support v3\"\n\
+  agx rfc new --from 0003 \"Parser support v4\"\n\
+  agx rfc new --link-inline \"Replace [[0003]] parser\"",
         override_usage = "agx rfc new [options] <title>"
     )]
-    New(RfcEditArgs),
+    New(Box<RfcEditArgs>),
 
     #[command(
         name = "revise",
         about = "Revise an existing RFC markdown file in place",
         long_about = "Revise an existing RFC markdown file in place.\n\n\
-Accepts the same options and input shape as `rfc new`, but the positional argument selects an existing RFC.",
+Accepts the same options and input shape as `rfc new`, but the positional argument selects an existing RFC.\n\
+Use `latest` (or `last`) to select the highest-numbered RFC.\n\
+`--change` sets the recorded revision message (default `Revised`, or `[rfc] revised_change` in `.agx.toml`).\n\
+The revising author (first `--author`, else git `user.name`) is recorded as the revision `by` and top-level `updated_by`.\n\
+`--remove-author`/`--remove-agent` drop entries (warning when absent); an RFC must keep at least one author.\n\
+`--label` appends to the `labels` array, skipping labels already present. Other frontmatter keys are kept as written.\n\
+Use `--body-file` to replace the whole body; the `# RFC NNNN: title` heading is rewritten to match the metadata.\n\
+Retitling keeps the file name; pass `--rename` to move it to the new slug, or `--keep-filename` to silence the stale-slug warning.\n\
+RFCs whose `status` is frozen (`accepted`/`final`, or `[rfc] frozen_statuses` in `.agx.toml`) require `--allow-frozen`.\n\
+Status changes must move forward (draft -> proposed -> accepted -> final) or close the RFC as rejected/withdrawn;\n\
+pass `--force` (or `--allow-backward`) to move backwards or reopen a closed RFC.\n\
+A revise that changes no metadata or body is skipped with a \"no changes\" note; `--change` or `--force` records the revision anyway.\n\
+`--force` implies both `--allow-backward` and `--force-revision`; use those to override only one check.",
         after_help = "Examples:\n\
   agx rfc revise 0001\n\
-  agx rfc revise --title \"Updated RFC title\" 0001",
+  agx rfc revise latest\n\
+  agx rfc revise --final 0001\n\
+  agx rfc revise --author Alice --remove-author Bob 0001\n\
+  agx rfc revise --label security --label api 0001\n\
+  agx rfc revise --change \"Incorporated security feedback\" 0001\n\
+  agx rfc revise --body-file regenerated.md 0001\n\
+  agx rfc revise --title \"Updated RFC title\" 0001\n\
+  agx rfc revise --title \"Updated RFC title\" --rename 0001",
         override_usage = "agx rfc revise [options] <title>"
     )]
-    Revise(RfcEditArgs),
+    Revise(Box<RfcEditArgs>),
+
+    #[command(
+        name = "validate",
+        about = "Validate RFC markdown files under rfc",
+        long_about = "Validate RFC markdown files under `rfc`.\n\n\
+Checks that every RFC has parseable TOML frontmatter with `rfc`, `title`, and `created` fields and at least one author,\n\
+RFC 3339 timestamps, and a `# RFC NNNN: title` heading and file name id that match the metadata.\n\
+Every `## Section` of the template `rfc new` uses (`[rfc] sections` in `.agx.toml`, else `rfc/0000-template.md`,\n\
+else the embedded template) must appear as a heading, and metadata references must name RFC ids with a file.\n\
+Every problem in every file is reported before exiting non-zero.\n\
+Use `--fix` to backfill `created`, normalize parseable timestamps, rewrite mismatched headings, and prune dangling references.\n\
+A file name id that disagrees with `rfc` is fixed by renaming the file; pass `--id-source filename` to rewrite `rfc` instead.\n\
+`--format json` prints `{ schema_version, total, passed, failed, results: [{ path, ok, problems }] }`.",
+        after_help = "Examples:\n\
+  agx rfc validate\n\
+  agx rfc validate --fix\n\
+  agx rfc validate --fix --id-source filename\n\
+  agx rfc validate --format json"
+    )]
+    Validate(RfcValidateArgs),
+
+    #[command(
+        name = "stats",
+        about = "Report RFC body size metrics",
+        long_about = "Report RFC body size metrics.\n\n\
+Counts body words (frontmatter excluded) and `##` sections across all RFCs.\n\
+Use `--per-rfc` for one row per RFC and `--format json` for machine-readable output.",
+        after_help = "Examples:\n\
+  agx rfc stats\n\
+  agx rfc stats --per-rfc\n\
+  agx rfc stats --per-rfc --format json"
+    )]
+    Stats(RfcStatsArgs),
+
+    #[command(
+        name = "referrers",
+        about = "List RFCs that reference a given RFC",
+        long_about = "List RFCs that reference a given RFC.\n\n\
+Scans `prerequisite`, `supersedes`, and `superseded_by` metadata across all RFCs and groups matches by relation.\n\
+The target may be an RFC id or title.",
+        after_help = "Examples:\n\
+  agx rfc referrers 0001\n\
+  agx rfc referrers \"Add parser support\"\n\
+  agx rfc referrers 1 --format json"
+    )]
+    Referrers(RfcReferrersArgs),
+
+    #[command(
+        name = "index",
+        about = "Generate rfc/README.md listing every RFC",
+        long_about = "Generate `rfc/README.md` listing every RFC.\n\n\
+Writes a markdown table of id, linked title, status, and authors sorted by id. Regenerating is idempotent.\n\
+Content above the `<!-- Generated by ... -->` marker line in an existing file is preserved.\n\
+Use `--stdout` to print the index instead of writing the file, or `--check` to fail when the file is out of date.",
+        after_help = "Examples:\n\
+  agx rfc index\n\
+  agx rfc index --stdout\n\
+  agx rfc index --check"
+    )]
+    Index(RfcIndexArgs),
+
+    #[command(
+        name = "changelog",
+        about = "Print a changelog built from every RFC's revision history",
+        long_about = "Print a changelog built from every RFC's revision history.\n\n\
+Collects `[[revision]]` entries (date, change, and optional `by`) across all RFCs and sorts them by date.\n\
+Markdown output is grouped by day, or by RFC with `--group-by rfc`. Use `--since` to drop older entries.",
+        after_help = "Examples:\n\
+  agx rfc changelog\n\
+  agx rfc changelog --group-by rfc\n\
+  agx rfc changelog --since 2025-01-01 --format json"
+    )]
+    Changelog(RfcChangelogArgs),
+
+    #[command(
+        name = "set-status",
+        about = "Set the status of every RFC matching a query",
+        long_about = "Set the status of every RFC matching a query.\n\n\
+Selects RFCs whose string metadata matches every `--where key=value` predicate and whose `authors` include every\n\
+`--author`, then sets `status` and appends a revision entry to each. RFCs already at the status are left untouched.\n\
+Use `--dry-run` to report the planned transitions without writing.",
+        after_help = "Examples:\n\
+  agx rfc set-status accepted --where status=draft --author Roger\n\
+  agx rfc set-status final --where status=accepted --dry-run"
+    )]
+    SetStatus(RfcSetStatusArgs),
+
+    #[command(
+        name = "env",
+        about = "Print an RFC's metadata as shell variable assignments",
+        long_about = "Print an RFC's metadata as shell variable assignments.\n\n\
+Emits `AGX_RFC_ID`, `AGX_RFC_TITLE`, `AGX_RFC_STATUS`, `AGX_RFC_AUTHORS` (comma-separated), and `AGX_RFC_PATH`\n\
+as `NAME=value` lines, shell-quoted for `eval`. Missing fields are empty strings.\n\
+The selector accepts an RFC id, file name, path, title, or `latest`.",
+        after_help = "Examples:\n\
+  agx rfc env 0001\n\
+  eval \"$(agx rfc env latest)\"\n\
+  agx rfc env 0001 --format json"
+    )]
+    Env(RfcEnvArgs),
+
+    #[command(
+        name = "list",
+        about = "List existing RFCs",
+        long_about = "List existing RFCs.\n\n\
+Prints id, title, authors, `last_updated`, and `updated_by` for every RFC in the project RFC directory, sorted by id.\n\
+Files that fail to parse are reported on stderr and skipped.\n\
+`--label <name>` keeps only RFCs whose `labels` include that label, ignoring case; an unknown label lists nothing.\n\
+`--format json` prints `{ schema_version, rfcs: [...] }`.",
+        after_help = "Examples:\n\
+  agx rfc list\n\
+  agx rfc list --label parser\n\
+  agx rfc list --format json"
+    )]
+    List(RfcListArgs),
+
+    #[command(
+        name = "board",
+        about = "Print a markdown checklist of RFCs grouped by status",
+        long_about = "Print a markdown checklist of RFCs grouped by status.\n\n\
+Renders one `## <status>` section per status with `- [ ] NNNN: Title` items; `accepted` and `final` RFCs are checked.\n\
+RFCs without a `status` are listed under `unset`. `--format json` reports the same groups.",
+        after_help = "Examples:\n\
+  agx rfc board\n\
+  agx rfc board --format json"
+    )]
+    Board(RfcBoardArgs),
+
+    #[command(
+        name = "show",
+        about = "Print one RFC's metadata and body",
+        long_about = "Print one RFC's metadata and body.\n\n\
+Prints each frontmatter field as `key: value`, the revision history, and then the markdown body.\n\
+The selector is resolved exactly like `rfc revise`: an RFC id, file name, path, title, or `latest`.\n\
+`--format json` prints `{ schema_version, path, metadata, body }` with the full frontmatter as JSON.",
+        after_help = "Examples:\n\
+  agx rfc show 0001\n\
+  agx rfc show latest\n\
+  agx rfc show 0001 --format json"
+    )]
+    Show(RfcShowArgs),
+
+    #[command(
+        name = "graph",
+        about = "Print the RFC reference graph as Graphviz DOT or Mermaid",
+        long_about = "Print the RFC reference graph as Graphviz DOT or Mermaid.\n\n\
+Every RFC becomes a `NNNN: Title` node with an edge for each id in its `prerequisite`, `supersedes`, and `superseded_by` fields, labeled by field.\n\
+`supersedes` edges are dashed and `superseded_by` edges dotted; prerequisite edges are solid.\n\
+`--format mermaid` prints a `graph LR` flowchart for embedding in markdown.",
+        after_help = "Examples:\n\
+  agx rfc graph > rfcs.dot\n\
+  agx rfc graph | dot -Tsvg -o rfcs.svg\n\
+  agx rfc graph --format mermaid"
+    )]
+    Graph(RfcGraphArgs),
+
+    #[command(
+        name = "renumber",
+        about = "Rename RFC files to match their ids and optionally close id gaps",
+        long_about = "Rename RFC files to match their ids and optionally close id gaps.\n\n\
+Reports every RFC whose file name prefix disagrees with its `rfc` id and plans a rename to the metadata id.\n\
+`--close-gaps` also renumbers every RFC to 1..N in id order, rewriting `rfc` and the `# RFC NNNN: title` heading.\n\
+References to a changed id in `prerequisite`, `supersedes`, `superseded_by`, and `[RFC NNNN](file.md)` body links\n\
+are updated, and each changed RFC gets a revision entry.\n\
+Nothing is written without `--apply`. Duplicate ids (without `--close-gaps`) or renames onto an unrelated file\n\
+abort before any file changes. Run `agx rfc index` afterwards to refresh `rfc/README.md`.",
+        after_help = "Examples:\n\
+  agx rfc renumber\n\
+  agx rfc renumber --apply\n\
+  agx rfc renumber --close-gaps --apply"
+    )]
+    Renumber(RfcRenumberArgs),
+}
+
+#[derive(Debug, Args)]
+pub struct RfcGraphArgs {
+    /// Output format.
+    #[arg(long = "format", value_enum, default_value_t = RfcGraphFormat::Dot)]
+    pub format: RfcGraphFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcGraphFormat {
+    Dot,
+    Mermaid,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcRenumberArgs {
+    /// Renumber every RFC to 1..N in id order, removing gaps.
+    #[arg(long = "close-gaps", action = ArgAction::SetTrue)]
+    pub close_gaps: bool,
+
+    /// Write the planned renames and id changes instead of only printing them.
+    #[arg(long = "apply", action = ArgAction::SetTrue)]
+    pub apply: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcShowArgs {
+    /// RFC to show (id, file name, path, title, or `latest`).
+    #[arg(value_name = "rfc")]
+    pub rfc: String,
+
+    /// Output format.
+    #[arg(long = "format", value_enum, default_value_t = RfcShowFormat::Text)]
+    pub format: RfcShowFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcShowFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcBoardArgs {
+    /// Output format for the board.
+    #[arg(long = "format", value_enum, default_value_t = RfcBoardFormat::Markdown)]
+    pub format: RfcBoardFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcBoardFormat {
+    Markdown,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcListArgs {
+    /// Output format for the RFC listing.
+    #[arg(long = "format", value_enum, default_value_t = RfcListFormat::Text)]
+    pub format: RfcListFormat,
+
+    /// Only list RFCs whose `labels` include this label (case-insensitive).
+    #[arg(long = "label", value_name = "name")]
+    pub label: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcListFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcEnvArgs {
+    /// RFC to export (id, file name, path, title, or `latest`).
+    #[arg(value_name = "rfc")]
+    pub rfc: String,
+
+    /// Output format for the variables.
+    #[arg(long = "format", value_enum, default_value_t = RfcEnvFormat::Text)]
+    pub format: RfcEnvFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcEnvFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcSetStatusArgs {
+    /// Status to set on matching RFCs.
+    #[arg(value_name = "status")]
+    pub status: String,
+
+    /// Match RFCs whose metadata `key` equals `value` (repeatable).
+    #[arg(long = "where", value_name = "key=value", action = ArgAction::Append)]
+    pub predicates: Vec<String>,
+
+    /// Match RFCs listing this author (repeatable).
+    #[arg(long = "author", value_name = "name", action = ArgAction::Append)]
+    pub authors: Vec<String>,
+
+    /// Report planned transitions without writing files.
+    #[arg(long = "dry-run", action = ArgAction::SetTrue)]
+    pub dry_run: bool,
+
+    /// Allow transitions that move backwards or reopen a closed RFC.
+    #[arg(long = "allow-backward", visible_alias = "force", action = ArgAction::SetTrue)]
+    pub allow_backward: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcChangelogArgs {
+    /// Keep only revisions dated on or after this date (RFC 3339 or `YYYY-MM-DD`).
+    #[arg(long = "since", value_name = "date")]
+    pub since: Option<String>,
+
+    /// How to group markdown output.
+    #[arg(long = "group-by", value_enum, default_value_t = RfcChangelogGroup::Date)]
+    pub group_by: RfcChangelogGroup,
+
+    /// Output format for the changelog.
+    #[arg(long = "format", value_enum, default_value_t = RfcChangelogFormat::Text)]
+    pub format: RfcChangelogFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcChangelogGroup {
+    Date,
+    Rfc,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcChangelogFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcIndexArgs {
+    /// Print the index to stdout instead of writing `rfc/README.md`.
+    #[arg(long = "stdout", action = ArgAction::SetTrue)]
+    pub stdout: bool,
+
+    /// Exit non-zero when `rfc/README.md` differs from the regenerated index, without writing it.
+    #[arg(long = "check", action = ArgAction::SetTrue, conflicts_with = "stdout")]
+    pub check: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcReferrersArgs {
+    /// RFC to find referrers for (id or title).
+    #[arg(value_name = "rfc id or title")]
+    pub rfc: RfcReference,
+
+    /// Output format for referrers.
+    #[arg(long = "format", value_enum, default_value_t = RfcReferrersFormat::Text)]
+    pub format: RfcReferrersFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcReferrersFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcStatsArgs {
+    /// Report metrics for each RFC instead of only the aggregate.
+    #[arg(long = "per-rfc", action = ArgAction::SetTrue)]
+    pub per_rfc: bool,
+
+    /// Output format for RFC metrics.
+    #[arg(long = "format", value_enum, default_value_t = RfcStatsFormat::Text)]
+    pub format: RfcStatsFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcStatsFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct RfcValidateArgs {
+    /// Accepted for compatibility; references are always checked.
+    #[arg(long = "check-refs", action = ArgAction::SetTrue, hide = true)]
+    pub check_refs: bool,
+
+    /// Repair fixable problems: reconcile file name ids, normalize timestamps, rewrite headings, and remove dangling references.
+    #[arg(long = "fix", action = ArgAction::SetTrue)]
+    pub fix: bool,
+
+    /// With `--fix`, which id wins when a file name prefix and the `rfc` field disagree.
+    #[arg(
+        long = "id-source",
+        value_enum,
+        default_value_t = RfcIdSource::Metadata,
+        requires = "fix"
+    )]
+    pub id_source: RfcIdSource,
+
+    /// Output format. `json` reports per-file problems for CI and cannot be combined with `--fix`.
+    #[arg(
+        long = "format",
+        value_enum,
+        default_value_t = RfcValidateFormat::Text,
+        conflicts_with = "fix"
+    )]
+    pub format: RfcValidateFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcValidateFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcIdSource {
+    Metadata,
+    Filename,
 }
 
 #[derive(Debug, Args)]
@@ -123,20 +556,96 @@ Use `--no-dump` to only create the directory without dumping built-in skills.",
         name = "new",
         about = "Create a new skill scaffold under .agents/skills",
         long_about = "Create a new skill scaffold under `.agents/skills`.\n\n\
-Creates `.agents/skills/<name>` with `SKILL.md` and `agents/openai.yaml`.",
+Creates `.agents/skills/<name>` with `SKILL.md` and `agents/openai.yaml`.\n\
+Use `--agent-format` (repeatable: openai, claude, cursor, gemini) to scaffold `agents/<format>.yaml` configs instead.\n\
+Invalid names are rejected with a suggested name; pass `--normalize` to use the suggestion.\n\
+Use `--prompt` to set `default_prompt`; `{name}` and `{title}` expand to the skill name and display title.\n\
+Use `--global` to scaffold under the user-global skills directory (`~/.config/agx/skills` on Linux) instead.",
         after_help = "Examples:\n\
-  agx skill new ask-user-question"
+  agx skill new ask-user-question\n\
+  agx skill new \"Ask User Question\" --normalize\n\
+  agx skill new triage --prompt \"Use ${name} to triage {title} issues.\"\n\
+  agx skill new triage --agent-format claude --agent-format cursor\n\
+  agx skill new personal-notes --global"
     )]
     New(SkillNewArgs),
 
+    #[command(
+        name = "edit",
+        about = "Open a workspace skill in $EDITOR",
+        long_about = "Open a workspace skill in `$EDITOR`.\n\n\
+Opens `.agents/skills/<name>/SKILL.md` after checking its frontmatter parses.\n\
+Use `--dir` to open the whole skill directory for editors that support it.",
+        after_help = "Examples:\n\
+  agx skill edit ask-user-question\n\
+  agx skill edit ask-user-question --dir"
+    )]
+    Edit(SkillEditArgs),
+
+    #[command(
+        name = "remove",
+        about = "Delete a workspace skill directory",
+        long_about = "Delete a workspace skill directory.\n\n\
+Removes `.agents/skills/<name>` after checking the name is valid and the directory contains `SKILL.md`,\n\
+then prints the removed path. Directories that resolve outside `.agents/skills` (for example through a symlink)\n\
+are refused. Without `--force` the removal is confirmed interactively; `--force` is required when stdin is not a terminal.",
+        after_help = "Examples:\n\
+  agx skill remove old-skill\n\
+  agx skill remove old-skill --force"
+    )]
+    Remove(SkillRemoveArgs),
+
+    #[command(
+        name = "rename",
+        about = "Rename a workspace skill and update its metadata",
+        long_about = "Rename a workspace skill and update its metadata.\n\n\
+Moves `.agents/skills/<old>` to `.agents/skills/<new>` and rewrites the `name:` field in `SKILL.md`.\n\
+In `agents/*.yaml`, `$<old>` mentions in `default_prompt` become `$<new>`, and a `display_name` still matching\n\
+the scaffolded title is retitled. `<new>` must be a valid skill name that does not exist yet.",
+        after_help = "Examples:\n\
+  agx skill rename triage issue-triage"
+    )]
+    Rename(SkillRenameArgs),
+
+    #[command(
+        name = "version",
+        about = "Bump or set a workspace skill's version",
+        long_about = "Bump or set a workspace skill's version.\n\n\
+Rewrites the semver `version` field in `.agents/skills/<name>/SKILL.md` frontmatter and any matching\n\
+`version` entries in `agents/*.yaml`. `--bump` requires an existing version; `--set` writes one explicitly.",
+        after_help = "Examples:\n\
+  agx skill version my-skill --bump patch\n\
+  agx skill version my-skill --bump minor\n\
+  agx skill version my-skill --set 1.0.0"
+    )]
+    Version(SkillVersionArgs),
+
     #[command(
         name = "validate",
         about = "Validate one skill or all skills under .agents/skills",
         long_about = "Validate one skill or all skills under `.agents/skills`.\n\n\
-Defaults to all skills when no name is provided.",
+Defaults to all skills when no name is provided.\n\
+Use `--check-skill-refs` to warn about `$name` references to unknown skills.\n\
+Use `--check-requires` to verify tools listed in frontmatter `requires` are on `PATH`.\n\
+Use `--check-eol` to flag files with CRLF line endings (frontmatter parsing accepts them either way).\n\
+Use `--check-links` to verify relative links in the `SKILL.md` body resolve to files inside the skill directory; URLs are ignored.\n\
+Use `--strict` to reject files in `agents/` other than known agent configs (`openai.yaml`, `claude.yaml`, `cursor.yaml`, `gemini.yaml`).\n\
+Use `--schema <file>` to check frontmatter against a JSON Schema; it replaces the built-in allowed-keys check.\n\
+Every enabled check runs, so each skill reports all of its problems rather than the first.\n\
+Always ends with a `validated N skill(s), M failed` summary; `--format json` reports totals and per-skill `errors`.\n\
+`--format ndjson` prints one JSON object per skill per line, then a summary object with `schema_version`.\n\
+Set `AGX_SKILLS_PATH` to search several skills roots in precedence order instead of `.agents/skills`.",
         after_help = "Examples:\n\
   agx skill validate\n\
-  agx skill validate ask-user-question"
+  agx skill validate ask-user-question\n\
+  agx skill validate --check-skill-refs\n\
+  agx skill validate --check-requires\n\
+  agx skill validate --check-eol\n\
+  agx skill validate --check-links\n\
+  agx skill validate --strict\n\
+  agx skill validate --schema skill-schema.json\n\
+  agx skill validate --format json\n\
+  agx skill validate --format ndjson"
     )]
     Validate(SkillValidateArgs),
 
@@ -144,23 +653,72 @@ Defaults to all skills when no name is provided.",
         name = "list",
         about = "List discoverable built-in and workspace skills",
         long_about = "List discoverable built-in and workspace skills.\n\n\
-Supports machine-readable JSON output for other tools.",
+Supports machine-readable JSON output for other tools; `--format ndjson` prints one skill per line\n\
+followed by a summary object with `schema_version` and `total`. `--format text` is tab-separated for scripts;\n\
+`--format table` pads the same columns to line up in a terminal (bold header unless `NO_COLOR` is set).\n\
+`--installed-only` and `--available-only` filter the `--origin` listing after discovery; \
+use them with `--origin all` (the default), since `--origin builtin` never reports workspace paths.\n\
+Set `AGX_SKILLS_PATH` to search several workspace skills roots; earlier roots shadow later ones by name.\n\
+Use `--lang <lang>` to show localized `description.<lang>` frontmatter, falling back to `description`.\n\
+Skills declaring `deprecated: true` are marked in text output and report `deprecated`/`deprecation` in JSON;\n\
+`skill install` and `skill dump` warn (naming any `replacement`) when writing them.\n\
+With `--global`, the user-global skills directory (`~/.config/agx/skills` on Linux) is searched after the project\n\
+roots, so a project skill shadows a global skill with the same name.\n\
+Skills are sorted by name; `--sort manifest` keeps the curated `builtin-manifest.toml` order (reported as `order` in JSON)\n\
+and lists workspace-only skills last.",
         after_help = "Examples:\n\
   agx skill list\n\
   agx skill list --origin builtin\n\
-  agx skill list --origin all --format json"
+  agx skill list --origin all --format json\n\
+  agx skill list --installed-only\n\
+  agx skill list --available-only --format json\n\
+  agx skill list --format ndjson\n\
+  agx skill list --format table\n\
+  agx skill list --lang fr\n\
+  agx skill list --sort manifest\n\
+  agx skill list --global"
     )]
     List(SkillListArgs),
 
+    #[command(
+        name = "which",
+        about = "Show where a skill name resolves from",
+        long_about = "Show where a skill name resolves from.\n\n\
+Applies the same precedence as `skill list`: a workspace skill shadows a built-in skill of the same name,\n\
+and with `AGX_SKILLS_PATH` earlier roots shadow later ones.",
+        after_help = "Examples:\n\
+  agx skill which ask-user-question\n\
+  agx skill which ask-user-question --format json"
+    )]
+    Which(SkillWhichArgs),
+
+    #[command(
+        name = "root",
+        about = "Show the project and skills roots resolved from the current directory",
+        long_about = "Show the project and skills roots resolved from the current directory.\n\n\
+Prints the Cargo workspace root, the nearest crate root, the project skills root (`.agents/skills` under the\n\
+workspace root, else the crate root) used by `skill dump`, each `AGX_SKILLS_PATH` search root in precedence order,\n\
+and the user-global skills root. Unresolved roots print as `-` (`null` in JSON).",
+        after_help = "Examples:\n\
+  agx skill root\n\
+  AGX_SKILLS_PATH=team-skills:.agents/skills agx skill root\n\
+  agx skill root --format json"
+    )]
+    Root(SkillRootArgs),
+
     #[command(
         name = "dump",
         about = "Dump built-in skills for human use",
         long_about = "Dump built-in skills for human use.\n\n\
-Writes selected built-in skills to `.agents/skills` by default.",
+Writes selected built-in skills to `.agents/skills` by default, or to the user-global skills directory with `--global`.\n\
+Skills may ship `hooks/post-install.sh`; it only runs (with `sh`, in the skill directory) when `--run-hooks` is passed.",
         after_help = "Examples:\n\
   agx skill dump ask-user-question\n\
   agx skill dump --all\n\
-  agx skill dump --all --to /tmp/agent-skills"
+  agx skill dump --all --to /tmp/agent-skills\n\
+  agx skill dump ask-user-question --print-prompts\n\
+  agx skill dump --all --run-hooks\n\
+  agx skill dump --all --global"
     )]
     Dump(SkillDumpArgs),
 
@@ -168,23 +726,73 @@ Writes selected built-in skills to `.agents/skills` by default.",
         name = "install",
         about = "Install built-in skills for automation",
         long_about = "Install built-in skills for automation.\n\n\
-Writes selected skills to `.agents/skills` by default and can emit JSON output.",
+Writes selected skills to `.agents/skills` by default, or to the user-global skills directory with `--global`,\n\
+and can emit JSON output.\n\
+Skills may ship `hooks/post-install.sh`; it only runs (with `sh`, in the skill directory) when `--run-hooks` is passed,\n\
+and its exit status and output are reported.\n\
+Each installed skill's version and checksum are pinned in `builtin-pins.toml` under the install root; replacing a\n\
+pinned skill with different built-in content requires `--force`.",
         after_help = "Examples:\n\
   agx skill install ask-user-question\n\
   agx skill install --all --force\n\
-  agx skill install ask-user-question --format json --to /tmp/agent-skills"
+  agx skill install ask-user-question --format json --to /tmp/agent-skills\n\
+  agx skill install --all --print-prompts\n\
+  agx skill install --all --run-hooks\n\
+  agx skill install ask-user-question --global"
     )]
     Install(SkillInstallArgs),
 
     #[command(
         name = "export",
-        about = "Export built-in skills to a tar.gz archive",
-        long_about = "Export built-in skills to a tar.gz archive.\n\n\
-Archive layout preserves `.agents/skills/<name>/...` paths.",
+        about = "Export built-in skills to a tar.gz archive or OCI image layout",
+        long_about = "Export built-in skills to a tar.gz archive or OCI image layout.\n\n\
+Archive layout preserves `.agents/skills/<name>/...` paths.\n\
+Use `--split --output-dir <path>` to write one `<name>.tar.gz` archive per skill instead.\n\
+Use `--format oci --output <dir>` to write an OCI image layout (`oci-layout`, `index.json`, `blobs/sha256/...`)\n\
+with one manifest per skill, ready to push to an artifact registry.\n\
+Use `--manifest-only --output <path>` to write just a JSON inventory of the bundle without file contents.\n\
+Use `--dedup` to store byte-identical files once per archive; later copies become tar hard link entries.\n\
+Use `--tag <tag>` (repeatable) to export only skills whose frontmatter `tags` include one of the given tags.\n\
+Use `--since <manifest.json>` with a manifest from an earlier `--manifest-only` run to export only skills whose\n\
+description or file checksums changed; unchanged skills are listed in a `log:` line and the export may be empty.",
         after_help = "Examples:\n\
-  agx skill export --output dist/agx-skills-v0.1.0.tar.gz"
+  agx skill export --output dist/agx-skills-v0.1.0.tar.gz\n\
+  agx skill export --split --output-dir dist/skills\n\
+  agx skill export --format oci --output dist/skills-oci\n\
+  agx skill export --manifest-only --output dist/skills-manifest.json\n\
+  agx skill export --dedup --output dist/agx-skills.tar.gz\n\
+  agx skill export --tag rfc --output dist/rfc-skills.tar.gz\n\
+  agx skill export --since dist/skills-manifest.json --output dist/changed-skills.tar.gz"
     )]
     Export(SkillExportArgs),
+
+    #[command(
+        name = "catalog",
+        about = "Print SHA-256 digests of the embedded built-in skill catalog",
+        long_about = "Print SHA-256 digests of the embedded built-in skill catalog.\n\n\
+Each skill digest covers its name, description, and file paths, modes, and contents in path order;\n\
+the catalog digest covers every skill digest in name order. Pin the catalog digest to verify that an agx\n\
+binary carries exactly the expected built-in skills.\n\
+Text output lists `<name>\\t<digest>` lines and a final `catalog` line; `--digest` prints only the catalog digest.\n\
+`--format json` prints `{ schema_version, algorithm, digest, skills: [{ name, digest }] }`.",
+        after_help = "Examples:\n\
+  agx skill catalog\n\
+  agx skill catalog --digest\n\
+  agx skill catalog --format json"
+    )]
+    Catalog(SkillCatalogArgs),
+
+    #[command(
+        name = "selftest",
+        about = "Check that built-in skills round-trip through dump and export",
+        long_about = "Check that built-in skills round-trip through dump and export.\n\n\
+Dumps every built-in skill to a temporary directory, exports the same set to an archive, unpacks it into another\n\
+temporary directory, and compares both trees (contents and file modes), reporting the first divergence.\n\
+Intended for maintainers and CI.",
+        after_help = "Examples:\n\
+  agx skill selftest"
+    )]
+    Selftest,
 }
 
 #[derive(Debug, Args)]
@@ -199,6 +807,90 @@ pub struct SkillNewArgs {
     /// Skill name to scaffold under `.agents/skills`.
     #[arg(value_name = "name")]
     pub name: String,
+
+    /// Target the user-global skills directory (`<config dir>/agx/skills`) instead of the project.
+    #[arg(long = "global", action = ArgAction::SetTrue)]
+    pub global: bool,
+
+    /// Normalize an invalid name (for example `"Ask User Question"`) instead of rejecting it.
+    #[arg(long = "normalize", action = ArgAction::SetTrue)]
+    pub normalize: bool,
+
+    /// Default prompt for the scaffolded agent configs; `{name}` and `{title}` are expanded.
+    #[arg(long = "prompt", value_name = "text")]
+    pub prompt: Option<String>,
+
+    /// Agent config to scaffold under `agents/`. Repeat for several; defaults to `openai`.
+    #[arg(long = "agent-format", value_enum, action = ArgAction::Append)]
+    pub agent_formats: Vec<SkillAgentFormat>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SkillAgentFormat {
+    Openai,
+    Claude,
+    Cursor,
+    Gemini,
+}
+
+#[derive(Debug, Args)]
+pub struct SkillVersionArgs {
+    /// Skill name under `.agents/skills`.
+    #[arg(value_name = "name")]
+    pub name: String,
+
+    /// Semver component to increment.
+    #[arg(
+        long = "bump",
+        value_enum,
+        conflicts_with = "set",
+        required_unless_present = "set"
+    )]
+    pub bump: Option<SkillVersionBump>,
+
+    /// Explicit version to write (`MAJOR.MINOR.PATCH`).
+    #[arg(long = "set", value_name = "version")]
+    pub set: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SkillVersionBump {
+    Major,
+    Minor,
+    Patch,
+}
+
+#[derive(Debug, Args)]
+pub struct SkillRemoveArgs {
+    /// Skill name under `.agents/skills`.
+    #[arg(value_name = "name")]
+    pub name: String,
+
+    /// Remove without asking for confirmation.
+    #[arg(long = "force", action = ArgAction::SetTrue)]
+    pub force: bool,
+}
+
+#[derive(Debug, Args)]
+pub struct SkillRenameArgs {
+    /// Current skill name under `.agents/skills`.
+    #[arg(value_name = "old")]
+    pub old: String,
+
+    /// New skill name.
+    #[arg(value_name = "new")]
+    pub new: String,
+}
+
+#[derive(Debug, Args)]
+pub struct SkillEditArgs {
+    /// Skill name under `.agents/skills`.
+    #[arg(value_name = "name")]
+    pub name: String,
+
+    /// Open the skill directory instead of `SKILL.md`.
+    #[arg(long = "dir", action = ArgAction::SetTrue)]
+    pub dir: bool,
 }
 
 #[derive(Debug, Args)]
@@ -206,6 +898,41 @@ pub struct SkillValidateArgs {
     /// Optional skill name under `.agents/skills`.
     #[arg(value_name = "name")]
     pub name: Option<String>,
+
+    /// Warn when `SKILL.md` bodies reference `$name` skills that are neither built-in nor in the workspace.
+    #[arg(long = "check-skill-refs", action = ArgAction::SetTrue)]
+    pub check_skill_refs: bool,
+
+    /// Fail when tools listed in frontmatter `requires` are missing from `PATH`.
+    #[arg(long = "check-requires", action = ArgAction::SetTrue)]
+    pub check_requires: bool,
+
+    /// Fail when skill files use CRLF line endings.
+    #[arg(long = "check-eol", action = ArgAction::SetTrue)]
+    pub check_eol: bool,
+
+    /// Fail when relative links in `SKILL.md` point at files missing from the skill directory.
+    #[arg(long = "check-links", action = ArgAction::SetTrue)]
+    pub check_links: bool,
+
+    /// Fail when `agents/` contains anything other than recognized agent config files.
+    #[arg(long = "strict", action = ArgAction::SetTrue)]
+    pub strict: bool,
+
+    /// Validate frontmatter against this JSON Schema instead of the built-in allowed keys.
+    #[arg(long = "schema", value_name = "file")]
+    pub schema: Option<PathBuf>,
+
+    /// Output format for validation results.
+    #[arg(long = "format", value_enum, default_value_t = SkillValidateFormat::Text)]
+    pub format: SkillValidateFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SkillValidateFormat {
+    Text,
+    Json,
+    Ndjson,
 }
 
 #[derive(Debug, Args)]
@@ -214,9 +941,35 @@ pub struct SkillListArgs {
     #[arg(long = "origin", value_enum, default_value_t = SkillListOrigin::All)]
     pub origin: SkillListOrigin,
 
+    /// Also discover skills in the user-global skills directory; project skills shadow global ones.
+    #[arg(long = "global", action = ArgAction::SetTrue)]
+    pub global: bool,
+
     /// Output format for discovered skills.
     #[arg(long = "format", value_enum, default_value_t = SkillListFormat::Text)]
     pub format: SkillListFormat,
+
+    /// Keep only skills present in the workspace.
+    #[arg(long = "installed-only", action = ArgAction::SetTrue, conflicts_with = "available_only")]
+    pub installed_only: bool,
+
+    /// Keep only built-in skills not yet present in the workspace.
+    #[arg(long = "available-only", action = ArgAction::SetTrue)]
+    pub available_only: bool,
+
+    /// Show `description.<lang>` descriptions when present, falling back to `description`.
+    #[arg(long = "lang", value_name = "lang")]
+    pub lang: Option<String>,
+
+    /// Order skills by name, or by `builtin-manifest.toml` order with workspace-only skills last.
+    #[arg(long = "sort", value_enum, default_value_t = SkillListSort::Name)]
+    pub sort: SkillListSort,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SkillListSort {
+    Name,
+    Manifest,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -228,6 +981,38 @@ pub enum SkillListOrigin {
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
 pub enum SkillListFormat {
+    Text,
+    Table,
+    Json,
+    Ndjson,
+}
+
+#[derive(Debug, Args)]
+pub struct SkillWhichArgs {
+    /// Skill name to resolve.
+    #[arg(value_name = "name")]
+    pub name: String,
+
+    /// Output format for the resolution.
+    #[arg(long = "format", value_enum, default_value_t = SkillWhichFormat::Text)]
+    pub format: SkillWhichFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SkillWhichFormat {
+    Text,
+    Json,
+}
+
+#[derive(Debug, Args)]
+pub struct SkillRootArgs {
+    /// Output format for the roots.
+    #[arg(long = "format", value_enum, default_value_t = SkillRootFormat::Text)]
+    pub format: SkillRootFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SkillRootFormat {
     Text,
     Json,
 }
@@ -246,9 +1031,21 @@ pub struct SkillDumpArgs {
     #[arg(long = "to", value_name = "path")]
     pub to: Option<PathBuf>,
 
+    /// Target the user-global skills directory (`<config dir>/agx/skills`) instead of the project.
+    #[arg(long = "global", action = ArgAction::SetTrue, conflicts_with = "to")]
+    pub global: bool,
+
     /// Overwrite existing target skill directories.
     #[arg(long = "force", action = ArgAction::SetTrue)]
     pub force: bool,
+
+    /// Print each skill's `default_prompt` from its first agent config (`agents/openai.yaml`, then `claude.yaml`, ...) after writing.
+    #[arg(long = "print-prompts", action = ArgAction::SetTrue)]
+    pub print_prompts: bool,
+
+    /// Run each skill's `hooks/post-install.sh` in its written directory.
+    #[arg(long = "run-hooks", action = ArgAction::SetTrue)]
+    pub run_hooks: bool,
 }
 
 #[derive(Debug, Args)]
@@ -273,7 +1070,11 @@ pub struct SkillInstallArgs {
     #[arg(long = "to", value_name = "path")]
     pub to: Option<PathBuf>,
 
-    /// Overwrite existing target skill directories.
+    /// Target the user-global skills directory (`<config dir>/agx/skills`) instead of the project.
+    #[arg(long = "global", action = ArgAction::SetTrue, conflicts_with = "to")]
+    pub global: bool,
+
+    /// Overwrite existing target skill directories and ignore `builtin-pins.toml` pins.
     #[arg(long = "force", action = ArgAction::SetTrue)]
     pub force: bool,
 
@@ -284,6 +1085,14 @@ pub struct SkillInstallArgs {
         default_value_t = SkillInstallFormat::Text
     )]
     pub format: SkillInstallFormat,
+
+    /// Print each skill's `default_prompt` from its first agent config (`agents/openai.yaml`, then `claude.yaml`, ...) after installing.
+    #[arg(long = "print-prompts", action = ArgAction::SetTrue)]
+    pub print_prompts: bool,
+
+    /// Run each skill's `hooks/post-install.sh` in its installed directory.
+    #[arg(long = "run-hooks", action = ArgAction::SetTrue)]
+    pub run_hooks: bool,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -297,6 +1106,23 @@ pub enum SkillInstallFormat {
     Json,
 }
 
+#[derive(Debug, Args)]
+pub struct SkillCatalogArgs {
+    /// Print only the catalog digest.
+    #[arg(long = "digest", action = ArgAction::SetTrue)]
+    pub digest: bool,
+
+    /// Output format for the digests.
+    #[arg(long = "format", value_enum, default_value_t = SkillCatalogFormat::Text)]
+    pub format: SkillCatalogFormat,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SkillCatalogFormat {
+    Text,
+    Json,
+}
+
 #[derive(Debug, Args)]
 pub struct SkillExportArgs {
     /// Export origin.
@@ -307,9 +1133,42 @@ pub struct SkillExportArgs {
     )]
     pub origin: SkillExportOrigin,
 
-    /// Output `.tar.gz` archive path.
-    #[arg(long = "output", value_name = "path")]
-    pub output: PathBuf,
+    /// Export format.
+    #[arg(long = "format", value_enum, default_value_t = SkillExportFormat::TarGz)]
+    pub format: SkillExportFormat,
+
+    /// Output `.tar.gz` archive path, or directory for `--format oci`.
+    #[arg(
+        long = "output",
+        value_name = "path",
+        required_unless_present = "split",
+        conflicts_with = "split"
+    )]
+    pub output: Option<PathBuf>,
+
+    /// Write one `<name>.tar.gz` archive per skill into `--output-dir`.
+    #[arg(long = "split", action = ArgAction::SetTrue, requires = "output_dir")]
+    pub split: bool,
+
+    /// Write only a JSON manifest (names, descriptions, file paths, sha256 checksums) to `--output`.
+    #[arg(long = "manifest-only", action = ArgAction::SetTrue, conflicts_with = "split")]
+    pub manifest_only: bool,
+
+    /// Store byte-identical files once; repeats become hard link entries to the first copy.
+    #[arg(long = "dedup", action = ArgAction::SetTrue, conflicts_with = "manifest_only")]
+    pub dedup: bool,
+
+    /// Export only skills that changed since this `--manifest-only` manifest; unchanged skills are logged.
+    #[arg(long = "since", value_name = "manifest.json")]
+    pub since: Option<PathBuf>,
+
+    /// Export only skills tagged with this value in frontmatter `tags` (repeatable; any tag matches).
+    #[arg(long = "tag", value_name = "tag", action = ArgAction::Append)]
+    pub tags: Vec<String>,
+
+    /// Output directory for per-skill archives written by `--split`.
+    #[arg(long = "output-dir", value_name = "path", requires = "split")]
+    pub output_dir: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
@@ -317,14 +1176,23 @@ pub enum SkillExportOrigin {
     Builtin,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum SkillExportFormat {
+    TarGz,
+    Oci,
+}
+
 /// CLI-provided RFC reference used by metadata fields.
 ///
-/// Numeric inputs are treated as direct RFC ids, while non-numeric inputs are
-/// resolved later as RFC titles against the project RFC directory.
+/// Numeric inputs are treated as direct RFC ids, `latest`/`last` select the
+/// highest existing id, and other inputs are resolved later as RFC titles
+/// against the project RFC directory.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RfcReference {
     /// Direct numeric RFC identifier.
     Id(u32),
+    /// The highest-numbered existing RFC (`latest` or `last`).
+    Latest,
     /// RFC title that must be resolved to an identifier.
     Title(String),
 }
@@ -344,6 +1212,9 @@ impl FromStr for RfcReference {
                 .map_err(|_| format!("invalid RFC id `{normalized}`"))?;
             return Ok(Self::Id(parsed));
         }
+        if matches!(normalized, "latest" | "last") {
+            return Ok(Self::Latest);
+        }
 
         Ok(Self::Title(normalized.to_owned()))
     }
@@ -359,6 +1230,22 @@ pub struct RfcEditArgs {
     #[arg(long = "agent", value_name = "name", action = ArgAction::Append)]
     pub agents: Vec<String>,
 
+    /// For `rfc revise`: remove an author from metadata. Repeat to remove multiple authors.
+    #[arg(long = "remove-author", value_name = "name", action = ArgAction::Append)]
+    pub remove_authors: Vec<String>,
+
+    /// For `rfc revise`: remove an agent identifier from metadata. Repeat to remove multiple agents.
+    #[arg(long = "remove-agent", value_name = "name", action = ArgAction::Append)]
+    pub remove_agents: Vec<String>,
+
+    /// Add a label to the `labels` metadata array. Repeat to add multiple labels; duplicates are ignored.
+    #[arg(long = "label", value_name = "name", action = ArgAction::Append)]
+    pub labels: Vec<String>,
+
+    /// Set the one-paragraph abstract stored in metadata and the `## Summary` section.
+    #[arg(long = "abstract", value_name = "text")]
+    pub r#abstract: Option<String>,
+
     /// Set the discussion reference (for example, a link or ticket id).
     #[arg(long = "discussion", value_name = "link or id")]
     pub discussion: Option<String>,
@@ -367,7 +1254,27 @@ pub struct RfcEditArgs {
     #[arg(long = "tracking_issue", value_name = "link or id")]
     pub tracking_issue: Option<String>,
 
-    /// List prerequisite RFC references (id or title). Repeat to add multiple.
+    /// For `rfc new`: set `tracking_issue` from an issue URL or id; with `--fetch`, also use the issue title.
+    #[arg(
+        long = "from-issue",
+        value_name = "url or id",
+        conflicts_with = "tracking_issue"
+    )]
+    pub from_issue: Option<String>,
+
+    /// For `rfc new`: print the created path, or a JSON object with `schema_version`, `path`, `rfc_id`, and `title`.
+    #[arg(long = "format", value_enum, default_value_t = RfcNewFormat::Text)]
+    pub format: RfcNewFormat,
+
+    /// For `rfc new`: copy the body of this existing RFC (id, file name, path, title, or `latest`) instead of the template body.
+    #[arg(long = "from", value_name = "rfc")]
+    pub from: Option<String>,
+
+    /// For `rfc new --from-issue`: read the issue title with the GitHub CLI (`gh`) when no title is given.
+    #[arg(long = "fetch", action = ArgAction::SetTrue, requires = "from_issue")]
+    pub fetch: bool,
+
+    /// List prerequisite RFC references (id, title, or `latest`). Repeat to add multiple.
     #[arg(
         long = "prerequisite",
         value_name = "rfc id or title",
@@ -375,7 +1282,7 @@ pub struct RfcEditArgs {
     )]
     pub prerequisite: Vec<RfcReference>,
 
-    /// List superseded RFC references (id or title). Repeat to add multiple.
+    /// List superseded RFC references (id, title, or `latest`). Repeat to add multiple.
     #[arg(
         long = "supersedes",
         value_name = "rfc id or title",
@@ -383,7 +1290,7 @@ pub struct RfcEditArgs {
     )]
     pub supersedes: Vec<RfcReference>,
 
-    /// List replacement RFC references (id or title). Repeat to add multiple.
+    /// List replacement RFC references (id, title, or `latest`). Repeat to add multiple.
     #[arg(
         long = "superseded_by",
         value_name = "rfc id or title",
@@ -399,11 +1306,112 @@ pub struct RfcEditArgs {
     #[arg(long = "title_parts", value_name = "string", num_args = 1..)]
     pub title_parts: Vec<String>,
 
-    /// For `rfc new`: RFC title. For `rfc revise`: selector (path, id, or slug) for an existing RFC.
+    /// Set the `status` metadata field: draft (default), proposed, accepted, final, rejected, or withdrawn.
+    #[arg(long = "status", value_name = "status")]
+    pub status: Option<String>,
+
+    /// Shortcut for `--status draft`.
+    #[arg(long = "draft", action = ArgAction::SetTrue, conflicts_with = "final")]
+    pub draft: bool,
+
+    /// Shortcut for `--status final`.
+    #[arg(long = "final", action = ArgAction::SetTrue)]
+    pub r#final: bool,
+
+    /// For `rfc new`: fail when the template reads a variable that is not defined, even in `{% if %}` conditions.
+    #[arg(long = "strict-template", action = ArgAction::SetTrue)]
+    pub strict_template: bool,
+
+    /// For `rfc new`: add the new RFC to `superseded_by` in every RFC it supersedes.
+    #[arg(long = "link-back", action = ArgAction::SetTrue)]
+    pub link_back: bool,
+
+    /// Keep `--prerequisite`/`--supersedes`/`--superseded_by` ids that do not match an existing RFC.
+    #[arg(long = "allow-dangling-refs", action = ArgAction::SetTrue)]
+    pub allow_dangling_refs: bool,
+
+    /// Also add every `--supersedes` id to `prerequisite`.
+    #[arg(long = "infer-prereq-from-supersedes", action = ArgAction::SetTrue)]
+    pub infer_prereq_from_supersedes: bool,
+
+    /// Add `[[id]]` tokens in the title and abstract to `prerequisite` and link them in the body.
+    /// The ids are checked like `--prerequisite` ids.
+    #[arg(long = "link-inline", action = ArgAction::SetTrue)]
+    pub link_inline: bool,
+
+    /// Treat warnings, such as an RFC id listed under two reference fields, as errors.
+    #[arg(long = "strict", action = ArgAction::SetTrue)]
+    pub strict: bool,
+
+    /// For `rfc new`: use this RFC id instead of the next free one (at most `rfc.id_width` digits, 4 by default).
+    #[arg(
+        long = "id",
+        value_name = "number",
+        value_parser = clap::value_parser!(u32).range(1..)
+    )]
+    pub id: Option<u32>,
+
+    /// For `rfc new`: skip the duplicate-title check. Requires `--slug` so file names stay distinct.
+    #[arg(long = "allow-duplicate-title", action = ArgAction::SetTrue, requires = "slug")]
+    pub allow_duplicate_title: bool,
+
+    /// For `rfc new`: file name slug to use instead of one derived from the title.
+    #[arg(long = "slug", value_name = "slug")]
+    pub slug: Option<String>,
+
+    /// For `rfc new`: write only metadata and the heading, skipping the template.
+    #[arg(long = "no-template", action = ArgAction::SetTrue)]
+    pub no_template: bool,
+
+    /// For `rfc revise`: replace the RFC body (everything after the frontmatter) with this file.
+    #[arg(long = "body-file", value_name = "path")]
+    pub body_file: Option<PathBuf>,
+
+    /// For `rfc revise`: rename the file to match a new title's slug.
+    #[arg(long = "rename", action = ArgAction::SetTrue, conflicts_with = "keep_filename")]
+    pub rename: bool,
+
+    /// For `rfc revise`: keep the current file name when retitling (the default), without a warning.
+    #[arg(long = "keep-filename", action = ArgAction::SetTrue)]
+    pub keep_filename: bool,
+
+    /// For `rfc revise`: allow revising an RFC whose `status` is frozen (for example `accepted`).
+    #[arg(long = "allow-frozen", action = ArgAction::SetTrue)]
+    pub allow_frozen: bool,
+
+    /// For `rfc revise`: record this message as the `[[revision]]` change instead of the default.
+    #[arg(long = "change", value_name = "message")]
+    pub change: Option<String>,
+
+    /// Use this RFC 3339 timestamp for `created`/`last_updated` and the revision entry
+    /// instead of the current time (`AGX_NOW` or `SOURCE_DATE_EPOCH` also pin it).
+    #[arg(long = "date", value_name = "rfc3339", hide = true)]
+    pub date: Option<String>,
+
+    /// For `rfc revise`: allow a status change that moves backwards or reopens a closed RFC.
+    #[arg(long = "allow-backward", action = ArgAction::SetTrue)]
+    pub allow_backward: bool,
+
+    /// For `rfc revise`: override the status transition and no-op checks
+    /// (implies `--allow-backward` and `--force-revision`).
+    #[arg(long = "force", action = ArgAction::SetTrue)]
+    pub force: bool,
+
+    /// For `rfc revise`: record a revision even when the metadata and body are unchanged.
+    #[arg(long = "force-revision", action = ArgAction::SetTrue)]
+    pub force_revision: bool,
+
+    /// For `rfc new`: RFC title. For `rfc revise`: selector (path, id, slug, or `latest`) for an existing RFC.
     #[arg(value_name = "title")]
     pub title_arg: Option<String>,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum RfcNewFormat {
+    Text,
+    Json,
+}
+
 impl RfcEditArgs {
     /// Resolve title input precedence:
     /// `--title` > `--title_parts` > positional `<title>`.
@@ -21,15 +21,35 @@ fn run() -> Result<()> {
             RfcCommand::Init => rfc::init::run(),
             RfcCommand::New(new_args) => rfc::create::create_rfc(&new_args),
             RfcCommand::Revise(revise_args) => rfc::revise::revise_rfc(&revise_args),
+            RfcCommand::Validate(validate_args) => rfc::validate::run(&validate_args),
+            RfcCommand::Stats(stats_args) => rfc::stats::run(&stats_args),
+            RfcCommand::Referrers(referrers_args) => rfc::referrers::run(&referrers_args),
+            RfcCommand::Index(index_args) => rfc::index::run(&index_args),
+            RfcCommand::Changelog(changelog_args) => rfc::changelog::run(&changelog_args),
+            RfcCommand::SetStatus(set_status_args) => rfc::set_status::run(&set_status_args),
+            RfcCommand::Env(env_args) => rfc::env::run(&env_args),
+            RfcCommand::List(list_args) => rfc::list::run(&list_args),
+            RfcCommand::Board(board_args) => rfc::board::run(&board_args),
+            RfcCommand::Show(show_args) => rfc::show::run(&show_args),
+            RfcCommand::Graph(graph_args) => rfc::graph::run(&graph_args),
+            RfcCommand::Renumber(renumber_args) => rfc::renumber::run(&renumber_args),
         },
         Command::Skill(args) => match args.command {
             SkillCommand::Init(init_args) => skill::init::run(init_args),
             SkillCommand::New(new_args) => skill::init::run_new(new_args),
+            SkillCommand::Edit(edit_args) => skill::edit::run(edit_args),
+            SkillCommand::Remove(remove_args) => skill::remove::run(remove_args),
+            SkillCommand::Rename(rename_args) => skill::rename::run(rename_args),
+            SkillCommand::Version(version_args) => skill::version::run(version_args),
             SkillCommand::Validate(validate_args) => skill::validate::run(validate_args),
             SkillCommand::List(list_args) => skill::list::run(list_args),
+            SkillCommand::Which(which_args) => skill::which::run(which_args),
+            SkillCommand::Root(root_args) => skill::root::run(root_args),
             SkillCommand::Dump(dump_args) => skill::dump::run(dump_args),
             SkillCommand::Install(install_args) => skill::install::run(install_args),
             SkillCommand::Export(export_args) => skill::export::run(export_args),
+            SkillCommand::Catalog(catalog_args) => skill::digest::run(catalog_args),
+            SkillCommand::Selftest => skill::selftest::run(),
         },
     }
 }
@@ -16,6 +16,7 @@ use ratatui::{
 
 #[derive(Clone, Copy)]
 enum MessageKind {
+    Header,
     Path,
     Log,
     Hint,
@@ -53,6 +54,44 @@ pub(crate) fn print_error(message: impl AsRef<str>) {
     write_stderr(text, MessageKind::Error);
 }
 
+/// Print rows as a table with space-padded, left-aligned columns.
+///
+/// Columns are separated by two spaces and sized to their widest cell; the
+/// last column is not padded. The header is bold when stdout supports color.
+pub(crate) fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let mut widths = header
+        .iter()
+        .map(|cell| cell.chars().count())
+        .collect::<Vec<_>>();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    write_stdout(format_table_row(header, &widths), MessageKind::Header);
+    let mut stdout = io::stdout();
+    for row in rows {
+        let cells = row.iter().map(String::as_str).collect::<Vec<_>>();
+        let _ = writeln!(stdout, "{}", format_table_row(&cells, &widths));
+    }
+}
+
+fn format_table_row(cells: &[&str], widths: &[usize]) -> String {
+    let mut line = String::new();
+    for (index, (cell, width)) in cells.iter().zip(widths).enumerate() {
+        if index > 0 {
+            line.push_str("  ");
+        }
+        line.push_str(cell);
+        if index + 1 < cells.len() {
+            let padding = width.saturating_sub(cell.chars().count());
+            line.extend(std::iter::repeat_n(' ', padding));
+        }
+    }
+    line
+}
+
 fn write_stdout(text: String, kind: MessageKind) {
     let mut stdout = io::stdout();
     write_line(&mut stdout, &text, kind, stdout_supports_color());
@@ -157,6 +196,7 @@ fn is_force_color_var_set(name: &str) -> bool {
 
 fn style_for(kind: MessageKind) -> Style {
     match kind {
+        MessageKind::Header => Style::new().add_modifier(Modifier::BOLD),
         MessageKind::Path => Style::new().fg(Color::Cyan).add_modifier(Modifier::DIM),
         MessageKind::Log => Style::new().fg(Color::Blue).add_modifier(Modifier::DIM),
         MessageKind::Hint => Style::new().fg(Color::Yellow).add_modifier(Modifier::BOLD),
@@ -0,0 +1,103 @@
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::cli::{RfcBoardArgs, RfcBoardFormat};
+
+use super::{
+    config::RfcConfig,
+    list::{RfcListEntry, collect_rfcs},
+    status::STATUSES,
+    util::format_rfc_id,
+};
+
+/// Statuses rendered as checked items.
+const DONE_STATUSES: [&str; 2] = ["accepted", "final"];
+
+/// Label for RFCs without a `status` field.
+const UNSET_STATUS: &str = "unset";
+
+/// Print a GitHub-flavored markdown checklist of RFCs grouped by status.
+pub(crate) fn run(args: &RfcBoardArgs) -> Result<()> {
+    let groups = group_by_status(collect_rfcs()?);
+    match args.format {
+        RfcBoardFormat::Markdown => {
+            let id_width = RfcConfig::load()?.id_width;
+            print!("{}", render_markdown(&groups, id_width));
+        }
+        RfcBoardFormat::Json => {
+            let payload = RfcBoardResponseJson {
+                schema_version: 1,
+                groups,
+            };
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+        }
+    }
+    Ok(())
+}
+
+fn group_by_status(entries: Vec<RfcListEntry>) -> Vec<RfcBoardGroupJson> {
+    let mut groups = Vec::<RfcBoardGroupJson>::new();
+    for entry in entries {
+        let status = entry.status.as_deref().unwrap_or(UNSET_STATUS).to_owned();
+        let item = RfcBoardItemJson {
+            done: DONE_STATUSES.contains(&status.as_str()),
+            rfc: entry.rfc,
+            title: entry.title,
+        };
+        match groups.iter_mut().find(|group| group.status == status) {
+            Some(group) => group.rfcs.push(item),
+            None => groups.push(RfcBoardGroupJson {
+                status,
+                rfcs: vec![item],
+            }),
+        }
+    }
+    groups.sort_by_key(|group| {
+        // Known statuses follow lifecycle order; others follow alphabetically,
+        // then RFCs without a status.
+        let rank = STATUSES
+            .iter()
+            .position(|known| *known == group.status)
+            .unwrap_or(STATUSES.len());
+        (group.status == UNSET_STATUS, rank, group.status.clone())
+    });
+    groups
+}
+
+fn render_markdown(groups: &[RfcBoardGroupJson], id_width: usize) -> String {
+    let mut rendered = String::new();
+    for group in groups {
+        if !rendered.is_empty() {
+            rendered.push('\n');
+        }
+        rendered.push_str(&format!("## {}\n\n", group.status));
+        for item in &group.rfcs {
+            let mark = if item.done { 'x' } else { ' ' };
+            rendered.push_str(&format!(
+                "- [{mark}] {}: {}\n",
+                format_rfc_id(item.rfc, id_width),
+                item.title
+            ));
+        }
+    }
+    rendered
+}
+
+#[derive(Debug, Serialize)]
+struct RfcBoardItemJson {
+    rfc: u32,
+    title: String,
+    done: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct RfcBoardGroupJson {
+    status: String,
+    rfcs: Vec<RfcBoardItemJson>,
+}
+
+#[derive(Debug, Serialize)]
+struct RfcBoardResponseJson {
+    schema_version: u32,
+    groups: Vec<RfcBoardGroupJson>,
+}
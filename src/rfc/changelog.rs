@@ -0,0 +1,130 @@
+use std::path::Path;
+
+use anyhow::{Result, anyhow, bail};
+use serde::Serialize;
+
+use crate::cli::{RfcChangelogArgs, RfcChangelogFormat, RfcChangelogGroup};
+use crate::output;
+
+use super::{
+    config::RfcConfig,
+    document::{RfcDocument, rfc_paths},
+    util::{RFC_DIR, format_rfc_id, parse_timestamp},
+};
+
+/// Print revision entries from every RFC sorted by date.
+///
+/// Entries whose date cannot be parsed are skipped with a warning.
+pub(crate) fn run(args: &RfcChangelogArgs) -> Result<()> {
+    let rfc_dir = Path::new(RFC_DIR);
+    if !rfc_dir.is_dir() {
+        bail!("RFC directory does not exist at {}", rfc_dir.display());
+    }
+    let since = args
+        .since
+        .as_deref()
+        .map(|raw| parse_timestamp(raw).ok_or_else(|| anyhow!("invalid `--since` date `{raw}`")))
+        .transpose()?;
+
+    let mut entries = Vec::new();
+    for path in rfc_paths(rfc_dir)? {
+        let document = RfcDocument::read(&path)?;
+        let rfc = document.id()?;
+        let title = document.title()?.to_owned();
+        for revision in document.revisions()? {
+            let Some(timestamp) = parse_timestamp(&revision.date) else {
+                output::print_warning(format!(
+                    "{}: skipping revision with invalid date `{}`",
+                    path.display(),
+                    revision.date
+                ));
+                continue;
+            };
+            if since.is_some_and(|since| timestamp < since) {
+                continue;
+            }
+            entries.push((
+                timestamp,
+                ChangelogEntryJson {
+                    rfc,
+                    title: title.clone(),
+                    date: revision.date,
+                    change: revision.change,
+                    by: revision.by,
+                },
+            ));
+        }
+    }
+    entries.sort_by_key(|(timestamp, entry)| (*timestamp, entry.rfc));
+
+    match args.format {
+        RfcChangelogFormat::Text => {
+            let mut entries = entries
+                .into_iter()
+                .map(|(timestamp, entry)| (timestamp.date_naive().to_string(), entry))
+                .collect::<Vec<_>>();
+            if args.group_by == RfcChangelogGroup::Rfc {
+                entries.sort_by_key(|(_, entry)| entry.rfc);
+            }
+            let id_width = RfcConfig::load()?.id_width;
+            print!("{}", render_markdown(&entries, args.group_by, id_width));
+        }
+        RfcChangelogFormat::Json => {
+            let payload = ChangelogResponseJson {
+                schema_version: 1,
+                entries: entries.into_iter().map(|(_, entry)| entry).collect(),
+            };
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+        }
+    }
+    Ok(())
+}
+
+/// Render `(day, entry)` pairs as markdown, starting a `##` section whenever
+/// the group key changes. RFC ids are padded to `id_width` digits.
+fn render_markdown(
+    entries: &[(String, ChangelogEntryJson)],
+    group_by: RfcChangelogGroup,
+    id_width: usize,
+) -> String {
+    let mut rendered = String::from("# Changelog\n");
+    let mut current_group = None;
+    for (day, entry) in entries {
+        let rfc_id = format_rfc_id(entry.rfc, id_width);
+        let (group, line) = match group_by {
+            RfcChangelogGroup::Date => (
+                day.clone(),
+                format!("RFC {rfc_id}: {} — {}", entry.title, entry.change),
+            ),
+            RfcChangelogGroup::Rfc => (
+                format!("RFC {rfc_id}: {}", entry.title),
+                format!("{day} — {}", entry.change),
+            ),
+        };
+        if current_group.as_ref() != Some(&group) {
+            rendered.push_str(&format!("\n## {group}\n\n"));
+            current_group = Some(group);
+        }
+        match &entry.by {
+            Some(by) => rendered.push_str(&format!("- {line} ({by})\n")),
+            None => rendered.push_str(&format!("- {line}\n")),
+        }
+    }
+    rendered
+}
+
+#[derive(Debug, Serialize)]
+struct ChangelogEntryJson {
+    rfc: u32,
+    title: String,
+    date: String,
+    change: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    by: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ChangelogResponseJson {
+    schema_version: u32,
+    entries: Vec<ChangelogEntryJson>,
+}
@@ -0,0 +1,228 @@
+//! Project-level RFC configuration.
+//!
+//! Settings are read from the `[rfc]` table of `.agx.toml` at the project root
+//! (workspace root, then crate root, then current directory). Missing files or
+//! keys fall back to the built-in defaults.
+
+use std::fs;
+
+use anyhow::{Context, Result, bail};
+use toml_edit::{DocumentMut, Table};
+
+use super::{
+    template::resolve_project_root,
+    util::{INITIAL_REVISION_CHANGE, REVISED_REVISION_CHANGE},
+};
+
+const CONFIG_FILE: &str = ".agx.toml";
+
+/// RFC id width used when `rfc.id_width` is not set.
+const DEFAULT_ID_WIDTH: usize = 4;
+
+/// RFC settings resolved from `.agx.toml`.
+#[derive(Debug, Clone)]
+pub(crate) struct RfcConfig {
+    /// Revision change recorded by `rfc new`.
+    pub(crate) initial_change: String,
+    /// Revision change recorded by `rfc revise`.
+    pub(crate) revised_change: String,
+    /// Statuses that `rfc revise` refuses to modify without `--allow-frozen`.
+    pub(crate) frozen_statuses: Vec<String>,
+    /// Render list fields one entry per line.
+    pub(crate) multiline_arrays: bool,
+    /// Keep `authors` sorted alphabetically.
+    pub(crate) sort_authors: bool,
+    /// Body sections for the embedded template, when customized.
+    pub(crate) sections: Option<Vec<String>>,
+    /// `{{ project_name }}` for templates, overriding the Cargo package name.
+    pub(crate) project_name: Option<String>,
+    /// Zero-padded digits in new RFC ids and file names.
+    pub(crate) id_width: usize,
+}
+
+impl Default for RfcConfig {
+    fn default() -> Self {
+        Self {
+            initial_change: INITIAL_REVISION_CHANGE.to_owned(),
+            revised_change: REVISED_REVISION_CHANGE.to_owned(),
+            frozen_statuses: vec!["accepted".to_owned(), "final".to_owned()],
+            multiline_arrays: false,
+            sort_authors: false,
+            sections: None,
+            project_name: None,
+            id_width: DEFAULT_ID_WIDTH,
+        }
+    }
+}
+
+impl RfcConfig {
+    /// Load the project configuration, or defaults when `.agx.toml` is absent.
+    pub(crate) fn load() -> Result<Self> {
+        let path = resolve_project_root()?.join(CONFIG_FILE);
+        if !path.is_file() {
+            return Ok(Self::default());
+        }
+
+        let source = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read `{}`", path.display()))?;
+        Self::parse(&source).with_context(|| format!("invalid config `{}`", path.display()))
+    }
+
+    fn parse(source: &str) -> Result<Self> {
+        let document = source
+            .parse::<DocumentMut>()
+            .context("failed to parse TOML")?;
+        let mut config = Self::default();
+        let Some(item) = document.get("rfc") else {
+            return Ok(config);
+        };
+        let Some(table) = item.as_table() else {
+            bail!("`rfc` must be a table");
+        };
+
+        if let Some(change) = optional_string(table, "initial_change")? {
+            config.initial_change = change;
+        }
+        if let Some(change) = optional_string(table, "revised_change")? {
+            config.revised_change = change;
+        }
+        if let Some(statuses) = optional_string_array(table, "frozen_statuses")? {
+            config.frozen_statuses = statuses;
+        }
+        if let Some(enabled) = optional_bool(table, "multiline_arrays")? {
+            config.multiline_arrays = enabled;
+        }
+        if let Some(enabled) = optional_bool(table, "sort_authors")? {
+            config.sort_authors = enabled;
+        }
+        if let Some(name) = optional_string(table, "project_name")? {
+            config.project_name = Some(name);
+        }
+        if let Some(width) = optional_integer(table, "id_width")? {
+            if !(1..=9).contains(&width) {
+                bail!("`rfc.id_width` must be between 1 and 9");
+            }
+            config.id_width = width as usize;
+        }
+        if let Some(sections) = optional_string_array(table, "sections")? {
+            if sections.is_empty() || sections.iter().any(|section| section.trim().is_empty()) {
+                bail!("`rfc.sections` must list one or more non-empty section names");
+            }
+            config.sections = Some(sections);
+        }
+        Ok(config)
+    }
+}
+
+fn optional_string(table: &Table, key: &str) -> Result<Option<String>> {
+    let Some(item) = table.get(key) else {
+        return Ok(None);
+    };
+    let Some(value) = item.as_str() else {
+        bail!("`rfc.{key}` must be a string");
+    };
+    if value.trim().is_empty() {
+        bail!("`rfc.{key}` cannot be empty");
+    }
+    Ok(Some(value.to_owned()))
+}
+
+fn optional_bool(table: &Table, key: &str) -> Result<Option<bool>> {
+    let Some(item) = table.get(key) else {
+        return Ok(None);
+    };
+    let Some(value) = item.as_bool() else {
+        bail!("`rfc.{key}` must be a boolean");
+    };
+    Ok(Some(value))
+}
+
+fn optional_integer(table: &Table, key: &str) -> Result<Option<i64>> {
+    let Some(item) = table.get(key) else {
+        return Ok(None);
+    };
+    let Some(value) = item.as_integer() else {
+        bail!("`rfc.{key}` must be an integer");
+    };
+    Ok(Some(value))
+}
+
+fn optional_string_array(table: &Table, key: &str) -> Result<Option<Vec<String>>> {
+    let Some(item) = table.get(key) else {
+        return Ok(None);
+    };
+    let Some(array) = item.as_array() else {
+        bail!("`rfc.{key}` must be an array of strings");
+    };
+
+    let mut values = Vec::with_capacity(array.len());
+    for entry in array {
+        let Some(value) = entry.as_str() else {
+            bail!("`rfc.{key}` must be an array of strings");
+        };
+        values.push(value.to_owned());
+    }
+    Ok(Some(values))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RfcConfig;
+
+    #[test]
+    fn parse_uses_defaults_without_rfc_table() {
+        let config = RfcConfig::parse("[other]\nkey = 1\n").expect("config should parse");
+        assert_eq!(config.initial_change, "Initial draft");
+        assert_eq!(config.revised_change, "Revised");
+        assert_eq!(config.frozen_statuses, vec!["accepted", "final"]);
+    }
+
+    #[test]
+    fn parse_reads_revision_change_defaults() {
+        let config =
+            RfcConfig::parse("[rfc]\ninitial_change = \"Created\"\nrevised_change = \"Updated\"\n")
+                .expect("config should parse");
+        assert_eq!(config.initial_change, "Created");
+        assert_eq!(config.revised_change, "Updated");
+    }
+
+    #[test]
+    fn parse_reads_frozen_statuses() {
+        let config = RfcConfig::parse("[rfc]\nfrozen_statuses = [\"implemented\"]\n")
+            .expect("config should parse");
+        assert_eq!(config.frozen_statuses, vec!["implemented"]);
+    }
+
+    #[test]
+    fn parse_reads_list_style_flags() {
+        let config = RfcConfig::parse("[rfc]\nmultiline_arrays = true\nsort_authors = true\n")
+            .expect("config should parse");
+        assert!(config.multiline_arrays);
+        assert!(config.sort_authors);
+    }
+
+    #[test]
+    fn parse_reads_project_name() {
+        let config =
+            RfcConfig::parse("[rfc]\nproject_name = \"agx\"\n").expect("config should parse");
+        assert_eq!(config.project_name.as_deref(), Some("agx"));
+    }
+
+    #[test]
+    fn parse_reads_id_width() {
+        assert_eq!(
+            RfcConfig::parse("").expect("config should parse").id_width,
+            4
+        );
+        let config = RfcConfig::parse("[rfc]\nid_width = 5\n").expect("config should parse");
+        assert_eq!(config.id_width, 5);
+        let error = RfcConfig::parse("[rfc]\nid_width = 0\n").expect_err("expected error");
+        assert!(error.to_string().contains("rfc.id_width"));
+    }
+
+    #[test]
+    fn parse_rejects_non_string_values() {
+        let error = RfcConfig::parse("[rfc]\ninitial_change = 1\n").expect_err("expected error");
+        assert!(error.to_string().contains("rfc.initial_change"));
+    }
+}
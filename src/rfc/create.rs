@@ -1,30 +1,61 @@
-use std::{fs::OpenOptions, io::Write, path::Path};
+use std::{
+    fs::{self, OpenOptions},
+    io::Write,
+    path::{Path, PathBuf},
+};
 
-use anyhow::{Context, Result, anyhow, bail};
-use tera::{Context as TeraContext, Tera};
+use anyhow::{Context, Result, bail};
+use serde::Serialize;
+use tera::Context as TeraContext;
+use toml_edit::{Array, DocumentMut, value};
 
-use crate::cli::RfcEditArgs;
+use crate::cli::{RfcEditArgs, RfcNewFormat};
 use crate::output;
 
-use super::reference::{ensure_unique_rfc_title, resolve_metadata_references};
-use super::template::load_template;
+use super::config::RfcConfig;
+use super::document::{RfcDocument, rfc_paths};
+use super::issue::fetch_issue_title;
+use super::lookup::locate_existing_rfc;
+use super::reference::{
+    ResolvedMetadataReferences, ensure_acyclic_prerequisites, ensure_acyclic_supersedes,
+    ensure_unique_rfc_title, inline_reference_ids, link_inline_references,
+    resolve_metadata_references,
+};
+use super::revise::rewrite_rfc_heading;
+use super::status::{DEFAULT_STATUS, requested_status};
+use super::template::{load_template, render_template_text, resolve_project_name};
 use super::util::{
-    INITIAL_REVISION_CHANGE, RFC_DIR, dedupe, next_rfc_id, resolve_default_author, slugify,
-    timestamp_now, toml_escape,
+    RFC_DIR, dedupe, format_rfc_id, next_rfc_id, resolve_default_author, resolve_timestamp,
+    rfc_file_id, slugify, toml_escape,
 };
 
 /// Create a new RFC file using CLI inputs and the resolved template source.
 pub(crate) fn create_rfc(cli: &RfcEditArgs) -> Result<()> {
-    let title = cli.resolved_title().ok_or_else(|| {
-        anyhow!("missing <title>: pass positional <title>, --title, or --title_parts")
-    })?;
+    let status = requested_status(cli)?.unwrap_or_else(|| DEFAULT_STATUS.to_owned());
+    let title = match (cli.resolved_title(), &cli.from_issue) {
+        (Some(title), _) => title,
+        (None, Some(issue)) if cli.fetch => fetch_issue_title(issue)?,
+        (None, Some(issue)) => bail!(
+            "missing <title>: `--from-issue {issue}` only sets `tracking_issue`; pass <title>, or --fetch to use the issue title"
+        ),
+        (None, None) => {
+            bail!("missing <title>: pass positional <title>, --title, or --title_parts")
+        }
+    };
     if is_numeric_selector(&title) {
         bail!(
             "create mode does not accept numeric-only title `{}`; numeric values are treated as RFC ids by `rfc revise`",
             title.trim()
         );
     }
-    ensure_unique_rfc_title(&title)?;
+    if !cli.allow_duplicate_title {
+        ensure_unique_rfc_title(&title)?;
+    }
+    let slug = match &cli.slug {
+        Some(slug) => explicit_slug(slug)?,
+        None => slugify(&title),
+    };
+    let config = RfcConfig::load()?;
 
     let mut authors = dedupe(&cli.authors);
     if authors.is_empty() {
@@ -32,67 +63,405 @@ pub(crate) fn create_rfc(cli: &RfcEditArgs) -> Result<()> {
     }
 
     let agents = dedupe(&cli.agents);
-    let references = resolve_metadata_references(cli)?;
+    let labels = dedupe(&cli.labels);
+    let inline_ids = if cli.link_inline {
+        std::iter::once(&title)
+            .chain(&cli.r#abstract)
+            .flat_map(|text| inline_reference_ids(text))
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+    let mut references = resolve_metadata_references(cli, &inline_ids)?;
+    let inline_prerequisite = std::mem::take(&mut references.inline_prerequisite);
+    references.extend_prerequisites(&inline_prerequisite);
+    let summary = match &cli.r#abstract {
+        Some(text) if cli.link_inline => Some(link_inline_references(text)?),
+        summary => summary.clone(),
+    };
 
-    let rfc_id = next_rfc_id(Path::new(RFC_DIR))?;
-    let output_path = Path::new(RFC_DIR).join(format!("{rfc_id}-{}.md", slugify(&title)));
+    let rfc_id = match cli.id {
+        Some(id) => reserved_rfc_id(id, config.id_width)?,
+        None => next_rfc_id(Path::new(RFC_DIR), config.id_width)?,
+    };
+    let output_path = Path::new(RFC_DIR).join(format!("{rfc_id}-{slug}.md"));
     if output_path.exists() {
         bail!("output RFC already exists: {}", output_path.display());
     }
+    let source_body = match &cli.from {
+        Some(selector) => Some(RfcDocument::read(&locate_existing_rfc(selector)?)?.body),
+        None => None,
+    };
+    let numeric_id = rfc_id.parse().context("generated RFC id is not numeric")?;
+    ensure_acyclic_prerequisites(Path::new(RFC_DIR), numeric_id, &references.prerequisite)?;
+    let timestamp = resolve_timestamp(cli.date.as_deref())?;
+    let backlinks = if cli.link_back {
+        ensure_acyclic_supersedes(Path::new(RFC_DIR), numeric_id, &references.supersedes)?;
+        plan_superseded_links(&rfc_id, &references.supersedes, &timestamp, config.id_width)?
+    } else {
+        Vec::new()
+    };
+
+    let draft = RfcDraft {
+        output_path,
+        rfc_id,
+        title,
+        authors,
+        agents,
+        labels,
+        references,
+        summary,
+        status,
+        // clap rejects `--from-issue` together with `--tracking_issue`.
+        tracking_issue: cli.from_issue.clone().or(cli.tracking_issue.clone()),
+        timestamp,
+    };
+    let mut rendered = if cli.no_template {
+        render_skeleton(&draft, cli, &config)?
+    } else {
+        render_template(&draft, cli, &config)?
+    };
+    if let Some(body) = source_body {
+        let mut document = RfcDocument::parse(&draft.output_path, &rendered)?;
+        document.body = rewrite_rfc_heading(&body, &draft.rfc_id, &draft.title);
+        rendered = document.render();
+    }
+    let output_path = draft.output_path;
+
+    let mut file = OpenOptions::new()
+        .create_new(true)
+        .write(true)
+        .open(&output_path)
+        .with_context(|| format!("failed to create RFC at {}", output_path.display()))?;
+    file.write_all(rendered.as_bytes())
+        .with_context(|| format!("failed to write RFC file {}", output_path.display()))?;
+    write_backlinks(&backlinks, &output_path)?;
+
+    match cli.format {
+        RfcNewFormat::Text => output::print_path(output_path.display()),
+        RfcNewFormat::Json => {
+            let payload = RfcNewResponseJson {
+                schema_version: 1,
+                path: output_path.to_string_lossy().into_owned(),
+                rfc_id: draft.rfc_id,
+                title: draft.title,
+            };
+            println!("{}", serde_json::to_string(&payload)?);
+        }
+    }
+    if cli.format == RfcNewFormat::Text {
+        for document in &backlinks {
+            output::print_path(document.path.display());
+        }
+    }
+    Ok(())
+}
+
+/// Write the planned `superseded_by` backlinks for the RFC just created at
+/// `output_path`.
+///
+/// When any write fails, targets already written are restored and the new RFC
+/// is removed, so it never claims to supersede RFCs that do not link back.
+fn write_backlinks(backlinks: &[RfcDocument], output_path: &Path) -> Result<()> {
+    let mut written = Vec::new();
+    for document in backlinks {
+        let result = fs::read(&document.path)
+            .with_context(|| format!("failed to read {}", document.path.display()))
+            .and_then(|original| {
+                document.write()?;
+                Ok(original)
+            });
+        match result {
+            Ok(original) => written.push((&document.path, original)),
+            Err(error) => {
+                for (path, original) in written {
+                    let _ = fs::write(path, original);
+                }
+                let _ = fs::remove_file(output_path);
+                return Err(error.context(format!(
+                    "removed {} because its supersession backlinks could not be written",
+                    output_path.display()
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Prepare each RFC listed in `supersedes` to link back to `rfc_id` through
+/// `superseded_by`, without writing anything.
+///
+/// Every target must exist and parse, or the whole `rfc new` is aborted before
+/// any file is written. RFCs that already link back are left out.
+fn plan_superseded_links(
+    rfc_id: &str,
+    supersedes: &[u32],
+    timestamp: &str,
+    id_width: usize,
+) -> Result<Vec<RfcDocument>> {
+    if supersedes.is_empty() {
+        return Ok(Vec::new());
+    }
+    let new_id = rfc_id
+        .parse::<u32>()
+        .context("generated RFC id is not numeric")?;
+    let paths = rfc_paths(Path::new(RFC_DIR))?;
+    let revised_by = resolve_default_author().ok();
 
-    let timestamp = timestamp_now();
-    let revision_timestamp = timestamp.clone();
+    let mut planned = Vec::new();
+    for target in supersedes {
+        let Some(path) = paths.iter().find(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(rfc_file_id)
+                == Some(*target)
+        }) else {
+            bail!(
+                "cannot link back to RFC {}: no RFC file with that id under {RFC_DIR}",
+                format_rfc_id(*target, id_width)
+            );
+        };
+        let mut document = RfcDocument::read(path)
+            .and_then(|document| {
+                document.id()?;
+                Ok(document)
+            })
+            .with_context(|| format!("cannot link back to {}", path.display()))?;
+        let mut superseded_by = document
+            .references("superseded_by")
+            .with_context(|| format!("cannot link back to {}", path.display()))?;
+        if superseded_by.contains(&new_id) {
+            continue;
+        }
+        superseded_by.push(new_id);
+        document.set_references("superseded_by", &dedupe(&superseded_by));
+        document.ensure_created(timestamp);
+        document.record_revision(
+            timestamp.to_owned(),
+            format!("Superseded by RFC {rfc_id}"),
+            revised_by.as_deref(),
+        )?;
+        planned.push(document);
+    }
+    Ok(planned)
+}
 
+/// Validate an explicit `--slug`, rejecting malformed slugs and slugs already
+/// used by another RFC file name.
+fn explicit_slug(slug: &str) -> Result<String> {
+    let slug = slug.trim();
+    if slug.is_empty() || slugify(slug) != slug {
+        bail!("invalid `--slug` `{slug}`; use lowercase letters, digits, and `-`");
+    }
+    let suffix = format!("-{slug}.md");
+    if let Some(existing) = rfc_paths(Path::new(RFC_DIR))?.into_iter().find(|path| {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(&suffix))
+    }) {
+        bail!("slug `{slug}` is already used by {}", existing.display());
+    }
+    Ok(slug.to_owned())
+}
+
+/// Format an explicit `--id` to `width` digits, rejecting ids that do not fit
+/// or are already used by an RFC file.
+fn reserved_rfc_id(id: u32, width: usize) -> Result<String> {
+    let rfc_id = format_rfc_id(id, width);
+    if rfc_id.len() > width {
+        bail!("`--id {id}` does not fit in {width} digits; raise `rfc.id_width` in .agx.toml");
+    }
+    if let Some(existing) = rfc_paths(Path::new(RFC_DIR))?.into_iter().find(|path| {
+        path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| rfc_file_id(name) == Some(id))
+    }) {
+        bail!("RFC {rfc_id} already exists at {}", existing.display());
+    }
+    Ok(rfc_id)
+}
+
+/// Resolved inputs shared by the template and skeleton renderers.
+struct RfcDraft {
+    output_path: PathBuf,
+    rfc_id: String,
+    title: String,
+    authors: Vec<String>,
+    agents: Vec<String>,
+    labels: Vec<String>,
+    references: ResolvedMetadataReferences,
+    /// Abstract as rendered into the `## Summary` section.
+    summary: Option<String>,
+    status: String,
+    /// `--tracking_issue`, or the issue named by `--from-issue`.
+    tracking_issue: Option<String>,
+    timestamp: String,
+}
+
+/// Render the resolved RFC template with Tera.
+fn render_template(draft: &RfcDraft, cli: &RfcEditArgs, config: &RfcConfig) -> Result<String> {
     let mut context = TeraContext::new();
-    context.insert("rfc_id", &rfc_id);
-    context.insert("title", &title);
-    context.insert("title_toml", &toml_escape(&title));
+    context.insert("rfc_id", &draft.rfc_id);
+    context.insert("title", &draft.title);
+    context.insert("title_toml", &toml_escape(&draft.title));
+    context.insert("abstract", &draft.summary);
+    context.insert(
+        "abstract_toml",
+        &cli.r#abstract.as_ref().map(|v| toml_escape(v)),
+    );
+    context.insert("status", &toml_escape(&draft.status));
     context.insert(
         "agents",
-        &agents
+        &draft
+            .agents
             .iter()
             .map(|entry| toml_escape(entry))
             .collect::<Vec<_>>(),
     );
     context.insert(
         "authors",
-        &authors
+        &draft
+            .authors
             .iter()
             .map(|entry| toml_escape(entry))
             .collect::<Vec<_>>(),
     );
-    context.insert("timestamp", &timestamp);
+    context.insert(
+        "labels",
+        &draft
+            .labels
+            .iter()
+            .map(|entry| toml_escape(entry))
+            .collect::<Vec<_>>(),
+    );
+    context.insert("timestamp", &draft.timestamp);
     context.insert(
         "discussion",
         &cli.discussion.as_ref().map(|v| toml_escape(v)),
     );
     context.insert(
         "tracking_issue",
-        &cli.tracking_issue.as_ref().map(|v| toml_escape(v)),
+        &draft.tracking_issue.as_ref().map(|v| toml_escape(v)),
+    );
+    context.insert("prerequisite", &draft.references.prerequisite);
+    context.insert("supersedes", &draft.references.supersedes);
+    context.insert("superseded_by", &draft.references.superseded_by);
+    context.insert(
+        "project_name",
+        &resolve_project_name(config.project_name.as_deref())?,
+    );
+    context.insert("revision_timestamp", &draft.timestamp);
+    context.insert("revision_change", &toml_escape(&config.initial_change));
+    context.insert(
+        "revision_by",
+        &draft.authors.first().map(|author| toml_escape(author)),
     );
-    context.insert("prerequisite", &references.prerequisite);
-    context.insert("supersedes", &references.supersedes);
-    context.insert("superseded_by", &references.superseded_by);
-    context.insert("revision_timestamp", &revision_timestamp);
-    context.insert("revision_change", &toml_escape(INITIAL_REVISION_CHANGE));
 
-    let template = load_template()?;
-    let rendered =
-        Tera::one_off(&template, &context, false).context("failed to render template")?;
+    let template = load_template(config.sections.as_deref())?;
+    let rendered = render_template_text(&template, &context, cli.strict_template)?;
+    if !config.multiline_arrays && !config.sort_authors && draft.labels.is_empty() {
+        return Ok(rendered);
+    }
+    let mut document = RfcDocument::parse(&draft.output_path, &rendered)?;
+    // Project templates written before `labels` existed do not render them.
+    if !draft.labels.is_empty() && !document.metadata.contains_key("labels") {
+        document.set_value("labels", draft.labels.iter().collect::<Array>());
+    }
+    document.apply_list_style(config);
+    Ok(document.render())
+}
 
-    let mut file = OpenOptions::new()
-        .create_new(true)
-        .write(true)
-        .open(&output_path)
-        .with_context(|| format!("failed to create RFC at {}", output_path.display()))?;
-    file.write_all(rendered.as_bytes())
-        .with_context(|| format!("failed to write RFC file {}", output_path.display()))?;
+/// Build a minimal RFC with populated metadata and only the title heading.
+fn render_skeleton(draft: &RfcDraft, cli: &RfcEditArgs, config: &RfcConfig) -> Result<String> {
+    let mut metadata = DocumentMut::new();
+    metadata["rfc"] = value(draft.rfc_id.as_str());
+    metadata["title"] = value(draft.title.as_str());
+    if let Some(summary) = &cli.r#abstract {
+        metadata["abstract"] = value(summary.as_str());
+    }
+    metadata["status"] = value(draft.status.as_str());
+    if !draft.agents.is_empty() {
+        metadata["agents"] = value(draft.agents.iter().collect::<Array>());
+    }
+    metadata["authors"] = value(draft.authors.iter().collect::<Array>());
+    if !draft.labels.is_empty() {
+        metadata["labels"] = value(draft.labels.iter().collect::<Array>());
+    }
+    metadata["created"] = value(draft.timestamp.as_str());
+    if let Some(discussion) = &cli.discussion {
+        metadata["discussion"] = value(discussion.as_str());
+    }
+    if let Some(tracking_issue) = &draft.tracking_issue {
+        metadata["tracking_issue"] = value(tracking_issue.as_str());
+    }
 
-    output::print_path(output_path.display());
-    Ok(())
+    let mut document = RfcDocument {
+        path: draft.output_path.clone(),
+        metadata,
+        body: format!("# RFC {}: {}\n", draft.rfc_id, draft.title),
+        crlf: false,
+    };
+    for (key, ids) in [
+        ("prerequisite", &draft.references.prerequisite),
+        ("supersedes", &draft.references.supersedes),
+        ("superseded_by", &draft.references.superseded_by),
+    ] {
+        if !ids.is_empty() {
+            document.set_references(key, ids);
+        }
+    }
+    document.record_revision(
+        draft.timestamp.clone(),
+        config.initial_change.clone(),
+        draft.authors.first().map(String::as_str),
+    )?;
+    document.apply_list_style(config);
+    Ok(document.render())
 }
 
 fn is_numeric_selector(value: &str) -> bool {
     let normalized = value.trim();
     !normalized.is_empty() && normalized.chars().all(|ch| ch.is_ascii_digit())
 }
+
+#[derive(Debug, Serialize)]
+struct RfcNewResponseJson {
+    schema_version: u32,
+    path: String,
+    rfc_id: String,
+    title: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{RfcDocument, write_backlinks};
+
+    #[test]
+    fn failed_backlink_restores_targets_and_removes_new_rfc() {
+        let dir = std::env::temp_dir().join(format!("agx-backlinks-{}", std::process::id()));
+        fs::create_dir_all(&dir).expect("failed to create temp dir");
+        let new_rfc = dir.join("0003-new.md");
+        let target = dir.join("0001-old.md");
+        let original = "+++\nrfc = \"0001\"\ntitle = \"Old\"\n+++\n\n# RFC 0001: Old\n";
+        fs::write(&new_rfc, "new").expect("failed to write new RFC");
+        fs::write(&target, original).expect("failed to write target");
+
+        let mut linked = RfcDocument::read(&target).expect("target should parse");
+        linked.set_references("superseded_by", &[3]);
+        let mut missing = RfcDocument::parse(&dir.join("missing/0002-gone.md"), original)
+            .expect("document should parse");
+        missing.set_references("superseded_by", &[3]);
+
+        let error = write_backlinks(&[linked, missing], &new_rfc).expect_err("expected error");
+        assert!(format!("{error:#}").contains("0003-new.md"));
+        assert_eq!(
+            fs::read_to_string(&target).expect("target should remain"),
+            original
+        );
+        assert!(!new_rfc.exists());
+
+        fs::remove_dir_all(dir).expect("failed to clean temp dir");
+    }
+}
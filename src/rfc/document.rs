@@ -0,0 +1,366 @@
+//! RFC markdown documents: TOML frontmatter plus markdown body.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, anyhow, bail};
+use toml_edit::{Array, ArrayOfTables, DocumentMut, Item, Table, Value, value};
+
+use super::{
+    config::RfcConfig,
+    util::{rfc_file_id, toml_escape},
+};
+
+/// Metadata fields that hold RFC id references.
+pub(crate) const REFERENCE_FIELDS: [&str; 3] = ["prerequisite", "supersedes", "superseded_by"];
+
+/// One `[[revision]]` entry from RFC metadata.
+pub(crate) struct RfcRevision {
+    pub(crate) date: String,
+    pub(crate) change: String,
+    pub(crate) by: Option<String>,
+}
+
+/// A parsed RFC file whose frontmatter can be edited in place.
+pub(crate) struct RfcDocument {
+    pub(crate) path: PathBuf,
+    pub(crate) metadata: DocumentMut,
+    pub(crate) body: String,
+    /// Whether the source file mostly used `\r\n` line endings; [`Self::write`]
+    /// re-emits them so revising does not rewrite every line on Windows.
+    pub(crate) crlf: bool,
+}
+
+impl RfcDocument {
+    /// Read and parse an RFC file.
+    pub(crate) fn read(path: &Path) -> Result<Self> {
+        let markdown = fs::read_to_string(path)
+            .with_context(|| format!("failed to read RFC file {}", path.display()))?;
+        Self::parse(path, &markdown)
+    }
+
+    /// Parse RFC markdown that belongs at `path`.
+    pub(crate) fn parse(path: &Path, markdown: &str) -> Result<Self> {
+        let (frontmatter, body) = split_frontmatter(markdown)?;
+        let metadata = frontmatter
+            .parse::<DocumentMut>()
+            .context("failed to parse RFC TOML frontmatter")?;
+        Ok(Self {
+            path: path.to_path_buf(),
+            metadata,
+            body,
+            crlf: uses_crlf(markdown),
+        })
+    }
+
+    /// Numeric RFC id from the `rfc` field.
+    pub(crate) fn id(&self) -> Result<u32> {
+        parse_rfc_id_item(
+            self.metadata
+                .get("rfc")
+                .ok_or_else(|| anyhow!("metadata is missing required `rfc` field"))?,
+        )
+    }
+
+    /// RFC title from the `title` field.
+    pub(crate) fn title(&self) -> Result<&str> {
+        self.metadata
+            .get("title")
+            .and_then(|item| item.as_str())
+            .ok_or_else(|| anyhow!("metadata is missing required `title` field"))
+    }
+
+    /// Integer RFC ids stored under a reference field; missing fields are empty.
+    pub(crate) fn references(&self, key: &str) -> Result<Vec<u32>> {
+        let Some(item) = self.metadata.get(key) else {
+            return Ok(Vec::new());
+        };
+        let Some(array) = item.as_array() else {
+            bail!("metadata field `{key}` exists but is not an array");
+        };
+
+        array
+            .iter()
+            .map(|entry| {
+                entry
+                    .as_integer()
+                    .and_then(|id| u32::try_from(id).ok())
+                    .ok_or_else(|| anyhow!("metadata field `{key}` must contain RFC ids"))
+            })
+            .collect()
+    }
+
+    /// Replace an integer array field.
+    pub(crate) fn set_references(&mut self, key: &str, values: &[u32]) {
+        let mut array = Array::new();
+        for entry in values {
+            array.push(i64::from(*entry));
+        }
+        self.set_value(key, array);
+    }
+
+    /// Set a top-level field, keeping the comment and spacing attached to the
+    /// value it replaces (for example `title = "x"  # note`).
+    pub(crate) fn set_value(&mut self, key: &str, new: impl Into<Value>) {
+        let mut new = new.into();
+        if let Some(existing) = self.metadata.get(key).and_then(Item::as_value) {
+            *new.decor_mut() = existing.decor().clone();
+        }
+        self.metadata[key] = Item::Value(new);
+    }
+
+    /// `[[revision]]` entries in file order; missing `revision` is empty.
+    pub(crate) fn revisions(&self) -> Result<Vec<RfcRevision>> {
+        let Some(item) = self.metadata.get("revision") else {
+            return Ok(Vec::new());
+        };
+        let Some(revisions) = item.as_array_of_tables() else {
+            bail!("metadata field `revision` exists but is not an array of tables");
+        };
+
+        revisions
+            .iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                let field =
+                    |key: &str| entry.get(key).and_then(Item::as_str).map(ToOwned::to_owned);
+                Ok(RfcRevision {
+                    date: field("date")
+                        .ok_or_else(|| anyhow!("`revision[{index}].date` must be a string"))?,
+                    change: field("change")
+                        .ok_or_else(|| anyhow!("`revision[{index}].change` must be a string"))?,
+                    by: field("by"),
+                })
+            })
+            .collect()
+    }
+
+    /// Backfill a missing `created` field from the first revision date, or
+    /// `fallback` when there is no dated revision.
+    pub(crate) fn ensure_created(&mut self, fallback: &str) {
+        if self.metadata.contains_key("created") {
+            return;
+        }
+
+        let first_revision_date = self
+            .metadata
+            .get("revision")
+            .and_then(Item::as_array_of_tables)
+            .and_then(|revisions| revisions.iter().next())
+            .and_then(|entry| entry.get("date"))
+            .and_then(Item::as_str)
+            .map(ToOwned::to_owned);
+        self.metadata["created"] =
+            value(first_revision_date.unwrap_or_else(|| fallback.to_owned()));
+    }
+
+    /// Set `last_updated` and append a matching `[[revision]]` entry, attributed
+    /// to `by` when given.
+    ///
+    /// `change` is always written as a single-line basic string (escaped with
+    /// [`toml_escape`]), so multi-line messages cannot introduce a `+++` line
+    /// that would end the frontmatter early.
+    pub(crate) fn record_revision(
+        &mut self,
+        date: String,
+        change: String,
+        by: Option<&str>,
+    ) -> Result<()> {
+        self.set_value("last_updated", date.as_str());
+        if !self.metadata.as_table().contains_key("revision") {
+            self.metadata["revision"] = Item::ArrayOfTables(ArrayOfTables::new());
+        }
+
+        let Some(revisions) = self.metadata["revision"].as_array_of_tables_mut() else {
+            bail!("metadata field `revision` exists but is not an array of tables");
+        };
+
+        let mut entry = Table::new();
+        entry["date"] = value(date);
+        let change = format!("\"{}\"", toml_escape(&change))
+            .parse::<Value>()
+            .context("failed to encode revision change")?;
+        entry["change"] = Item::Value(change);
+        if let Some(by) = by {
+            entry["by"] = value(by);
+        }
+        revisions.push(entry);
+        Ok(())
+    }
+
+    /// Apply the configured list style to `authors`, `agents`, `labels`, and
+    /// reference fields.
+    pub(crate) fn apply_list_style(&mut self, config: &RfcConfig) {
+        if config.sort_authors
+            && let Some(authors) = self
+                .metadata
+                .get_mut("authors")
+                .and_then(Item::as_array_mut)
+        {
+            let mut sorted = authors
+                .iter()
+                .filter_map(|entry| entry.as_str().map(ToOwned::to_owned))
+                .collect::<Vec<_>>();
+            sorted.sort_by_key(|author| author.to_lowercase());
+            authors.clear();
+            for author in sorted {
+                authors.push(author);
+            }
+        }
+
+        if !config.multiline_arrays {
+            return;
+        }
+        for key in ["authors", "agents", "labels"]
+            .into_iter()
+            .chain(REFERENCE_FIELDS)
+        {
+            let Some(array) = self.metadata.get_mut(key).and_then(Item::as_array_mut) else {
+                continue;
+            };
+            for entry in array.iter_mut() {
+                entry.decor_mut().set_prefix("\n    ");
+                entry.decor_mut().set_suffix("");
+            }
+            array.set_trailing_comma(!array.is_empty());
+            array.set_trailing("\n");
+        }
+    }
+
+    /// Render the document back to markdown with `+++` frontmatter markers.
+    pub(crate) fn render(&self) -> String {
+        let mut rendered = String::new();
+        rendered.push_str("+++\n");
+        let mut serialized_frontmatter = self.metadata.to_string();
+        if !serialized_frontmatter.ends_with('\n') {
+            serialized_frontmatter.push('\n');
+        }
+        rendered.push_str(&serialized_frontmatter);
+        rendered.push_str("+++\n\n");
+        rendered.push_str(self.body.trim_start_matches('\n'));
+        if !rendered.ends_with('\n') {
+            rendered.push('\n');
+        }
+        rendered
+    }
+
+    /// Write the rendered document back to its path, restoring `\r\n` line
+    /// endings when the source used them.
+    pub(crate) fn write(&self) -> Result<()> {
+        self.write_to(&self.path)
+    }
+
+    /// Like [`Self::write`], but to `path` instead of the document's own path.
+    pub(crate) fn write_to(&self, path: &Path) -> Result<()> {
+        let mut rendered = self.render();
+        if self.crlf {
+            rendered = rendered.replace('\n', "\r\n");
+        }
+        fs::write(path, rendered).with_context(|| format!("failed to update {}", path.display()))
+    }
+}
+
+/// Numbered RFC markdown files under `rfc_dir`, sorted by file name.
+///
+/// Any run of leading digits counts as the id, whatever `rfc.id_width` is set
+/// to. The `0000-template.md` file is skipped.
+pub(crate) fn rfc_paths(rfc_dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for entry in fs::read_dir(rfc_dir)
+        .with_context(|| format!("failed to read RFC directory {}", rfc_dir.display()))?
+    {
+        let entry = entry?;
+        let path = entry.path();
+        if !path.is_file() || path.extension().and_then(|ext| ext.to_str()) != Some("md") {
+            continue;
+        }
+
+        let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+            continue;
+        };
+        if file_name == "0000-template.md" {
+            continue;
+        }
+        if rfc_file_id(file_name).is_none() {
+            continue;
+        }
+
+        paths.push(path);
+    }
+
+    paths.sort();
+    Ok(paths)
+}
+
+/// Whether `\r\n` outnumbers bare `\n` line endings in `text`.
+fn uses_crlf(text: &str) -> bool {
+    let crlf = text.matches("\r\n").count();
+    crlf > text.matches('\n').count() - crlf
+}
+
+fn parse_rfc_id_item(item: &Item) -> Result<u32> {
+    if let Some(value) = item.as_str() {
+        return value
+            .parse::<u32>()
+            .with_context(|| format!("invalid RFC id `{value}`"));
+    }
+    if let Some(value) = item.as_integer() {
+        let parsed = u32::try_from(value).context("RFC id must be a non-negative integer")?;
+        return Ok(parsed);
+    }
+
+    bail!("RFC id field must be a string or integer")
+}
+
+fn split_frontmatter(markdown: &str) -> Result<(String, String)> {
+    let normalized = markdown.replace("\r\n", "\n");
+    if !normalized.starts_with("+++\n") {
+        bail!("RFC file does not start with TOML frontmatter marker `+++`");
+    }
+
+    let rest = &normalized[4..];
+    if let Some(end) = rest.find("\n+++\n") {
+        let frontmatter = rest[..end].to_owned();
+        let body = rest[end + 5..].to_owned();
+        return Ok((frontmatter, body));
+    }
+    if let Some(end) = rest.find("\n+++") {
+        let frontmatter = rest[..end].to_owned();
+        let mut body = rest[end + 4..].to_owned();
+        if body.starts_with('\n') {
+            body = body[1..].to_owned();
+        }
+        return Ok((frontmatter, body));
+    }
+
+    bail!("missing closing TOML frontmatter marker `+++`");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{split_frontmatter, uses_crlf};
+
+    #[test]
+    fn split_frontmatter_parses_metadata_and_body() {
+        let markdown = "+++\nrfc = \"0001\"\n+++\n\n# RFC 0001: Title\n";
+        let (frontmatter, body) = split_frontmatter(markdown).expect("frontmatter should parse");
+        assert_eq!(frontmatter.trim(), "rfc = \"0001\"");
+        assert_eq!(body.trim(), "# RFC 0001: Title");
+    }
+
+    #[test]
+    fn uses_crlf_follows_the_dominant_line_ending() {
+        assert!(uses_crlf("+++\r\nrfc = 1\r\n+++\r\n"));
+        assert!(uses_crlf("a\r\nb\r\nc\n"));
+        assert!(!uses_crlf("a\nb\r\nc\n"));
+        assert!(!uses_crlf("no newline"));
+    }
+
+    #[test]
+    fn split_frontmatter_rejects_missing_markers() {
+        let error = split_frontmatter("# RFC 0001: Title").expect_err("expected error");
+        assert!(error.to_string().contains("frontmatter marker"));
+    }
+}
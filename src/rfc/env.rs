@@ -0,0 +1,98 @@
+use std::collections::BTreeMap;
+
+use anyhow::Result;
+use serde::Serialize;
+use toml_edit::Item;
+
+use crate::cli::{RfcEnvArgs, RfcEnvFormat};
+
+use super::{
+    config::RfcConfig, document::RfcDocument, lookup::locate_existing_rfc, util::format_rfc_id,
+};
+
+/// Print one RFC's metadata as `AGX_RFC_*` shell assignments or JSON.
+///
+/// Missing optional fields are emitted as empty strings so every variable is
+/// always defined.
+pub(crate) fn run(args: &RfcEnvArgs) -> Result<()> {
+    let path = locate_existing_rfc(&args.rfc)?;
+    let document = RfcDocument::read(&path)?;
+    let string_field = |key: &str| {
+        document
+            .metadata
+            .get(key)
+            .and_then(Item::as_str)
+            .unwrap_or_default()
+            .to_owned()
+    };
+    let authors = document
+        .metadata
+        .get("authors")
+        .and_then(Item::as_array)
+        .map(|authors| {
+            authors
+                .iter()
+                .filter_map(|author| author.as_str())
+                .collect::<Vec<_>>()
+                .join(",")
+        })
+        .unwrap_or_default();
+
+    let variables = [
+        (
+            "AGX_RFC_ID",
+            format_rfc_id(document.id()?, RfcConfig::load()?.id_width),
+        ),
+        ("AGX_RFC_TITLE", document.title()?.to_owned()),
+        ("AGX_RFC_STATUS", string_field("status")),
+        ("AGX_RFC_AUTHORS", authors),
+        ("AGX_RFC_PATH", path.to_string_lossy().into_owned()),
+    ];
+
+    match args.format {
+        RfcEnvFormat::Text => {
+            for (name, value) in &variables {
+                println!("{name}={}", shell_quote(value));
+            }
+        }
+        RfcEnvFormat::Json => {
+            let payload = RfcEnvResponseJson {
+                schema_version: 1,
+                variables: variables.into_iter().collect(),
+            };
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+        }
+    }
+    Ok(())
+}
+
+/// Quote `value` for POSIX shells, leaving plain words bare.
+fn shell_quote(value: &str) -> String {
+    let plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|ch| ch.is_ascii_alphanumeric() || "_-./:,@+%".contains(ch));
+    if plain {
+        return value.to_owned();
+    }
+    format!("'{}'", value.replace('\'', r"'\''"))
+}
+
+#[derive(Debug, Serialize)]
+struct RfcEnvResponseJson {
+    schema_version: u32,
+    variables: BTreeMap<&'static str, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::shell_quote;
+
+    #[test]
+    fn shell_quote_wraps_words_needing_escapes() {
+        assert_eq!(shell_quote("0001"), "0001");
+        assert_eq!(shell_quote(""), "''");
+        assert_eq!(shell_quote("Add parser"), "'Add parser'");
+        assert_eq!(shell_quote("Roger's $HOME"), r"'Roger'\''s $HOME'");
+    }
+}
@@ -0,0 +1,130 @@
+use anyhow::Result;
+
+use crate::cli::{RfcGraphArgs, RfcGraphFormat};
+use crate::output;
+
+use super::{
+    config::RfcConfig,
+    document::{REFERENCE_FIELDS, RfcDocument, rfc_paths},
+    template::resolve_project_rfc_dir,
+    util::format_rfc_id,
+};
+
+/// One RFC in the graph, with its outgoing reference edges.
+struct GraphNode {
+    rfc: u32,
+    title: String,
+    /// `(relation, target id)` pairs in field order.
+    edges: Vec<(&'static str, u32)>,
+}
+
+/// Print the RFC reference graph as Graphviz DOT or a Mermaid flowchart.
+///
+/// Every RFC becomes a `NNNN: Title` node (ids padded to `rfc.id_width`
+/// digits) with an edge to each id listed in
+/// its `prerequisite`, `supersedes`, or `superseded_by` field, labeled by the
+/// field name. Supersession edges are dashed (`supersedes`) or dotted
+/// (`superseded_by`) so they stand apart from prerequisites.
+pub(crate) fn run(args: &RfcGraphArgs) -> Result<()> {
+    let nodes = collect_nodes()?;
+    let id_width = RfcConfig::load()?.id_width;
+    match args.format {
+        RfcGraphFormat::Dot => print!("{}", render_dot(&nodes, id_width)),
+        RfcGraphFormat::Mermaid => print!("{}", render_mermaid(&nodes, id_width)),
+    }
+    Ok(())
+}
+
+/// Read every RFC under the project RFC directory, sorted by id.
+///
+/// Files that fail to parse are reported as warnings and skipped.
+fn collect_nodes() -> Result<Vec<GraphNode>> {
+    let rfc_dir = resolve_project_rfc_dir()?;
+    if !rfc_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut nodes = Vec::new();
+    for path in rfc_paths(&rfc_dir)? {
+        let node = RfcDocument::read(&path).and_then(|document| {
+            let mut edges = Vec::new();
+            for field in REFERENCE_FIELDS {
+                for target in document.references(field)? {
+                    edges.push((field, target));
+                }
+            }
+            Ok(GraphNode {
+                rfc: document.id()?,
+                title: document.title()?.to_owned(),
+                edges,
+            })
+        });
+        match node {
+            Ok(node) => nodes.push(node),
+            Err(error) => {
+                output::print_warning(format!("skipping {}: {error:#}", path.display()));
+            }
+        }
+    }
+    nodes.sort_by_key(|node| node.rfc);
+    Ok(nodes)
+}
+
+fn render_dot(nodes: &[GraphNode], id_width: usize) -> String {
+    let id = |rfc: u32| format_rfc_id(rfc, id_width);
+    let mut rendered = String::from("digraph rfcs {\n    node [shape=box];\n");
+    for node in nodes {
+        let rfc = id(node.rfc);
+        rendered.push_str(&format!(
+            "    rfc{rfc} [label=\"{}\"];\n",
+            dot_escape(&format!("{rfc}: {}", node.title))
+        ));
+    }
+    for node in nodes {
+        for (relation, target) in &node.edges {
+            let style = match *relation {
+                "supersedes" => ", style=dashed",
+                "superseded_by" => ", style=dotted",
+                _ => "",
+            };
+            rendered.push_str(&format!(
+                "    rfc{} -> rfc{} [label=\"{relation}\"{style}];\n",
+                id(node.rfc),
+                id(*target)
+            ));
+        }
+    }
+    rendered.push_str("}\n");
+    rendered
+}
+
+fn render_mermaid(nodes: &[GraphNode], id_width: usize) -> String {
+    let id = |rfc: u32| format_rfc_id(rfc, id_width);
+    let mut rendered = String::from("graph LR\n");
+    for node in nodes {
+        let rfc = id(node.rfc);
+        rendered.push_str(&format!(
+            "    rfc{rfc}[\"{rfc}: {}\"]\n",
+            node.title.replace('"', "#quot;")
+        ));
+    }
+    for node in nodes {
+        for (relation, target) in &node.edges {
+            let arrow = if *relation == "prerequisite" {
+                "-->"
+            } else {
+                "-.->"
+            };
+            rendered.push_str(&format!(
+                "    rfc{} {arrow}|{relation}| rfc{}\n",
+                id(node.rfc),
+                id(*target)
+            ));
+        }
+    }
+    rendered
+}
+
+fn dot_escape(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -0,0 +1,121 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result, bail};
+use toml_edit::Item;
+
+use crate::cli::RfcIndexArgs;
+use crate::output;
+
+use super::{
+    config::RfcConfig,
+    document::{RfcDocument, rfc_paths},
+    util::{RFC_DIR, format_rfc_id},
+};
+
+const INDEX_FILE: &str = "README.md";
+
+/// Line separating hand-written content from the generated table.
+const GENERATED_MARKER: &str = "<!-- Generated by `agx rfc index`; do not edit by hand. -->";
+
+/// Heading written above the marker when the index does not exist yet.
+const DEFAULT_PREAMBLE: &str = "# RFC Index\n\n";
+
+/// Write (or print) a markdown table of every RFC sorted by id.
+///
+/// Content above the generated-index marker in an existing `rfc/README.md` is
+/// kept; everything from the marker down is regenerated. `--check` compares
+/// the regenerated index with the file on disk instead of writing it.
+pub(crate) fn run(args: &RfcIndexArgs) -> Result<()> {
+    let rfc_dir = Path::new(RFC_DIR);
+    if !rfc_dir.is_dir() {
+        bail!("RFC directory does not exist at {}", rfc_dir.display());
+    }
+
+    let id_width = RfcConfig::load()?.id_width;
+    let mut rows = Vec::new();
+    for path in rfc_paths(rfc_dir)? {
+        let document = RfcDocument::read(&path)?;
+        let file_name = path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let status = document
+            .metadata
+            .get("status")
+            .and_then(|item| item.as_str())
+            .unwrap_or("-")
+            .to_owned();
+        let authors = document
+            .metadata
+            .get("authors")
+            .and_then(Item::as_array)
+            .map(|authors| {
+                authors
+                    .iter()
+                    .filter_map(|author| author.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            })
+            .filter(|authors| !authors.is_empty())
+            .unwrap_or_else(|| "-".to_owned());
+        rows.push((
+            document.id()?,
+            document.title()?.to_owned(),
+            file_name,
+            status,
+            authors,
+        ));
+    }
+    rows.sort_by_key(|(id, ..)| *id);
+
+    let index_path = rfc_dir.join(INDEX_FILE);
+    let existing = if index_path.is_file() {
+        Some(
+            fs::read_to_string(&index_path)
+                .with_context(|| format!("failed to read {}", index_path.display()))?,
+        )
+    } else {
+        None
+    };
+    let preamble = existing
+        .as_deref()
+        .and_then(|existing| existing.find(GENERATED_MARKER).map(|at| &existing[..at]))
+        .unwrap_or(DEFAULT_PREAMBLE);
+
+    let mut index = format!(
+        "{preamble}{GENERATED_MARKER}\n\n| RFC | Title | Status | Authors |\n| --- | --- | --- | --- |\n"
+    );
+    for (id, title, file_name, status, authors) in rows {
+        index.push_str(&format!(
+            "| {} | [{}]({file_name}) | {} | {} |\n",
+            format_rfc_id(id, id_width),
+            escape_cell(&title),
+            escape_cell(&status),
+            escape_cell(&authors)
+        ));
+    }
+
+    if args.stdout {
+        print!("{index}");
+        return Ok(());
+    }
+    if args.check {
+        if existing.as_deref() != Some(index.as_str()) {
+            bail!(
+                "{} is out of date; run `agx rfc index` to regenerate it",
+                index_path.display()
+            );
+        }
+        output::print_log(format!("{} is up to date", index_path.display()));
+        return Ok(());
+    }
+
+    fs::write(&index_path, index)
+        .with_context(|| format!("failed to write {}", index_path.display()))?;
+    output::print_path(index_path.display());
+    Ok(())
+}
+
+fn escape_cell(value: &str) -> String {
+    value.replace('|', "\\|")
+}
@@ -0,0 +1,31 @@
+//! Prefill RFC inputs from a tracked issue (`rfc new --from-issue`).
+
+use std::process::Command;
+
+use anyhow::{Context, Result, bail};
+
+/// Read an issue title through the GitHub CLI.
+///
+/// `issue` is anything `gh issue view` accepts: an issue URL, or a number for
+/// the current repository.
+pub(crate) fn fetch_issue_title(issue: &str) -> Result<String> {
+    let output = Command::new("gh")
+        .args(["issue", "view", issue, "--json", "title", "--jq", ".title"])
+        .output()
+        .context("failed to execute `gh issue view`; install the GitHub CLI or pass <title>")?;
+    if !output.status.success() {
+        bail!(
+            "failed to read the title of issue `{issue}` with `gh issue view`: {}",
+            String::from_utf8_lossy(&output.stderr).trim()
+        );
+    }
+
+    let title = String::from_utf8(output.stdout)
+        .context("issue title is not valid UTF-8")?
+        .trim()
+        .to_owned();
+    if title.is_empty() {
+        bail!("issue `{issue}` has an empty title; pass <title> instead");
+    }
+    Ok(title)
+}
@@ -0,0 +1,131 @@
+use anyhow::Result;
+use serde::Serialize;
+use toml_edit::Item;
+
+use crate::cli::{RfcListArgs, RfcListFormat};
+use crate::output;
+
+use super::{
+    config::RfcConfig,
+    document::{RfcDocument, rfc_paths},
+    template::resolve_project_rfc_dir,
+    util::format_rfc_id,
+};
+
+/// Print every RFC in the project RFC directory sorted by id.
+pub(crate) fn run(args: &RfcListArgs) -> Result<()> {
+    let mut entries = collect_rfcs()?;
+    if let Some(label) = &args.label {
+        entries.retain(|entry| {
+            entry
+                .labels
+                .iter()
+                .any(|candidate| candidate.eq_ignore_ascii_case(label))
+        });
+    }
+    match args.format {
+        RfcListFormat::Text => {
+            let id_width = RfcConfig::load()?.id_width;
+            println!("rfc\ttitle\tauthors\tlast_updated\tupdated_by");
+            for entry in &entries {
+                println!(
+                    "{}\t{}\t{}\t{}\t{}",
+                    format_rfc_id(entry.rfc, id_width),
+                    entry.title,
+                    entry.authors.join(", "),
+                    entry.last_updated.as_deref().unwrap_or("-"),
+                    entry.updated_by.as_deref().unwrap_or("-")
+                );
+            }
+        }
+        RfcListFormat::Json => {
+            let payload = RfcListResponseJson {
+                schema_version: 1,
+                rfcs: entries,
+            };
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+        }
+    }
+    Ok(())
+}
+
+/// Read every RFC under the project RFC directory, sorted by id.
+///
+/// Files that fail to parse are reported as warnings and skipped; a missing
+/// RFC directory yields an empty list.
+pub(crate) fn collect_rfcs() -> Result<Vec<RfcListEntry>> {
+    let rfc_dir = resolve_project_rfc_dir()?;
+    if !rfc_dir.is_dir() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries = Vec::new();
+    for path in rfc_paths(&rfc_dir)? {
+        match RfcDocument::read(&path).and_then(|document| RfcListEntry::from_document(&document)) {
+            Ok(entry) => entries.push(entry),
+            Err(error) => {
+                output::print_warning(format!("skipping {}: {error:#}", path.display()));
+            }
+        }
+    }
+    entries.sort_by_key(|entry| entry.rfc);
+    Ok(entries)
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct RfcListEntry {
+    pub(crate) rfc: u32,
+    pub(crate) title: String,
+    pub(crate) authors: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub(crate) labels: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) status: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) last_updated: Option<String>,
+    /// Author of the latest `rfc revise`, when recorded.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) updated_by: Option<String>,
+    pub(crate) path: String,
+}
+
+impl RfcListEntry {
+    fn from_document(document: &RfcDocument) -> Result<Self> {
+        let string_field = |key: &str| {
+            document
+                .metadata
+                .get(key)
+                .and_then(Item::as_str)
+                .map(ToOwned::to_owned)
+        };
+        let string_array = |key: &str| {
+            document
+                .metadata
+                .get(key)
+                .and_then(Item::as_array)
+                .map(|entries| {
+                    entries
+                        .iter()
+                        .filter_map(|entry| entry.as_str().map(ToOwned::to_owned))
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        Ok(Self {
+            rfc: document.id()?,
+            title: document.title()?.to_owned(),
+            authors: string_array("authors"),
+            labels: string_array("labels"),
+            status: string_field("status"),
+            last_updated: string_field("last_updated"),
+            updated_by: string_field("updated_by"),
+            path: document.path.to_string_lossy().into_owned(),
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RfcListResponseJson {
+    schema_version: u32,
+    rfcs: Vec<RfcListEntry>,
+}
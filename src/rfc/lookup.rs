@@ -3,11 +3,21 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use anyhow::{Context, Result, bail};
+use anyhow::{Context, Result, anyhow, bail};
 
-use super::util::{RFC_DIR, slugify};
+use super::{
+    document::rfc_paths,
+    util::{RFC_DIR, rfc_file_id, slugify},
+};
+
+/// Keywords that select the highest-numbered RFC.
+const LATEST_SELECTORS: [&str; 2] = ["latest", "last"];
 
 pub(crate) fn locate_existing_rfc(selector: &str) -> Result<PathBuf> {
+    if LATEST_SELECTORS.contains(&selector) {
+        return locate_latest_rfc();
+    }
+
     let candidates = collect_rfc_candidates()?;
     if selector.chars().all(|ch| ch.is_ascii_digit()) {
         return select_rfc_by_id(selector, &candidates);
@@ -42,6 +52,19 @@ pub(crate) fn locate_existing_rfc(selector: &str) -> Result<PathBuf> {
     choose_single_match(matches, selector)
 }
 
+/// The RFC file with the highest numeric prefix, so `10000-*.md` beats
+/// `9999-*.md` even though it sorts first by name.
+fn locate_latest_rfc() -> Result<PathBuf> {
+    rfc_paths(Path::new(RFC_DIR))?
+        .into_iter()
+        .max_by_key(|path| {
+            path.file_name()
+                .and_then(|name| name.to_str())
+                .and_then(rfc_file_id)
+        })
+        .ok_or_else(|| anyhow!("no RFCs found under {RFC_DIR}; create one with `agx rfc new`"))
+}
+
 fn collect_rfc_candidates() -> Result<Vec<(String, PathBuf)>> {
     let mut candidates = Vec::new();
     for entry in fs::read_dir(RFC_DIR).context("failed to read RFC directory")? {
@@ -69,10 +92,10 @@ fn collect_rfc_candidates() -> Result<Vec<(String, PathBuf)>> {
 }
 
 fn select_rfc_by_id(selector: &str, candidates: &[(String, PathBuf)]) -> Result<PathBuf> {
-    let id_match = format!("{:04}", selector.parse::<u32>()?);
+    let id = selector.parse::<u32>()?;
     let matches = candidates
         .iter()
-        .filter(|(name, _)| name.starts_with(&id_match))
+        .filter(|(name, _)| rfc_file_id(name) == Some(id))
         .map(|(_, path)| path.clone())
         .collect::<Vec<_>>();
     choose_single_match(matches, selector)
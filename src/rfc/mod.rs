@@ -3,10 +3,38 @@
 //! - `rfc init`: create RFC directory, seed template, and require `.agents/skills`.
 //! - `rfc new`: render a new RFC markdown file from the resolved template.
 //! - `rfc revise`: update an existing RFC in place and append a revision entry.
+//! - `rfc validate`: check RFC metadata and, optionally, reference targets.
+//! - `rfc stats`: report body word and section counts.
+//! - `rfc referrers`: list RFCs whose metadata references a given RFC.
+//! - `rfc index`: write `rfc/README.md` with a table of all RFCs.
+//! - `rfc changelog`: print revision entries from all RFCs in date order.
+//! - `rfc set-status`: transition `status` on every RFC matching a query.
+//! - `rfc env`: print one RFC's metadata as shell variable assignments.
+//! - `rfc list`: print a table of every RFC sorted by id.
+//! - `rfc board`: print a markdown checklist of RFCs grouped by status.
+//! - `rfc show`: print one RFC's metadata and body.
+//! - `rfc graph`: print the RFC reference graph as Graphviz DOT or Mermaid.
+//! - `rfc renumber`: rename files to match their ids and optionally close id gaps.
+pub(crate) mod board;
+pub(crate) mod changelog;
+mod config;
 pub(crate) mod create;
+mod document;
+pub(crate) mod env;
+pub(crate) mod graph;
+pub(crate) mod index;
 pub(crate) mod init;
+mod issue;
+pub(crate) mod list;
 mod lookup;
 mod reference;
+pub(crate) mod referrers;
+pub(crate) mod renumber;
 pub(crate) mod revise;
+pub(crate) mod set_status;
+pub(crate) mod show;
+pub(crate) mod stats;
+mod status;
 mod template;
-mod util;
+pub(crate) mod util;
+pub(crate) mod validate;
@@ -0,0 +1,143 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    path::Path,
+};
+
+use anyhow::{Result, bail};
+
+use super::RfcTitleIndex;
+use crate::rfc::util::format_rfc_id;
+
+/// Reject `prerequisites` for RFC `rfc_id` (stored under `rfc_dir`) that
+/// would list the RFC itself or close a cycle through existing RFCs'
+/// `prerequisite` fields.
+///
+/// The RFC index is loaded once, and only when `prerequisites` is non-empty.
+/// References from an RFC outside the project RFC directory (for example a
+/// member crate's `rfc/` pointing at workspace RFCs) cannot form cycles and
+/// are not checked. The error names every RFC on the cycle, for example
+/// `0001 -> 0002 -> 0001`.
+pub(crate) fn ensure_acyclic_prerequisites(
+    rfc_dir: &Path,
+    rfc_id: u32,
+    prerequisites: &[u32],
+) -> Result<()> {
+    if prerequisites.is_empty() {
+        return Ok(());
+    }
+    let index = RfcTitleIndex::load()?;
+    if rfc_dir.canonicalize().ok() != index.rfc_dir.canonicalize().ok() {
+        return Ok(());
+    }
+    if prerequisites.contains(&rfc_id) {
+        bail!(
+            "RFC {} cannot list itself as a prerequisite",
+            format_rfc_id(rfc_id, index.id_width)
+        );
+    }
+
+    let mut graph = index
+        .entries
+        .into_iter()
+        .map(|entry| (entry.id, entry.prerequisites))
+        .collect::<BTreeMap<_, _>>();
+    graph.insert(rfc_id, prerequisites.to_vec());
+
+    if let Some(cycle) = find_cycle_through(rfc_id, &graph) {
+        let chain = cycle
+            .iter()
+            .map(|id| format_rfc_id(*id, index.id_width))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        bail!("prerequisite cycle detected: {chain}");
+    }
+    Ok(())
+}
+
+/// Reject `supersedes` for RFC `rfc_id` (stored under `rfc_dir`) that would
+/// list the RFC itself or close a supersession cycle.
+///
+/// Supersession edges come from existing RFCs' `supersedes` fields and, in
+/// reverse, their `superseded_by` fields, so a dangling link to `rfc_id` that
+/// was recorded ahead of time is caught too. Like
+/// [`ensure_acyclic_prerequisites`], RFCs outside the project RFC directory
+/// are not checked.
+pub(crate) fn ensure_acyclic_supersedes(
+    rfc_dir: &Path,
+    rfc_id: u32,
+    supersedes: &[u32],
+) -> Result<()> {
+    if supersedes.is_empty() {
+        return Ok(());
+    }
+    let index = RfcTitleIndex::load()?;
+    if rfc_dir.canonicalize().ok() != index.rfc_dir.canonicalize().ok() {
+        return Ok(());
+    }
+    if supersedes.contains(&rfc_id) {
+        bail!(
+            "RFC {} cannot supersede itself",
+            format_rfc_id(rfc_id, index.id_width)
+        );
+    }
+
+    let mut graph = BTreeMap::<u32, Vec<u32>>::new();
+    for entry in index.entries {
+        graph.entry(entry.id).or_default().extend(&entry.supersedes);
+        for newer in entry.superseded_by {
+            graph.entry(newer).or_default().push(entry.id);
+        }
+    }
+    graph.entry(rfc_id).or_default().extend(supersedes);
+
+    if let Some(cycle) = find_cycle_through(rfc_id, &graph) {
+        let chain = cycle
+            .iter()
+            .map(|id| format_rfc_id(*id, index.id_width))
+            .collect::<Vec<_>>()
+            .join(" -> ");
+        bail!("supersession cycle detected: {chain}");
+    }
+    Ok(())
+}
+
+/// Depth-first search for a reference path from `start` back to itself,
+/// returned as the ids along the cycle (starting and ending with `start`).
+fn find_cycle_through(start: u32, graph: &BTreeMap<u32, Vec<u32>>) -> Option<Vec<u32>> {
+    let mut visited = BTreeSet::new();
+    let mut path = vec![start];
+    let mut stack = vec![graph.get(&start)?.iter()];
+    while let Some(next) = stack.last_mut() {
+        let Some(&id) = next.next() else {
+            stack.pop();
+            path.pop();
+            continue;
+        };
+        if id == start {
+            path.push(start);
+            return Some(path);
+        }
+        if !visited.insert(id) {
+            continue;
+        }
+        if let Some(edges) = graph.get(&id) {
+            path.push(id);
+            stack.push(edges.iter());
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::find_cycle_through;
+
+    #[test]
+    fn find_cycle_through_reports_cycle_members() {
+        let graph = BTreeMap::from([(1, vec![2]), (2, vec![4, 3]), (3, vec![1]), (4, vec![])]);
+        assert_eq!(find_cycle_through(1, &graph), Some(vec![1, 2, 3, 1]));
+        assert_eq!(find_cycle_through(4, &graph), None);
+    }
+}
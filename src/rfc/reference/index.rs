@@ -0,0 +1,193 @@
+use std::path::PathBuf;
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::rfc::{
+    config::RfcConfig,
+    document::{RfcDocument, rfc_paths},
+    template::resolve_project_rfc_dir,
+    util::{format_rfc_id, slugify},
+};
+
+pub(super) struct RfcTitleIndex {
+    pub(super) entries: Vec<RfcTitleEntry>,
+    pub(super) rfc_dir: PathBuf,
+    /// `rfc.id_width`, for rendering ids in messages.
+    pub(super) id_width: usize,
+}
+
+pub(super) struct RfcTitleEntry {
+    pub(super) id: u32,
+    /// Ids from the RFC's `prerequisite` field, for cycle checks.
+    pub(super) prerequisites: Vec<u32>,
+    /// Ids from the RFC's `supersedes` and `superseded_by` fields, for cycle checks.
+    pub(super) supersedes: Vec<u32>,
+    pub(super) superseded_by: Vec<u32>,
+    pub(super) title: String,
+    pub(super) title_folded: String,
+    pub(super) title_slug: String,
+}
+
+impl RfcTitleIndex {
+    /// Build a searchable title index from RFC files in the resolved RFC dir.
+    pub(super) fn load() -> Result<Self> {
+        let rfc_dir = resolve_project_rfc_dir()?;
+        if !rfc_dir.is_dir() {
+            bail!(
+                "cannot resolve RFC title references: RFC directory does not exist at {}",
+                rfc_dir.display()
+            );
+        }
+
+        let mut entries = Vec::new();
+        for path in rfc_paths(&rfc_dir)? {
+            let entry = RfcDocument::read(&path)
+                .and_then(|document| {
+                    let title = document.title()?.to_owned();
+                    Ok(RfcTitleEntry {
+                        id: document.id()?,
+                        prerequisites: document.references("prerequisite")?,
+                        supersedes: document.references("supersedes")?,
+                        superseded_by: document.references("superseded_by")?,
+                        title_folded: title.trim().to_ascii_lowercase(),
+                        title_slug: slugify(&title),
+                        title,
+                    })
+                })
+                .with_context(|| format!("failed to index RFC file {}", path.display()))?;
+            entries.push(entry);
+        }
+
+        Ok(Self {
+            entries,
+            rfc_dir,
+            id_width: RfcConfig::load()?.id_width,
+        })
+    }
+
+    /// Like [`Self::load`], but an absent RFC directory yields an empty index.
+    pub(super) fn load_or_empty() -> Result<Self> {
+        let rfc_dir = resolve_project_rfc_dir()?;
+        if rfc_dir.is_dir() {
+            return Self::load();
+        }
+        Ok(Self {
+            entries: Vec::new(),
+            rfc_dir,
+            id_width: RfcConfig::load()?.id_width,
+        })
+    }
+
+    /// Whether an RFC with `id` is indexed.
+    pub(super) fn contains(&self, id: u32) -> bool {
+        self.entries.iter().any(|entry| entry.id == id)
+    }
+
+    /// Highest RFC id in the index, for `latest`/`last` references.
+    pub(super) fn latest_id(&self) -> Result<u32> {
+        self.entries
+            .iter()
+            .map(|entry| entry.id)
+            .max()
+            .ok_or_else(|| {
+                anyhow!(
+                    "cannot resolve `latest` reference: no RFCs found under {}",
+                    self.rfc_dir.display()
+                )
+            })
+    }
+
+    /// Resolve a title-like string to a single RFC id.
+    ///
+    /// Matching order:
+    /// 1. Exact title
+    /// 2. Case-insensitive title
+    /// 3. Slugified title
+    pub(super) fn resolve_title(&self, input: &str) -> Result<u32> {
+        let normalized = input.trim();
+        if normalized.is_empty() {
+            bail!("RFC title reference cannot be empty");
+        }
+
+        let exact_matches = self
+            .entries
+            .iter()
+            .filter(|entry| entry.title == normalized)
+            .collect::<Vec<_>>();
+        if exact_matches.len() == 1 {
+            return Ok(exact_matches[0].id);
+        }
+        if exact_matches.len() > 1 {
+            bail!(
+                "RFC title reference `{normalized}` matched multiple RFCs by exact title: {}",
+                self.format_match_list(&exact_matches)
+            );
+        }
+
+        let folded = normalized.to_ascii_lowercase();
+        let folded_matches = self
+            .entries
+            .iter()
+            .filter(|entry| entry.title_folded == folded)
+            .collect::<Vec<_>>();
+        if folded_matches.len() == 1 {
+            return Ok(folded_matches[0].id);
+        }
+        if folded_matches.len() > 1 {
+            bail!(
+                "RFC title reference `{normalized}` matched multiple RFCs by case-insensitive title: {}",
+                self.format_match_list(&folded_matches)
+            );
+        }
+
+        let slug = slugify(normalized);
+        let slug_matches = self
+            .entries
+            .iter()
+            .filter(|entry| entry.title_slug == slug)
+            .collect::<Vec<_>>();
+        if slug_matches.len() == 1 {
+            return Ok(slug_matches[0].id);
+        }
+        if slug_matches.len() > 1 {
+            bail!(
+                "RFC title reference `{normalized}` matched multiple RFCs by slug: {}",
+                self.format_match_list(&slug_matches)
+            );
+        }
+
+        bail!(
+            "unable to resolve RFC title reference `{normalized}` in {}",
+            self.rfc_dir.display()
+        )
+    }
+
+    pub(super) fn find_title_conflicts<'a>(&'a self, input: &str) -> Vec<&'a RfcTitleEntry> {
+        let normalized = input.trim();
+        if normalized.is_empty() {
+            return Vec::new();
+        }
+
+        let folded = normalized.to_ascii_lowercase();
+        let slug = slugify(normalized);
+
+        self.entries
+            .iter()
+            .filter(|entry| entry.title_folded == folded || entry.title_slug == slug)
+            .collect()
+    }
+
+    pub(super) fn format_match_list(&self, matches: &[&RfcTitleEntry]) -> String {
+        matches
+            .iter()
+            .map(|entry| {
+                format!(
+                    "{} ({})",
+                    format_rfc_id(entry.id, self.id_width),
+                    entry.title
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
@@ -0,0 +1,93 @@
+use std::ops::Range;
+
+use anyhow::Result;
+
+use crate::rfc::{
+    config::RfcConfig,
+    document::rfc_paths,
+    template::resolve_project_rfc_dir,
+    util::{dedupe, format_rfc_id, rfc_file_id},
+};
+
+/// RFC ids written inline as `[[id]]` tokens, in order of appearance.
+pub(crate) fn inline_reference_ids(text: &str) -> Vec<u32> {
+    let ids = inline_reference_tokens(text)
+        .into_iter()
+        .map(|(_, id)| id)
+        .collect::<Vec<_>>();
+    dedupe(&ids)
+}
+
+/// Replace `[[id]]` tokens with markdown links to the referenced RFC file, or
+/// plain `RFC NNNN` text when no such file exists.
+pub(crate) fn link_inline_references(text: &str) -> Result<String> {
+    let tokens = inline_reference_tokens(text);
+    if tokens.is_empty() {
+        return Ok(text.to_owned());
+    }
+
+    let rfc_dir = resolve_project_rfc_dir()?;
+    let files = if rfc_dir.is_dir() {
+        rfc_paths(&rfc_dir)?
+            .into_iter()
+            .filter_map(|path| Some(path.file_name()?.to_str()?.to_owned()))
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let id_width = RfcConfig::load()?.id_width;
+    let mut linked = String::new();
+    let mut rest = 0;
+    for (range, id) in tokens {
+        linked.push_str(&text[rest..range.start]);
+        let rfc_id = format_rfc_id(id, id_width);
+        match files.iter().find(|name| rfc_file_id(name) == Some(id)) {
+            Some(file_name) => linked.push_str(&format!("[RFC {rfc_id}]({file_name})")),
+            None => linked.push_str(&format!("RFC {rfc_id}")),
+        }
+        rest = range.end;
+    }
+    linked.push_str(&text[rest..]);
+    Ok(linked)
+}
+
+/// Byte ranges and ids of `[[digits]]` tokens in `text`.
+fn inline_reference_tokens(text: &str) -> Vec<(Range<usize>, u32)> {
+    let mut tokens = Vec::new();
+    let mut search_from = 0;
+    while let Some(offset) = text[search_from..].find("[[") {
+        let start = search_from + offset;
+        let digits_start = start + 2;
+        let digits_len = text[digits_start..]
+            .bytes()
+            .take_while(u8::is_ascii_digit)
+            .count();
+        let digits_end = digits_start + digits_len;
+        if digits_len > 0
+            && text[digits_end..].starts_with("]]")
+            && let Ok(id) = text[digits_start..digits_end].parse::<u32>()
+        {
+            tokens.push((start..digits_end + 2, id));
+            search_from = digits_end + 2;
+        } else {
+            search_from = digits_start;
+        }
+    }
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::inline_reference_ids;
+
+    #[test]
+    fn inline_reference_ids_extracts_bracketed_numbers() {
+        assert_eq!(
+            inline_reference_ids(
+                "Replace [[0003]] and [[12]] but not [[x]] or [[7], again [[0003]]"
+            ),
+            vec![3, 12]
+        );
+    }
+}
@@ -0,0 +1,203 @@
+//! Metadata reference resolution.
+//!
+//! `prerequisite`, `supersedes`, and `superseded_by` accept mixed inputs:
+//! direct RFC ids, RFC titles, or the `latest`/`last` keyword. This module normalizes them into integer id
+//! lists for metadata output.
+
+mod cycle;
+mod index;
+mod inline;
+
+use anyhow::{Result, anyhow, bail};
+
+use crate::cli::{RfcEditArgs, RfcReference};
+use crate::output;
+
+use super::{
+    config::RfcConfig,
+    util::{dedupe, format_rfc_id},
+};
+use index::RfcTitleIndex;
+
+pub(crate) use cycle::{ensure_acyclic_prerequisites, ensure_acyclic_supersedes};
+pub(crate) use inline::{inline_reference_ids, link_inline_references};
+
+/// Integer-only metadata references ready for template rendering or TOML edit.
+pub(crate) struct ResolvedMetadataReferences {
+    pub(crate) prerequisite: Vec<u32>,
+    pub(crate) supersedes: Vec<u32>,
+    pub(crate) superseded_by: Vec<u32>,
+    /// `[[id]]` ids from `--link-inline` text, kept apart so `rfc revise` can
+    /// add them to the RFC's existing `prerequisite` list.
+    pub(crate) inline_prerequisite: Vec<u32>,
+}
+
+/// Resolve all metadata references on the CLI into RFC ids.
+///
+/// Title references are resolved against RFC files under the project RFC
+/// directory (workspace root first, then crate root). An id listed under more
+/// than one reference field is reported as a warning, or an error with
+/// `--strict`. Numeric ids must name an existing RFC unless
+/// `--allow-dangling-refs` is set. `inline_ids` from `[[id]]` tokens count as
+/// `prerequisite` entries for both checks. With
+/// `--infer-prereq-from-supersedes`, superseded ids are then also added to
+/// `prerequisite`.
+pub(crate) fn resolve_metadata_references(
+    cli: &RfcEditArgs,
+    inline_ids: &[u32],
+) -> Result<ResolvedMetadataReferences> {
+    let mut all_references = [&cli.prerequisite, &cli.supersedes, &cli.superseded_by]
+        .into_iter()
+        .flatten();
+    let needs_title_lookup = all_references
+        .clone()
+        .any(|reference| !matches!(reference, RfcReference::Id(_)));
+    let title_index = if needs_title_lookup {
+        Some(RfcTitleIndex::load()?)
+    } else if !cli.allow_dangling_refs
+        && (all_references.next().is_some() || !inline_ids.is_empty())
+    {
+        Some(RfcTitleIndex::load_or_empty()?)
+    } else {
+        None
+    };
+
+    let mut references = ResolvedMetadataReferences {
+        prerequisite: resolve_reference_list(&cli.prerequisite, title_index.as_ref())?,
+        supersedes: resolve_reference_list(&cli.supersedes, title_index.as_ref())?,
+        superseded_by: resolve_reference_list(&cli.superseded_by, title_index.as_ref())?,
+        inline_prerequisite: dedupe(inline_ids),
+    };
+    if !cli.allow_dangling_refs
+        && let Some(index) = &title_index
+    {
+        references.ensure_known(index)?;
+    }
+
+    let id_width = match &title_index {
+        Some(index) => index.id_width,
+        None => RfcConfig::load()?.id_width,
+    };
+    let conflicts = references.conflicts(id_width);
+    if cli.strict && !conflicts.is_empty() {
+        bail!("{}", conflicts.join("; "));
+    }
+    for conflict in conflicts {
+        output::print_warning(conflict);
+    }
+
+    if cli.infer_prereq_from_supersedes {
+        let superseded = references.supersedes.clone();
+        references.extend_prerequisites(&superseded);
+    }
+    Ok(references)
+}
+
+impl ResolvedMetadataReferences {
+    /// Reject ids that do not name an RFC in `index`.
+    fn ensure_known(&self, index: &RfcTitleIndex) -> Result<()> {
+        for (field, ids) in self.fields() {
+            if let Some(id) = ids.iter().find(|id| !index.contains(**id)) {
+                bail!(
+                    "`{field}` references unknown RFC {} (pass --allow-dangling-refs to keep it)",
+                    format_rfc_id(*id, index.id_width)
+                );
+            }
+        }
+        Ok(())
+    }
+
+    /// Reference fields and their ids, with inline ids under `prerequisite`.
+    fn fields(&self) -> [(&'static str, Vec<u32>); 3] {
+        let mut prerequisite = self.prerequisite.clone();
+        prerequisite.extend(&self.inline_prerequisite);
+        [
+            ("prerequisite", dedupe(&prerequisite)),
+            ("supersedes", self.supersedes.clone()),
+            ("superseded_by", self.superseded_by.clone()),
+        ]
+    }
+
+    /// Add `ids` to `prerequisite`, keeping entries unique.
+    pub(crate) fn extend_prerequisites(&mut self, ids: &[u32]) {
+        self.prerequisite.extend_from_slice(ids);
+        self.prerequisite = dedupe(&self.prerequisite);
+    }
+
+    /// Describe ids that appear under more than one reference field, padded
+    /// to `id_width` digits.
+    fn conflicts(&self, id_width: usize) -> Vec<String> {
+        let fields = self.fields();
+        let mut conflicts = Vec::new();
+        for (index, (field, ids)) in fields.iter().enumerate() {
+            for (other_field, other_ids) in &fields[index + 1..] {
+                for id in ids.iter().filter(|id| other_ids.contains(id)) {
+                    conflicts.push(format!(
+                        "RFC {} is listed in both `{field}` and `{other_field}`",
+                        format_rfc_id(*id, id_width)
+                    ));
+                }
+            }
+        }
+        conflicts
+    }
+}
+
+/// Resolve a single CLI reference into an RFC id.
+pub(crate) fn resolve_reference(reference: &RfcReference) -> Result<u32> {
+    match reference {
+        RfcReference::Id(id) => Ok(*id),
+        RfcReference::Latest => RfcTitleIndex::load()?.latest_id(),
+        RfcReference::Title(title) => RfcTitleIndex::load()?.resolve_title(title),
+    }
+}
+
+/// Ensure no existing RFC title conflicts with the provided title.
+///
+/// Conflict checks are performed by case-insensitive title match and slug
+/// match to prevent effectively-duplicate RFC entries.
+pub(crate) fn ensure_unique_rfc_title(title: &str) -> Result<()> {
+    let index = RfcTitleIndex::load()?;
+    let matches = index.find_title_conflicts(title);
+    if matches.is_empty() {
+        return Ok(());
+    }
+
+    let normalized = title.trim();
+    if matches.len() == 1 {
+        let existing = matches[0];
+        bail!(
+            "RFC title `{normalized}` already exists in {} as {} ({})",
+            index.rfc_dir.display(),
+            format_rfc_id(existing.id, index.id_width),
+            existing.title
+        );
+    }
+
+    bail!(
+        "RFC title `{normalized}` conflicts with multiple existing RFCs in {}: {}",
+        index.rfc_dir.display(),
+        index.format_match_list(&matches)
+    )
+}
+
+fn resolve_reference_list(
+    references: &[RfcReference],
+    title_index: Option<&RfcTitleIndex>,
+) -> Result<Vec<u32>> {
+    let mut resolved = Vec::new();
+    for reference in references {
+        match reference {
+            RfcReference::Id(id) => resolved.push(*id),
+            RfcReference::Latest => {
+                let index = title_index.ok_or_else(|| anyhow!("missing title index"))?;
+                resolved.push(index.latest_id()?);
+            }
+            RfcReference::Title(title) => {
+                let index = title_index.ok_or_else(|| anyhow!("missing title index"))?;
+                resolved.push(index.resolve_title(title)?);
+            }
+        }
+    }
+    Ok(dedupe(&resolved))
+}
@@ -0,0 +1,96 @@
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use serde::Serialize;
+
+use crate::cli::{RfcReferrersArgs, RfcReferrersFormat};
+
+use super::{
+    config::RfcConfig,
+    document::{REFERENCE_FIELDS, RfcDocument, rfc_paths},
+    reference::resolve_reference,
+    util::{RFC_DIR, format_rfc_id},
+};
+
+/// List RFCs whose reference fields mention the target RFC, grouped by
+/// relation.
+pub(crate) fn run(args: &RfcReferrersArgs) -> Result<()> {
+    let target = resolve_reference(&args.rfc)?;
+    let rfc_dir = Path::new(RFC_DIR);
+    if !rfc_dir.is_dir() {
+        bail!("RFC directory does not exist at {}", rfc_dir.display());
+    }
+
+    let mut documents = Vec::new();
+    for path in rfc_paths(rfc_dir)? {
+        documents.push(RfcDocument::read(&path)?);
+    }
+
+    let mut groups = Vec::new();
+    for relation in REFERENCE_FIELDS {
+        let mut referrers = Vec::new();
+        for document in &documents {
+            if document.references(relation)?.contains(&target) {
+                referrers.push(RfcReferrerJson {
+                    rfc: document.id()?,
+                    title: document.title()?.to_owned(),
+                    path: document.path.to_string_lossy().into_owned(),
+                });
+            }
+        }
+        if !referrers.is_empty() {
+            groups.push(RfcReferrerGroupJson {
+                relation,
+                referrers,
+            });
+        }
+    }
+
+    match args.format {
+        RfcReferrersFormat::Text => {
+            let id_width = RfcConfig::load()?.id_width;
+            if groups.is_empty() {
+                println!("no RFCs reference {}", format_rfc_id(target, id_width));
+            }
+            for group in &groups {
+                println!("{}:", group.relation);
+                for referrer in &group.referrers {
+                    println!(
+                        "  {}\t{}",
+                        format_rfc_id(referrer.rfc, id_width),
+                        referrer.title
+                    );
+                }
+            }
+        }
+        RfcReferrersFormat::Json => {
+            let payload = RfcReferrersResponseJson {
+                schema_version: 1,
+                rfc: target,
+                relations: groups,
+            };
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct RfcReferrerJson {
+    rfc: u32,
+    title: String,
+    path: String,
+}
+
+#[derive(Debug, Serialize)]
+struct RfcReferrerGroupJson {
+    relation: &'static str,
+    referrers: Vec<RfcReferrerJson>,
+}
+
+#[derive(Debug, Serialize)]
+struct RfcReferrersResponseJson {
+    schema_version: u32,
+    rfc: u32,
+    relations: Vec<RfcReferrerGroupJson>,
+}
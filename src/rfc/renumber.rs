@@ -0,0 +1,286 @@
+//! Reconcile RFC file names with `rfc` ids and optionally close numbering gaps.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use toml_edit::Item;
+
+use crate::cli::RfcRenumberArgs;
+use crate::output;
+
+use super::{
+    config::RfcConfig,
+    document::{REFERENCE_FIELDS, RfcDocument, rfc_paths},
+    revise::rewrite_rfc_heading,
+    util::{RFC_DIR, format_rfc_id, resolve_default_author, timestamp_now},
+};
+
+/// One RFC and the id and path it should end up with.
+struct PlannedRfc {
+    document: RfcDocument,
+    old_id: u32,
+    new_id: u32,
+    /// `rfc` value for the new id, as written in the file name and heading.
+    new_rfc_id: String,
+    old_path: PathBuf,
+    old_file_name: String,
+}
+
+/// Rename RFC files whose numeric prefix disagrees with their `rfc` id and,
+/// with `--close-gaps`, renumber every RFC to `1..=N` in id order.
+///
+/// Changed ids are rewritten in the `rfc` field and heading, and every
+/// `prerequisite`, `supersedes`, and `superseded_by` entry plus
+/// `[RFC NNNN](file.md)` body link pointing at them is updated. The plan is
+/// only printed unless `--apply` is given; nothing is written when the plan
+/// has duplicate ids or would overwrite an unrelated file.
+pub(crate) fn run(args: &RfcRenumberArgs) -> Result<()> {
+    let rfc_dir = Path::new(RFC_DIR);
+    if !rfc_dir.is_dir() {
+        bail!("RFC directory does not exist at {}", rfc_dir.display());
+    }
+    let config = RfcConfig::load()?;
+
+    let mut documents = Vec::new();
+    for path in rfc_paths(rfc_dir)? {
+        let document = RfcDocument::read(&path)
+            .and_then(|document| {
+                document.id()?;
+                Ok(document)
+            })
+            .with_context(|| format!("cannot renumber {}", path.display()))?;
+        documents.push(document);
+    }
+    documents
+        .sort_by(|left, right| (left.id().ok(), &left.path).cmp(&(right.id().ok(), &right.path)));
+
+    let mut problems = Vec::new();
+    let mut planned = Vec::with_capacity(documents.len());
+    let mut seen = BTreeMap::new();
+    for (index, document) in documents.into_iter().enumerate() {
+        let old_id = document.id()?;
+        let old_path = document.path.clone();
+        if let Some(first) = seen.insert(old_id, old_path.clone())
+            && !args.close_gaps
+        {
+            problems.push(format!(
+                "RFC id {} is used by both {} and {}; pass --close-gaps to give each its own id",
+                format_rfc_id(old_id, config.id_width),
+                first.display(),
+                old_path.display()
+            ));
+        }
+        let new_id = if args.close_gaps {
+            u32::try_from(index + 1).context("too many RFCs to renumber")?
+        } else {
+            old_id
+        };
+        let new_rfc_id = match document.metadata.get("rfc").and_then(Item::as_str) {
+            Some(rfc_id) if new_id == old_id => rfc_id.to_owned(),
+            _ => format_rfc_id(new_id, config.id_width),
+        };
+        let old_file_name = old_path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        planned.push(PlannedRfc {
+            document,
+            old_id,
+            new_id,
+            new_rfc_id,
+            old_path,
+            old_file_name,
+        });
+    }
+
+    // Duplicate ids keep pointing at the first RFC that carried them.
+    let mut id_changes = BTreeMap::new();
+    for rfc in &planned {
+        id_changes.entry(rfc.old_id).or_insert(rfc.new_id);
+    }
+    let mut link_changes = Vec::new();
+    for rfc in &mut planned {
+        let prefix_len = rfc
+            .old_file_name
+            .chars()
+            .take_while(char::is_ascii_digit)
+            .count();
+        let new_file_name = format!("{}{}", rfc.new_rfc_id, &rfc.old_file_name[prefix_len..]);
+        if new_file_name != rfc.old_file_name {
+            link_changes.push((
+                format!(
+                    "[RFC {}]({})",
+                    format_rfc_id(rfc.old_id, config.id_width),
+                    rfc.old_file_name
+                ),
+                format!("[RFC {}]({new_file_name})", rfc.new_rfc_id),
+            ));
+        }
+        rfc.document.path = rfc.old_path.with_file_name(new_file_name);
+    }
+
+    let mut changed = Vec::new();
+    for rfc in &mut planned {
+        let before = rfc.document.render();
+        let mut actions = Vec::new();
+        if rfc.document.path != rfc.old_path {
+            actions.push(format!("rename to {}", rfc.document.path.display()));
+        }
+        if rfc.new_id != rfc.old_id {
+            if rfc
+                .document
+                .metadata
+                .get("rfc")
+                .and_then(Item::as_str)
+                .is_some()
+            {
+                rfc.document.set_value("rfc", rfc.new_rfc_id.as_str());
+            } else {
+                rfc.document.set_value("rfc", i64::from(rfc.new_id));
+            }
+            let title = rfc.document.title()?.to_owned();
+            rfc.document.body = rewrite_rfc_heading(&rfc.document.body, &rfc.new_rfc_id, &title);
+            actions.push(format!(
+                "renumber RFC {} -> {}",
+                format_rfc_id(rfc.old_id, config.id_width),
+                rfc.new_rfc_id
+            ));
+        }
+        let mut references_changed = false;
+        for field in REFERENCE_FIELDS {
+            let references = rfc.document.references(field)?;
+            let renumbered = references
+                .iter()
+                .map(|id| id_changes.get(id).copied().unwrap_or(*id))
+                .collect::<Vec<_>>();
+            if renumbered != references {
+                rfc.document.set_references(field, &renumbered);
+                references_changed = true;
+            }
+        }
+        for (old_link, new_link) in &link_changes {
+            if rfc.document.body.contains(old_link.as_str()) {
+                rfc.document.body = rfc.document.body.replace(old_link.as_str(), new_link);
+                references_changed = true;
+            }
+        }
+        if references_changed {
+            actions.push("update references".to_owned());
+        }
+        if rfc.document.render() != before || rfc.document.path != rfc.old_path {
+            changed.push((rfc, actions));
+        }
+    }
+
+    let new_paths = changed
+        .iter()
+        .map(|(rfc, _)| rfc.document.path.clone())
+        .collect::<Vec<_>>();
+    let moved_from = changed
+        .iter()
+        .map(|(rfc, _)| rfc.old_path.clone())
+        .collect::<BTreeSet<_>>();
+    let mut targets = BTreeSet::new();
+    for path in &new_paths {
+        if !targets.insert(path) || (path.exists() && !moved_from.contains(path)) {
+            problems.push(format!(
+                "cannot rename to {}: file already exists",
+                path.display()
+            ));
+        }
+    }
+
+    if changed.is_empty() && problems.is_empty() {
+        output::print_log("RFC ids and file names already match");
+        return Ok(());
+    }
+    let verb = if args.apply { "" } else { "would change " };
+    for (rfc, actions) in &changed {
+        output::print_log(format!(
+            "{verb}{}: {}",
+            rfc.old_path.display(),
+            actions.join(", ")
+        ));
+    }
+    if !problems.is_empty() {
+        for problem in problems {
+            output::print_error(problem);
+        }
+        bail!("cannot renumber RFCs");
+    }
+    if !args.apply {
+        output::print_hint("dry run; pass --apply to write these changes");
+        return Ok(());
+    }
+
+    let renumbered = id_changes
+        .iter()
+        .filter(|(old, new)| old != new)
+        .map(|(old, new)| {
+            format!(
+                "{} -> {}",
+                format_rfc_id(*old, config.id_width),
+                format_rfc_id(*new, config.id_width)
+            )
+        })
+        .collect::<Vec<_>>();
+    let change = if renumbered.is_empty() {
+        "Renamed file to match RFC id".to_owned()
+    } else {
+        format!("Renumbered RFC {}", renumbered.join(", "))
+    };
+    let revised_by = resolve_default_author().ok();
+    let updated_timestamp = timestamp_now()?;
+    // Record revisions in memory first, then stage every file next to its
+    // target, so a failure before the final renames leaves the RFCs untouched.
+    let mut staged = Vec::with_capacity(changed.len());
+    for (rfc, _) in changed {
+        rfc.document.ensure_created(&updated_timestamp);
+        rfc.document.record_revision(
+            updated_timestamp.clone(),
+            change.clone(),
+            revised_by.as_deref(),
+        )?;
+        let file_name = rfc
+            .document
+            .path
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let staging = rfc
+            .document
+            .path
+            .with_file_name(format!(".{file_name}.renumber"));
+        staged.push((rfc, staging));
+    }
+    for (index, (rfc, staging)) in staged.iter().enumerate() {
+        if let Err(error) = rfc.document.write_to(staging) {
+            for (_, written) in &staged[..index] {
+                let _ = fs::remove_file(written);
+            }
+            return Err(error);
+        }
+    }
+    for (rfc, staging) in &staged {
+        fs::rename(staging, &rfc.document.path).with_context(|| {
+            format!(
+                "failed to move {} to {}",
+                staging.display(),
+                rfc.document.path.display()
+            )
+        })?;
+    }
+    let new_paths = new_paths.iter().collect::<BTreeSet<_>>();
+    for (rfc, _) in &staged {
+        if !new_paths.contains(&rfc.old_path) {
+            fs::remove_file(&rfc.old_path)
+                .with_context(|| format!("failed to remove {}", rfc.old_path.display()))?;
+        }
+        output::print_path(rfc.document.path.display());
+    }
+    Ok(())
+}
@@ -1,99 +1,235 @@
-use std::fs;
+use std::{fs, path::Path};
 
 use anyhow::{Context, Result, anyhow, bail};
-use toml_edit::{Array, ArrayOfTables, DocumentMut, Item, Table, Value, value};
+use toml_edit::{Array, DocumentMut, Item, Value};
 
 use crate::cli::RfcEditArgs;
 use crate::output;
 
 use super::{
+    config::RfcConfig,
+    document::RfcDocument,
     lookup::locate_existing_rfc,
-    reference::resolve_metadata_references,
-    util::{REVISED_REVISION_CHANGE, dedupe, timestamp_now},
+    reference::{
+        ensure_acyclic_prerequisites, inline_reference_ids, link_inline_references,
+        resolve_metadata_references,
+    },
+    status::{ensure_transition_allowed, requested_status},
+    util::{CodeFence, dedupe, resolve_default_author, resolve_timestamp, slugify},
 };
 
 /// Update an existing RFC frontmatter/body and append a revision entry.
+///
+/// When the flags leave the rendered document unchanged, nothing is written
+/// and no revision is recorded unless `--change` or `--force` is given.
 pub(crate) fn revise_rfc(cli: &RfcEditArgs) -> Result<()> {
     let selector = cli.title_arg.as_deref().ok_or_else(|| {
         anyhow!("rfc revise requires positional <title> to locate an existing RFC")
     })?;
     let path = locate_existing_rfc(selector)?;
-    let original = fs::read_to_string(&path)
-        .with_context(|| format!("failed to read RFC file {}", path.display()))?;
-    let (frontmatter, body) = split_frontmatter(&original)?;
-    let mut metadata = frontmatter
-        .parse::<DocumentMut>()
-        .context("failed to parse RFC TOML frontmatter")?;
+    let mut document = RfcDocument::read(&path)?;
+    let original = document.render();
+    let config = RfcConfig::load()?;
+    let mut revision_change = match cli.change.as_deref().map(str::trim) {
+        Some("") => bail!("`--change` cannot be empty"),
+        Some(change) => change.to_owned(),
+        None => config.revised_change.clone(),
+    };
+    if let Some(status) = document
+        .metadata
+        .get("status")
+        .and_then(|item| item.as_str())
+        && config.frozen_statuses.iter().any(|frozen| frozen == status)
+    {
+        if !cli.allow_frozen {
+            bail!(
+                "RFC {} has frozen status `{status}`; pass --allow-frozen to revise it anyway",
+                path.display()
+            );
+        }
+        revision_change = format!("{revision_change} (override frozen status `{status}`)");
+    }
 
     for author in dedupe(&cli.authors) {
-        append_unique_array_value(&mut metadata, "authors", &author)?;
+        append_unique_array_value(&mut document.metadata, "authors", &author)?;
     }
     for agent in dedupe(&cli.agents) {
-        append_unique_array_value(&mut metadata, "agents", &agent)?;
+        append_unique_array_value(&mut document.metadata, "agents", &agent)?;
+    }
+    for label in dedupe(&cli.labels) {
+        append_unique_array_value(&mut document.metadata, "labels", &label)?;
+    }
+    for author in dedupe(&cli.remove_authors) {
+        remove_array_value(&mut document.metadata, "authors", &author)?;
     }
-    let references = resolve_metadata_references(cli)?;
+    for agent in dedupe(&cli.remove_agents) {
+        remove_array_value(&mut document.metadata, "agents", &agent)?;
+    }
+    if !cli.remove_authors.is_empty()
+        && document
+            .metadata
+            .get("authors")
+            .and_then(Item::as_array)
+            .is_none_or(Array::is_empty)
+    {
+        bail!(
+            "cannot remove the last author from {}; every RFC needs at least one author",
+            path.display()
+        );
+    }
+    if !cli.remove_agents.is_empty()
+        && document
+            .metadata
+            .get("agents")
+            .and_then(Item::as_array)
+            .is_some_and(Array::is_empty)
+    {
+        document.metadata.remove("agents");
+    }
+    let title_override = revision_title_override(cli);
+    let inline_ids = if cli.link_inline {
+        title_override
+            .iter()
+            .chain(&cli.r#abstract)
+            .flat_map(|text| inline_reference_ids(text))
+            .collect::<Vec<_>>()
+    } else {
+        Vec::new()
+    };
+    let references = resolve_metadata_references(cli, &inline_ids)?;
+    let status = requested_status(cli)?;
 
+    if let Some(body_file) = &cli.body_file {
+        document.body = read_body_file(body_file)?;
+    }
+    if let Some(summary) = &cli.r#abstract {
+        document.set_value("abstract", summary.as_str());
+        let summary = if cli.link_inline {
+            link_inline_references(summary)?
+        } else {
+            summary.clone()
+        };
+        document.body = rewrite_summary_section(&document.body, &summary);
+    }
+    if let Some(status) = &status {
+        let current = document
+            .metadata
+            .get("status")
+            .and_then(|item| item.as_str());
+        ensure_transition_allowed(current, status, cli.allow_backward || cli.force)?;
+        document.set_value("status", status.as_str());
+    }
     if let Some(discussion) = &cli.discussion {
-        metadata["discussion"] = value(discussion.as_str());
+        document.set_value("discussion", discussion.as_str());
     }
     if let Some(tracking_issue) = &cli.tracking_issue {
-        metadata["tracking_issue"] = value(tracking_issue.as_str());
+        document.set_value("tracking_issue", tracking_issue.as_str());
     }
     if !references.prerequisite.is_empty() {
-        set_integer_array_value(&mut metadata, "prerequisite", &references.prerequisite);
+        document.set_references("prerequisite", &references.prerequisite);
+    }
+    if !references.inline_prerequisite.is_empty() {
+        let mut prerequisite = document.references("prerequisite")?;
+        prerequisite.extend(&references.inline_prerequisite);
+        document.set_references("prerequisite", &dedupe(&prerequisite));
+    }
+    if !references.prerequisite.is_empty() || cli.link_inline {
+        let rfc_dir = path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .unwrap_or(Path::new("."));
+        ensure_acyclic_prerequisites(
+            rfc_dir,
+            document.id()?,
+            &document.references("prerequisite")?,
+        )?;
     }
     if !references.supersedes.is_empty() {
-        set_integer_array_value(&mut metadata, "supersedes", &references.supersedes);
+        document.set_references("supersedes", &references.supersedes);
     }
     if !references.superseded_by.is_empty() {
-        set_integer_array_value(&mut metadata, "superseded_by", &references.superseded_by);
+        document.set_references("superseded_by", &references.superseded_by);
     }
 
-    let title_override = revision_title_override(cli);
     if let Some(new_title) = &title_override {
-        metadata["title"] = value(new_title.as_str());
+        document.set_value("title", new_title.as_str());
     }
 
-    let updated_timestamp = timestamp_now();
-    metadata["last_updated"] = value(updated_timestamp.clone());
-    append_revision_entry(
-        &mut metadata,
-        updated_timestamp,
-        REVISED_REVISION_CHANGE.to_owned(),
-    )?;
-
-    let rfc_id = metadata
+    let rfc_id = document
+        .metadata
         .get("rfc")
         .and_then(|item| item.as_str())
-        .ok_or_else(|| anyhow!("metadata is missing required `rfc` field"))?;
-    let title = title_override
-        .or_else(|| {
-            metadata
-                .get("title")
-                .and_then(|item| item.as_str())
-                .map(ToOwned::to_owned)
-        })
-        .ok_or_else(|| anyhow!("metadata is missing required `title` field"))?;
-
-    let updated_body = rewrite_rfc_heading(&body, rfc_id, &title);
-    let mut updated = String::new();
-    updated.push_str("+++\n");
-    let mut serialized_frontmatter = metadata.to_string();
-    if !serialized_frontmatter.ends_with('\n') {
-        serialized_frontmatter.push('\n');
-    }
-    updated.push_str(&serialized_frontmatter);
-    updated.push_str("+++\n\n");
-    updated.push_str(updated_body.trim_start_matches('\n'));
-    if !updated.ends_with('\n') {
-        updated.push('\n');
-    }
-
-    fs::write(&path, updated).with_context(|| format!("failed to update {}", path.display()))?;
-    output::print_path(path.display());
+        .ok_or_else(|| anyhow!("metadata is missing required `rfc` field"))?
+        .to_owned();
+    let retitled = title_override.is_some();
+    let title = match title_override {
+        Some(title) => title,
+        None => document.title()?.to_owned(),
+    };
+
+    document.body = rewrite_rfc_heading(&document.body, &rfc_id, &title);
+    document.apply_list_style(&config);
+    let updated_timestamp = resolve_timestamp(cli.date.as_deref())?;
+    document.ensure_created(&updated_timestamp);
+    if document.render() == original && cli.change.is_none() && !(cli.force || cli.force_revision) {
+        output::print_log(format!(
+            "no changes to {}; pass --force to record a revision anyway",
+            path.display()
+        ));
+        return Ok(());
+    }
+
+    let revised_by = cli
+        .authors
+        .first()
+        .cloned()
+        .or_else(|| resolve_default_author().ok());
+    document.record_revision(updated_timestamp, revision_change, revised_by.as_deref())?;
+    if let Some(revised_by) = &revised_by {
+        document.set_value("updated_by", revised_by.as_str());
+    }
+
+    let slug_path = path.with_file_name(format!("{rfc_id}-{}.md", slugify(&title)));
+    if !retitled || slug_path == path {
+        document.write()?;
+        output::print_path(path.display());
+        return Ok(());
+    }
+    if !cli.rename {
+        document.write()?;
+        output::print_path(path.display());
+        if !cli.keep_filename {
+            output::print_warning(format!(
+                "file name no longer matches the title; pass --rename to move it to {} or --keep-filename to keep it",
+                slug_path.display()
+            ));
+        }
+        return Ok(());
+    }
+
+    if slug_path.exists() {
+        bail!("cannot rename RFC: {} already exists", slug_path.display());
+    }
+    document.path = slug_path;
+    document.write()?;
+    fs::remove_file(&path)
+        .with_context(|| format!("failed to remove renamed RFC {}", path.display()))?;
+    output::print_path(document.path.display());
     Ok(())
 }
 
+/// Read a replacement RFC body, rejecting non-UTF-8 or blank files.
+fn read_body_file(path: &Path) -> Result<String> {
+    let bytes =
+        fs::read(path).with_context(|| format!("failed to read body file {}", path.display()))?;
+    let body = String::from_utf8(bytes)
+        .with_context(|| format!("body file {} is not valid UTF-8", path.display()))?;
+    if body.trim().is_empty() {
+        bail!("body file {} is empty", path.display());
+    }
+    Ok(body.replace("\r\n", "\n"))
+}
+
 fn revision_title_override(cli: &RfcEditArgs) -> Option<String> {
     if let Some(title) = &cli.title {
         return Some(title.clone());
@@ -106,46 +242,39 @@ fn revision_title_override(cli: &RfcEditArgs) -> Option<String> {
     None
 }
 
-fn split_frontmatter(markdown: &str) -> Result<(String, String)> {
-    let normalized = markdown.replace("\r\n", "\n");
-    if !normalized.starts_with("+++\n") {
-        bail!("RFC file does not start with TOML frontmatter marker `+++`");
-    }
-
-    let rest = &normalized[4..];
-    if let Some(end) = rest.find("\n+++\n") {
-        let frontmatter = rest[..end].to_owned();
-        let body = rest[end + 5..].to_owned();
-        return Ok((frontmatter, body));
-    }
-    if let Some(end) = rest.find("\n+++") {
-        let frontmatter = rest[..end].to_owned();
-        let mut body = rest[end + 4..].to_owned();
-        if body.starts_with('\n') {
-            body = body[1..].to_owned();
+/// Index of the `# RFC ...` title heading among `body.lines()`.
+///
+/// Only a top-level ATX heading counts: up to three spaces of indentation, and
+/// never inside a ``` or ~~~ fenced code block, so RFC samples in code blocks
+/// are not mistaken for the real heading.
+pub(crate) fn rfc_heading_index(body: &str) -> Option<usize> {
+    let mut fence = CodeFence::default();
+    for (index, line) in body.lines().enumerate() {
+        if fence.skips(line) {
+            continue;
+        }
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        if indent <= 3 && line[indent..].starts_with("# RFC ") {
+            return Some(index);
         }
-        return Ok((frontmatter, body));
     }
-
-    bail!("missing closing TOML frontmatter marker `+++`");
+    None
 }
 
-fn rewrite_rfc_heading(body: &str, rfc_id: &str, title: &str) -> String {
+/// Rewrite the `# RFC NNNN: title` heading (see [`rfc_heading_index`]), or
+/// prepend one when the body has none.
+pub(crate) fn rewrite_rfc_heading(body: &str, rfc_id: &str, title: &str) -> String {
     let heading = format!("# RFC {rfc_id}: {title}");
-    let mut replaced = false;
-    let mut output = String::new();
-
-    for line in body.lines() {
-        if !replaced && line.starts_with("# RFC ") {
-            output.push_str(&heading);
-            replaced = true;
-        } else {
-            output.push_str(line);
+    if let Some(heading_index) = rfc_heading_index(body) {
+        let mut output = String::new();
+        for (index, line) in body.lines().enumerate() {
+            output.push_str(if index == heading_index {
+                &heading
+            } else {
+                line
+            });
+            output.push('\n');
         }
-        output.push('\n');
-    }
-
-    if replaced {
         return output;
     }
 
@@ -159,6 +288,32 @@ fn rewrite_rfc_heading(body: &str, rfc_id: &str, title: &str) -> String {
     prefixed
 }
 
+/// Replace the content of the `## Summary` section, leaving bodies without one
+/// unchanged.
+fn rewrite_summary_section(body: &str, summary: &str) -> String {
+    let mut output = String::new();
+    let mut in_summary = false;
+    for line in body.lines() {
+        if line.trim_end() == "## Summary" {
+            in_summary = true;
+            output.push_str(line);
+            output.push_str("\n\n");
+            output.push_str(summary);
+            output.push_str("\n\n");
+            continue;
+        }
+        if in_summary {
+            if !line.starts_with('#') {
+                continue;
+            }
+            in_summary = false;
+        }
+        output.push_str(line);
+        output.push('\n');
+    }
+    output
+}
+
 fn append_unique_array_value(doc: &mut DocumentMut, key: &str, value_to_add: &str) -> Result<()> {
     if !doc.as_table().contains_key(key) {
         let mut values = Array::new();
@@ -182,47 +337,31 @@ fn append_unique_array_value(doc: &mut DocumentMut, key: &str, value_to_add: &st
     Ok(())
 }
 
-fn set_integer_array_value(doc: &mut DocumentMut, key: &str, values: &[u32]) {
-    let mut array = Array::new();
-    for entry in values {
-        array.push(i64::from(*entry));
-    }
-    doc[key] = Item::Value(Value::Array(array));
-}
-
-fn append_revision_entry(doc: &mut DocumentMut, date: String, change: String) -> Result<()> {
-    if !doc.as_table().contains_key("revision") {
-        doc["revision"] = Item::ArrayOfTables(ArrayOfTables::new());
-    }
-
-    let Some(revisions) = doc["revision"].as_array_of_tables_mut() else {
-        bail!("metadata field `revision` exists but is not an array of tables");
+/// Remove `value_to_remove` from an array field, warning when it is not listed.
+fn remove_array_value(doc: &mut DocumentMut, key: &str, value_to_remove: &str) -> Result<()> {
+    let Some(item) = doc.get_mut(key) else {
+        output::print_warning(format!(
+            "`{key}` does not list `{value_to_remove}`; nothing to remove"
+        ));
+        return Ok(());
+    };
+    let Some(array) = item.as_array_mut() else {
+        bail!("metadata field `{key}` exists but is not an array");
     };
 
-    let mut entry = Table::new();
-    entry["date"] = value(date);
-    entry["change"] = value(change);
-    revisions.push(entry);
+    let before = array.len();
+    array.retain(|entry| entry.as_str() != Some(value_to_remove));
+    if array.len() == before {
+        output::print_warning(format!(
+            "`{key}` does not list `{value_to_remove}`; nothing to remove"
+        ));
+    }
     Ok(())
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{rewrite_rfc_heading, split_frontmatter};
-
-    #[test]
-    fn split_frontmatter_parses_metadata_and_body() {
-        let markdown = "+++\nrfc = \"0001\"\n+++\n\n# RFC 0001: Title\n";
-        let (frontmatter, body) = split_frontmatter(markdown).expect("frontmatter should parse");
-        assert_eq!(frontmatter.trim(), "rfc = \"0001\"");
-        assert_eq!(body.trim(), "# RFC 0001: Title");
-    }
-
-    #[test]
-    fn split_frontmatter_rejects_missing_markers() {
-        let error = split_frontmatter("# RFC 0001: Title").expect_err("expected error");
-        assert!(error.to_string().contains("frontmatter marker"));
-    }
+    use super::{rewrite_rfc_heading, rewrite_summary_section};
 
     #[test]
     fn rewrite_rfc_heading_replaces_existing_heading() {
@@ -238,4 +377,28 @@ mod tests {
         let updated = rewrite_rfc_heading(body, "0002", "Prepended");
         assert!(updated.starts_with("# RFC 0002: Prepended\n\n## Summary"));
     }
+
+    #[test]
+    fn rewrite_rfc_heading_skips_fenced_code_blocks() {
+        let body = "```markdown\n# RFC 0009: Sample\n```\n\n  # RFC 0001: Old\n\n~~~~\n# RFC 0008: Other\n~~~\n~~~~\n";
+        let updated = rewrite_rfc_heading(body, "0001", "New");
+        assert_eq!(
+            updated,
+            "```markdown\n# RFC 0009: Sample\n```\n\n# RFC 0001: New\n\n~~~~\n# RFC 0008: Other\n~~~\n~~~~\n"
+        );
+
+        let body = "## Example\n\n```\n# RFC 0009: Sample\n```\n\n    # RFC 0007: Indented code\n";
+        let updated = rewrite_rfc_heading(body, "0002", "Prepended");
+        assert_eq!(updated, format!("# RFC 0002: Prepended\n\n{body}"));
+    }
+
+    #[test]
+    fn rewrite_summary_section_replaces_section_content() {
+        let body = "# RFC 0001: T\n\n## Summary\n\nOld text.\n\n## Motivation\n\nWhy.\n";
+        let updated = rewrite_summary_section(body, "New text.");
+        assert_eq!(
+            updated,
+            "# RFC 0001: T\n\n## Summary\n\nNew text.\n\n## Motivation\n\nWhy.\n"
+        );
+    }
 }
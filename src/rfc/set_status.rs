@@ -0,0 +1,117 @@
+//! Bulk `status` transitions selected by metadata predicates.
+
+use std::path::Path;
+
+use anyhow::{Result, anyhow, bail};
+use toml_edit::Item;
+
+use crate::cli::RfcSetStatusArgs;
+use crate::output;
+
+use super::{
+    document::{RfcDocument, rfc_paths},
+    status::{ensure_known_status, ensure_transition_allowed},
+    util::{RFC_DIR, resolve_default_author, timestamp_now},
+};
+
+/// Set `status` on every RFC matching the `--where`/`--author` predicates,
+/// appending a revision entry to each changed file.
+///
+/// RFCs already at the requested status are left untouched. Every transition
+/// is checked before any file is written, so a disallowed one (without
+/// `--force`) leaves the tree unchanged. With `--dry-run` the planned
+/// transitions are reported without writing.
+pub(crate) fn run(args: &RfcSetStatusArgs) -> Result<()> {
+    let status = args.status.trim();
+    if status.is_empty() {
+        bail!("status cannot be empty");
+    }
+    ensure_known_status(status)?;
+    let predicates = args
+        .predicates
+        .iter()
+        .map(|raw| parse_predicate(raw))
+        .collect::<Result<Vec<_>>>()?;
+    if predicates.is_empty() && args.authors.is_empty() {
+        bail!("pass --where or --author to select RFCs");
+    }
+
+    let rfc_dir = Path::new(RFC_DIR);
+    if !rfc_dir.is_dir() {
+        bail!("RFC directory does not exist at {}", rfc_dir.display());
+    }
+
+    let mut planned = Vec::new();
+    for path in rfc_paths(rfc_dir)? {
+        let document = RfcDocument::read(&path)?;
+        if !predicates
+            .iter()
+            .all(|(key, expected)| metadata_string(&document, key) == Some(expected.as_str()))
+            || !args
+                .authors
+                .iter()
+                .all(|author| has_author(&document, author))
+        {
+            continue;
+        }
+
+        let previous = metadata_string(&document, "status").map(ToOwned::to_owned);
+        if previous.as_deref() == Some(status) {
+            continue;
+        }
+        ensure_transition_allowed(previous.as_deref(), status, args.allow_backward)
+            .map_err(|error| anyhow!("{}: {error}", path.display()))?;
+        let (transition, change) = match &previous {
+            Some(previous) => (
+                format!("`{previous}` -> `{status}`"),
+                format!("Changed status from `{previous}` to `{status}`"),
+            ),
+            None => (format!("`{status}`"), format!("Set status to `{status}`")),
+        };
+        planned.push((path, document, transition, change));
+    }
+
+    if planned.is_empty() {
+        output::print_log("no RFCs matched");
+        return Ok(());
+    }
+    let revised_by = resolve_default_author().ok();
+    for (path, mut document, transition, change) in planned {
+        if args.dry_run {
+            output::print_log(format!("would set {}: {transition}", path.display()));
+            continue;
+        }
+
+        document.set_value("status", status);
+        let updated_timestamp = timestamp_now()?;
+        document.ensure_created(&updated_timestamp);
+        document.record_revision(updated_timestamp, change, revised_by.as_deref())?;
+        document.write()?;
+        output::print_path(path.display());
+    }
+    Ok(())
+}
+
+/// Split a `key=value` predicate.
+fn parse_predicate(raw: &str) -> Result<(String, String)> {
+    let (key, expected) = raw
+        .split_once('=')
+        .ok_or_else(|| anyhow!("invalid `--where` predicate `{raw}`; expected `key=value`"))?;
+    let key = key.trim();
+    if key.is_empty() {
+        bail!("invalid `--where` predicate `{raw}`; key cannot be empty");
+    }
+    Ok((key.to_owned(), expected.trim().to_owned()))
+}
+
+fn metadata_string<'a>(document: &'a RfcDocument, key: &str) -> Option<&'a str> {
+    document.metadata.get(key).and_then(Item::as_str)
+}
+
+fn has_author(document: &RfcDocument, author: &str) -> bool {
+    document
+        .metadata
+        .get("authors")
+        .and_then(Item::as_array)
+        .is_some_and(|authors| authors.iter().any(|entry| entry.as_str() == Some(author)))
+}
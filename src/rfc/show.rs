@@ -0,0 +1,113 @@
+use anyhow::Result;
+use serde::Serialize;
+use serde_json::{Map, Value as JsonValue};
+use toml_edit::{Item, Table, Value};
+
+use crate::cli::{RfcShowArgs, RfcShowFormat};
+
+use super::{document::RfcDocument, lookup::locate_existing_rfc};
+
+/// Print one RFC's metadata and markdown body.
+///
+/// Text output lists each top-level field as `key: value` (arrays joined with
+/// `, `), then the revisions, then the body. `--format json` emits the whole
+/// frontmatter as structured JSON alongside the body.
+pub(crate) fn run(args: &RfcShowArgs) -> Result<()> {
+    let path = locate_existing_rfc(&args.rfc)?;
+    let document = RfcDocument::read(&path)?;
+    document.id()?;
+    document.title()?;
+
+    match args.format {
+        RfcShowFormat::Text => {
+            for (key, item) in document.metadata.iter() {
+                if key == "revision" {
+                    continue;
+                }
+                if let Some(value) = item.as_value() {
+                    println!("{key}: {}", display_value(value));
+                }
+            }
+            let revisions = document.revisions()?;
+            if !revisions.is_empty() {
+                println!("revisions:");
+                for revision in revisions {
+                    match &revision.by {
+                        Some(by) => println!("  {}  {} ({by})", revision.date, revision.change),
+                        None => println!("  {}  {}", revision.date, revision.change),
+                    }
+                }
+            }
+            println!();
+            print!("{}", document.body.trim_start_matches('\n'));
+        }
+        RfcShowFormat::Json => {
+            let payload = RfcShowResponseJson {
+                schema_version: 1,
+                path: path.to_string_lossy().into_owned(),
+                metadata: table_to_json(document.metadata.as_table()),
+                body: document.body.trim_start_matches('\n').to_owned(),
+            };
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+        }
+    }
+    Ok(())
+}
+
+/// Inline text for a metadata value: strings unquoted, arrays comma-joined.
+fn display_value(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.value().clone(),
+        Value::Array(array) => array
+            .iter()
+            .map(display_value)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string().trim().to_owned(),
+    }
+}
+
+fn table_to_json(table: &Table) -> JsonValue {
+    JsonValue::Object(
+        table
+            .iter()
+            .filter_map(|(key, item)| Some((key.to_owned(), item_to_json(item)?)))
+            .collect::<Map<_, _>>(),
+    )
+}
+
+fn item_to_json(item: &Item) -> Option<JsonValue> {
+    match item {
+        Item::None => None,
+        Item::Value(value) => Some(value_to_json(value)),
+        Item::Table(table) => Some(table_to_json(table)),
+        Item::ArrayOfTables(tables) => {
+            Some(JsonValue::Array(tables.iter().map(table_to_json).collect()))
+        }
+    }
+}
+
+fn value_to_json(value: &Value) -> JsonValue {
+    match value {
+        Value::String(text) => JsonValue::from(text.value().as_str()),
+        Value::Integer(number) => JsonValue::from(*number.value()),
+        Value::Float(number) => JsonValue::from(*number.value()),
+        Value::Boolean(flag) => JsonValue::from(*flag.value()),
+        Value::Datetime(datetime) => JsonValue::from(datetime.value().to_string()),
+        Value::Array(array) => JsonValue::Array(array.iter().map(value_to_json).collect()),
+        Value::InlineTable(table) => JsonValue::Object(
+            table
+                .iter()
+                .map(|(key, value)| (key.to_owned(), value_to_json(value)))
+                .collect(),
+        ),
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RfcShowResponseJson {
+    schema_version: u32,
+    path: String,
+    metadata: JsonValue,
+    body: String,
+}
@@ -0,0 +1,117 @@
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use serde::Serialize;
+
+use crate::cli::{RfcStatsArgs, RfcStatsFormat};
+
+use super::{
+    config::RfcConfig,
+    document::{RfcDocument, rfc_paths},
+    util::{CodeFence, RFC_DIR, format_rfc_id},
+};
+
+/// Report body size metrics for RFCs, in aggregate or per RFC.
+pub(crate) fn run(args: &RfcStatsArgs) -> Result<()> {
+    let rfc_dir = Path::new(RFC_DIR);
+    if !rfc_dir.is_dir() {
+        bail!("RFC directory does not exist at {}", rfc_dir.display());
+    }
+
+    let mut entries = Vec::new();
+    for path in rfc_paths(rfc_dir)? {
+        entries.push(RfcStatsEntry::from_document(&RfcDocument::read(&path)?)?);
+    }
+    let summary = RfcStatsSummary {
+        rfc_count: entries.len(),
+        word_count: entries.iter().map(|entry| entry.word_count).sum(),
+        section_count: entries.iter().map(|entry| entry.section_count).sum(),
+    };
+
+    match args.format {
+        RfcStatsFormat::Text => {
+            if args.per_rfc {
+                let id_width = RfcConfig::load()?.id_width;
+                println!("rfc\tword_count\tsection_count\ttitle");
+                for entry in &entries {
+                    println!(
+                        "{}\t{}\t{}\t{}",
+                        format_rfc_id(entry.rfc, id_width),
+                        entry.word_count,
+                        entry.section_count,
+                        entry.title
+                    );
+                }
+            } else {
+                println!("rfc_count\tword_count\tsection_count");
+                println!(
+                    "{}\t{}\t{}",
+                    summary.rfc_count, summary.word_count, summary.section_count
+                );
+            }
+        }
+        RfcStatsFormat::Json => {
+            let payload = RfcStatsResponseJson {
+                schema_version: 1,
+                summary,
+                rfcs: args.per_rfc.then_some(entries),
+            };
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct RfcStatsEntry {
+    rfc: u32,
+    title: String,
+    path: String,
+    word_count: usize,
+    section_count: usize,
+}
+
+impl RfcStatsEntry {
+    /// Count body words (tokens with at least one alphanumeric character) and
+    /// `##`-or-deeper headings, skipping ``` and ~~~ fenced code blocks.
+    fn from_document(document: &RfcDocument) -> Result<Self> {
+        let mut word_count = 0;
+        let mut section_count = 0;
+        let mut fence = CodeFence::default();
+        for line in document.body.lines() {
+            if fence.skips(line) {
+                continue;
+            }
+            if line.trim_start().starts_with("##") {
+                section_count += 1;
+            }
+            word_count += line
+                .split_whitespace()
+                .filter(|token| token.chars().any(char::is_alphanumeric))
+                .count();
+        }
+
+        Ok(Self {
+            rfc: document.id()?,
+            title: document.title()?.to_owned(),
+            path: document.path.to_string_lossy().into_owned(),
+            word_count,
+            section_count,
+        })
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RfcStatsSummary {
+    rfc_count: usize,
+    word_count: usize,
+    section_count: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct RfcStatsResponseJson {
+    schema_version: u32,
+    summary: RfcStatsSummary,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rfcs: Option<Vec<RfcStatsEntry>>,
+}
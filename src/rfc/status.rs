@@ -0,0 +1,115 @@
+//! RFC `status` metadata requested from the command line.
+
+use anyhow::{Result, bail};
+
+use crate::cli::RfcEditArgs;
+
+/// Status written by `rfc new` when none is requested.
+pub(crate) const DEFAULT_STATUS: &str = "draft";
+
+/// Allowed `status` values, in lifecycle order.
+pub(crate) const STATUSES: [&str; 6] = [
+    "draft",
+    "proposed",
+    "accepted",
+    "final",
+    "rejected",
+    "withdrawn",
+];
+
+/// Statuses that end an RFC's lifecycle without acceptance.
+const CLOSED_STATUSES: [&str; 2] = ["rejected", "withdrawn"];
+
+/// Resolve `--status` and its `--draft`/`--final` shortcuts.
+///
+/// A shortcut combined with `--status` must name the same status, and the
+/// result must be one of [`STATUSES`].
+pub(crate) fn requested_status(cli: &RfcEditArgs) -> Result<Option<String>> {
+    let shortcut = if cli.draft {
+        Some(("--draft", "draft"))
+    } else if cli.r#final {
+        Some(("--final", "final"))
+    } else {
+        None
+    };
+
+    let explicit = match cli.status.as_deref().map(str::trim) {
+        Some("") => bail!("`--status` cannot be empty"),
+        explicit => explicit,
+    };
+
+    let status = match (shortcut, explicit) {
+        (Some((flag, implied)), Some(status)) if status != implied => {
+            bail!("`{flag}` conflicts with `--status {status}`")
+        }
+        (Some((_, implied)), _) => Some(implied),
+        (None, explicit) => explicit,
+    };
+    if let Some(status) = status {
+        ensure_known_status(status)?;
+    }
+    Ok(status.map(ToOwned::to_owned))
+}
+
+/// Reject statuses outside [`STATUSES`].
+pub(crate) fn ensure_known_status(status: &str) -> Result<()> {
+    if STATUSES.contains(&status) {
+        return Ok(());
+    }
+    bail!(
+        "unknown status `{status}`; expected one of {}",
+        STATUSES.join(", ")
+    )
+}
+
+/// Check a `from -> to` status change unless `allow_backward` is set.
+///
+/// Open RFCs may move forward (`draft -> proposed -> accepted -> final`) or be
+/// closed as `rejected`/`withdrawn`. Moving backwards or reopening a closed RFC
+/// requires `allow_backward`. A current status outside [`STATUSES`] may change freely.
+pub(crate) fn ensure_transition_allowed(
+    from: Option<&str>,
+    to: &str,
+    allow_backward: bool,
+) -> Result<()> {
+    let Some(from) = from else {
+        return Ok(());
+    };
+    if allow_backward || from == to || !STATUSES.contains(&from) {
+        return Ok(());
+    }
+    let rank = |status: &str| STATUSES.iter().position(|known| *known == status);
+    let allowed = !CLOSED_STATUSES.contains(&from)
+        && (CLOSED_STATUSES.contains(&to) || rank(to) > rank(from));
+    if allowed {
+        return Ok(());
+    }
+    bail!("cannot change status from `{from}` to `{to}`; pass --force to override")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ensure_transition_allowed;
+
+    #[test]
+    fn transitions_move_forward_or_close() {
+        for (from, to) in [
+            ("draft", "proposed"),
+            ("proposed", "final"),
+            ("accepted", "withdrawn"),
+            ("review", "draft"),
+        ] {
+            assert!(
+                ensure_transition_allowed(Some(from), to, false).is_ok(),
+                "{from} -> {to}"
+            );
+        }
+        for (from, to) in [("accepted", "draft"), ("rejected", "proposed")] {
+            assert!(
+                ensure_transition_allowed(Some(from), to, false).is_err(),
+                "{from} -> {to}"
+            );
+            assert!(ensure_transition_allowed(Some(from), to, true).is_ok());
+        }
+    }
+}
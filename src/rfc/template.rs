@@ -6,11 +6,16 @@
 //! 3. Current directory fallback
 
 use std::{
+    collections::BTreeSet,
     env, fs,
     path::{Path, PathBuf},
 };
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
+use tera::{
+    Context as TeraContext, Tera,
+    ast::{Expr, ExprVal, Node},
+};
 use toml_edit::DocumentMut;
 
 use super::util::{RFC_DIR, TEMPLATE_PATH};
@@ -26,9 +31,15 @@ pub(crate) struct ProjectRoots {
 
 /// Load template text from the project template path when available, otherwise
 /// fall back to the embedded default template shipped with the binary.
-pub(crate) fn load_template() -> Result<String> {
+///
+/// `sections` replaces the embedded template's body sections; it is ignored
+/// when a project template exists.
+pub(crate) fn load_template(sections: Option<&[String]>) -> Result<String> {
     let Some(template_path) = resolve_project_template_path()? else {
-        return Ok(DEFAULT_TEMPLATE.to_owned());
+        return Ok(match sections {
+            Some(sections) => embedded_template_with_sections(sections),
+            None => DEFAULT_TEMPLATE.to_owned(),
+        });
     };
 
     fs::read_to_string(&template_path).with_context(|| {
@@ -39,23 +50,214 @@ pub(crate) fn load_template() -> Result<String> {
     })
 }
 
+/// Render `template` with `context`, optionally rejecting undefined variables.
+///
+/// Tera already fails on an undefined `{{ variable }}`, but treats undefined
+/// names in `{% if %}` conditions and loop containers as falsy, so a typo such
+/// as `{% if titel %}` silently drops a block. With `strict`, every variable
+/// the template reads must be in `context`, bound by the template (`for`,
+/// `set`), or guarded by an `is defined` test or `default` filter.
+pub(crate) fn render_template_text(
+    template: &str,
+    context: &TeraContext,
+    strict: bool,
+) -> Result<String> {
+    if !strict {
+        return Tera::one_off(template, context, false).context("failed to render template");
+    }
+
+    let mut tera = Tera::default();
+    tera.add_raw_template("rfc", template)
+        .context("failed to parse template")?;
+    let mut checker = VariableChecker {
+        context,
+        bound: BTreeSet::from(["loop".to_owned()]),
+        undefined: BTreeSet::new(),
+    };
+    checker.nodes(&tera.get_template("rfc")?.ast);
+    if !checker.undefined.is_empty() {
+        bail!(
+            "template references undefined variable(s): {}",
+            checker
+                .undefined
+                .iter()
+                .map(|name| format!("`{name}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    tera.render("rfc", context)
+        .context("failed to render template")
+}
+
+/// Collects variable names a template reads but the context does not define.
+struct VariableChecker<'a> {
+    context: &'a TeraContext,
+    /// Names bound inside the template by `for` and `set`.
+    bound: BTreeSet<String>,
+    undefined: BTreeSet<String>,
+}
+
+impl VariableChecker<'_> {
+    fn nodes(&mut self, nodes: &[Node]) {
+        for node in nodes {
+            match node {
+                Node::VariableBlock(_, expr) => self.expr(expr),
+                Node::Set(_, set) => {
+                    self.expr(&set.value);
+                    self.bound.insert(set.key.clone());
+                }
+                Node::FilterSection(_, section, _) => {
+                    section.filter.args.values().for_each(|arg| self.expr(arg));
+                    self.nodes(&section.body);
+                }
+                Node::Block(_, block, _) => self.nodes(&block.body),
+                Node::Forloop(_, forloop, _) => {
+                    self.expr(&forloop.container);
+                    self.bound.extend(forloop.key.clone());
+                    self.bound.insert(forloop.value.clone());
+                    self.nodes(&forloop.body);
+                    if let Some(body) = &forloop.empty_body {
+                        self.nodes(body);
+                    }
+                }
+                Node::If(branches, _) => {
+                    for (_, condition, body) in &branches.conditions {
+                        self.expr(condition);
+                        self.nodes(body);
+                    }
+                    if let Some((_, body)) = &branches.otherwise {
+                        self.nodes(body);
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    fn expr(&mut self, expr: &Expr) {
+        if expr.filters.iter().any(|filter| filter.name == "default") {
+            return;
+        }
+        for filter in &expr.filters {
+            filter.args.values().for_each(|arg| self.expr(arg));
+        }
+        match &expr.val {
+            ExprVal::Ident(name) => self.ident(name),
+            ExprVal::Math(math) => {
+                self.expr(&math.lhs);
+                self.expr(&math.rhs);
+            }
+            ExprVal::Logic(logic) => {
+                self.expr(&logic.lhs);
+                self.expr(&logic.rhs);
+            }
+            ExprVal::Test(test) => {
+                if !matches!(test.name.as_str(), "defined" | "undefined") {
+                    self.ident(&test.ident);
+                }
+                test.args.iter().for_each(|arg| self.expr(arg));
+            }
+            ExprVal::FunctionCall(call) => call.args.values().for_each(|arg| self.expr(arg)),
+            ExprVal::MacroCall(call) => call.args.values().for_each(|arg| self.expr(arg)),
+            ExprVal::Array(items) => items.iter().for_each(|item| self.expr(item)),
+            ExprVal::StringConcat(concat) => {
+                for value in &concat.values {
+                    if let ExprVal::Ident(name) = value {
+                        self.ident(name);
+                    }
+                }
+            }
+            ExprVal::In(membership) => {
+                self.expr(&membership.lhs);
+                self.expr(&membership.rhs);
+            }
+            _ => {}
+        }
+    }
+
+    /// Check the root of a possibly dotted or indexed identifier.
+    fn ident(&mut self, name: &str) {
+        let root = name.split(['.', '[']).next().unwrap_or(name).trim();
+        if root.is_empty() || self.bound.contains(root) || self.context.contains_key(root) {
+            return;
+        }
+        self.undefined.insert(root.to_owned());
+    }
+}
+
 /// Return the embedded RFC template shipped in the binary.
 pub(crate) fn embedded_template() -> &'static str {
     DEFAULT_TEMPLATE
 }
 
+/// Rebuild the embedded template with the given `##` sections, keeping the
+/// frontmatter and heading. Sections named like an embedded one (ignoring case)
+/// keep its placeholder text; others are left empty.
+fn embedded_template_with_sections(sections: &[String]) -> String {
+    let (head, rest) = DEFAULT_TEMPLATE
+        .split_once("\n## ")
+        .expect("embedded template should contain `##` sections");
+    let defaults = rest
+        .split("\n## ")
+        .map(|section| {
+            let (heading, body) = section.split_once('\n').unwrap_or((section, ""));
+            (heading.trim().to_ascii_lowercase(), body.trim())
+        })
+        .collect::<Vec<_>>();
+
+    let mut template = head.to_owned();
+    for section in sections {
+        let name = section.trim();
+        template.push_str(&format!("\n## {name}\n"));
+        let folded = name.to_ascii_lowercase();
+        if let Some((_, body)) = defaults.iter().find(|(heading, _)| *heading == folded)
+            && !body.is_empty()
+        {
+            template.push_str(&format!("\n{body}\n"));
+        }
+    }
+    template
+}
+
+/// Resolve the `{{ project_name }}` template variable.
+///
+/// Precedence: `configured` (`[rfc] project_name` in `.agx.toml`), then the
+/// nearest `Cargo.toml` `[package] name`, then the project root directory name
+/// (for virtual workspace manifests or projects without Cargo).
+pub(crate) fn resolve_project_name(configured: Option<&str>) -> Result<String> {
+    if let Some(name) = configured {
+        return Ok(name.to_owned());
+    }
+    if let Some(crate_root) = discover_project_roots()?.crate_root
+        && let Some(name) = manifest_package_name(&crate_root.join("Cargo.toml"))?
+    {
+        return Ok(name);
+    }
+    let root = resolve_project_root()?;
+    Ok(root
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default())
+}
+
 /// Resolve the RFC directory used for title-based metadata reference lookup.
 pub(crate) fn resolve_project_rfc_dir() -> Result<PathBuf> {
+    Ok(resolve_project_root()?.join(RFC_DIR))
+}
+
+/// Resolve the project root (workspace root, then crate root, then current
+/// directory).
+pub(crate) fn resolve_project_root() -> Result<PathBuf> {
     let roots = discover_project_roots()?;
-    if let Some(root) = roots.workspace_root.as_ref() {
-        return Ok(root.join(RFC_DIR));
+    if let Some(root) = roots.workspace_root {
+        return Ok(root);
     }
-    if let Some(root) = roots.crate_root.as_ref() {
-        return Ok(root.join(RFC_DIR));
+    if let Some(root) = roots.crate_root {
+        return Ok(root);
     }
 
-    let cwd = env::current_dir().context("failed to resolve current directory")?;
-    Ok(cwd.join(RFC_DIR))
+    env::current_dir().context("failed to resolve current directory")
 }
 
 fn resolve_project_template_path() -> Result<Option<PathBuf>> {
@@ -110,6 +312,19 @@ pub(crate) fn discover_project_roots() -> Result<ProjectRoots> {
     })
 }
 
+fn manifest_package_name(path: &Path) -> Result<Option<String>> {
+    let source = fs::read_to_string(path)
+        .with_context(|| format!("failed to read cargo manifest {}", path.display()))?;
+    let Ok(manifest) = source.parse::<DocumentMut>() else {
+        return Ok(None);
+    };
+    Ok(manifest
+        .get("package")
+        .and_then(|package| package.get("name"))
+        .and_then(|name| name.as_str())
+        .map(ToOwned::to_owned))
+}
+
 fn manifest_declares_workspace(path: &Path) -> Result<bool> {
     let source = fs::read_to_string(path)
         .with_context(|| format!("failed to read cargo manifest {}", path.display()))?;
@@ -121,9 +336,22 @@ fn manifest_declares_workspace(path: &Path) -> Result<bool> {
 
 #[cfg(test)]
 mod tests {
-    use super::manifest_declares_workspace;
+    use super::{embedded_template_with_sections, manifest_declares_workspace};
     use std::{fs, time::SystemTime};
 
+    #[test]
+    fn configured_sections_replace_embedded_body() {
+        let template =
+            embedded_template_with_sections(&["Summary".to_owned(), "Open Issues".to_owned()]);
+        let headings = template
+            .lines()
+            .filter(|line| line.starts_with("## "))
+            .collect::<Vec<_>>();
+        assert_eq!(headings, vec!["## Summary", "## Open Issues"]);
+        assert!(template.contains("{% if abstract %}"));
+        assert!(template.starts_with("+++\n"));
+    }
+
     #[test]
     fn workspace_manifest_is_detected() {
         let temp_dir = std::env::temp_dir().join(format!(
@@ -1,12 +1,16 @@
-use std::{fs, path::Path, process::Command};
+use std::{env, fs, path::Path, process::Command};
 
 use anyhow::{Context, Result, bail};
-use chrono::{SecondsFormat, Utc};
+use chrono::{DateTime, NaiveDate, NaiveDateTime, SecondsFormat, Utc};
 
 pub(crate) const RFC_DIR: &str = "rfc";
 pub(crate) const TEMPLATE_PATH: &str = "rfc/0000-template.md";
 pub(crate) const INITIAL_REVISION_CHANGE: &str = "Initial draft";
 pub(crate) const REVISED_REVISION_CHANGE: &str = "Revised";
+/// Fixed RFC 3339 timestamp used instead of the current time when set.
+pub(crate) const NOW_ENV: &str = "AGX_NOW";
+/// Reproducible-builds fallback for [`NOW_ENV`]: seconds since the Unix epoch.
+const SOURCE_DATE_EPOCH_ENV: &str = "SOURCE_DATE_EPOCH";
 
 pub(crate) fn resolve_default_author() -> Result<String> {
     let output = Command::new("git")
@@ -29,7 +33,9 @@ pub(crate) fn resolve_default_author() -> Result<String> {
     Ok(name)
 }
 
-pub(crate) fn next_rfc_id(rfc_dir: &Path) -> Result<String> {
+/// Next free RFC id after the highest numbered file in `rfc_dir`, zero-padded
+/// to `width` digits.
+pub(crate) fn next_rfc_id(rfc_dir: &Path, width: usize) -> Result<String> {
     let entries = fs::read_dir(rfc_dir)
         .with_context(|| format!("failed to read RFC directory {}", rfc_dir.display()))?;
     let mut max_seen = 0u32;
@@ -43,29 +49,113 @@ pub(crate) fn next_rfc_id(rfc_dir: &Path) -> Result<String> {
             continue;
         }
 
-        let prefix: String = file_name.chars().take(4).collect();
-        if prefix.len() != 4 || !prefix.chars().all(|ch| ch.is_ascii_digit()) {
-            continue;
+        if let Some(parsed) = rfc_file_id(&file_name) {
+            max_seen = max_seen.max(parsed);
         }
+    }
 
-        let parsed = prefix.parse::<u32>()?;
-        if parsed > max_seen {
-            max_seen = parsed;
-        }
+    Ok(format_rfc_id(max_seen + 1, width))
+}
+
+/// Zero-pad an RFC id to `width` digits.
+pub(crate) fn format_rfc_id(id: u32, width: usize) -> String {
+    format!("{id:0width$}")
+}
+
+/// RFC id from the leading digits of an RFC file name (`0042-title.md`).
+pub(crate) fn rfc_file_id(file_name: &str) -> Option<u32> {
+    let digits = file_name
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect::<String>();
+    if digits.is_empty() {
+        return None;
     }
+    digits.parse().ok()
+}
 
-    Ok(format!("{:04}", max_seen + 1))
+/// Current timestamp for metadata, unless pinned by `AGX_NOW` (RFC 3339) or
+/// `SOURCE_DATE_EPOCH` (Unix seconds), checked in that order.
+pub(crate) fn timestamp_now() -> Result<String> {
+    if let Some(now) = env::var_os(NOW_ENV) {
+        let now = now.to_string_lossy();
+        return fixed_timestamp(&now).with_context(|| format!("invalid {NOW_ENV}"));
+    }
+    if let Some(epoch) = env::var_os(SOURCE_DATE_EPOCH_ENV) {
+        let epoch = epoch.to_string_lossy();
+        let timestamp = epoch
+            .trim()
+            .parse::<i64>()
+            .ok()
+            .and_then(|seconds| DateTime::from_timestamp(seconds, 0))
+            .with_context(|| {
+                format!("invalid {SOURCE_DATE_EPOCH_ENV} `{epoch}`; expected Unix seconds")
+            })?;
+        return Ok(format_timestamp(timestamp));
+    }
+    Ok(format_timestamp(Utc::now()))
 }
 
-pub(crate) fn timestamp_now() -> String {
-    Utc::now().to_rfc3339_opts(SecondsFormat::Secs, true)
+/// `--date` when given, otherwise [`timestamp_now`].
+pub(crate) fn resolve_timestamp(date: Option<&str>) -> Result<String> {
+    match date {
+        Some(date) => fixed_timestamp(date).context("invalid --date"),
+        None => timestamp_now(),
+    }
 }
 
+/// Normalize a pinned RFC 3339 timestamp to the metadata format.
+fn fixed_timestamp(input: &str) -> Result<String> {
+    let parsed = DateTime::parse_from_rfc3339(input.trim())
+        .with_context(|| format!("`{input}` is not an RFC 3339 timestamp"))?;
+    Ok(format_timestamp(parsed.with_timezone(&Utc)))
+}
+
+/// Format a timestamp the way agx writes metadata dates (RFC 3339, UTC, seconds).
+pub(crate) fn format_timestamp(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// Parse RFC 3339 or common hand-written variants (space separator, missing
+/// offset, date only); offset-less inputs are read as UTC.
+pub(crate) fn parse_timestamp(input: &str) -> Option<DateTime<Utc>> {
+    let input = input.trim();
+    if let Ok(parsed) = DateTime::parse_from_rfc3339(input) {
+        return Some(parsed.with_timezone(&Utc));
+    }
+    for format in ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%d %H:%M"] {
+        if let Ok(parsed) = NaiveDateTime::parse_from_str(input, format) {
+            return Some(parsed.and_utc());
+        }
+    }
+    NaiveDate::parse_from_str(input, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|parsed| parsed.and_utc())
+}
+
+/// Escape `input` for a TOML basic (double-quoted) string.
+///
+/// Control characters use the short escapes TOML defines and `\uXXXX`
+/// otherwise.
 pub(crate) fn toml_escape(input: &str) -> String {
-    input
-        .replace('\\', "\\\\")
-        .replace('"', "\\\"")
-        .replace('\n', "\\n")
+    let mut escaped = String::with_capacity(input.len());
+    for ch in input.chars() {
+        match ch {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\t' => escaped.push_str("\\t"),
+            '\r' => escaped.push_str("\\r"),
+            '\u{8}' => escaped.push_str("\\b"),
+            '\u{c}' => escaped.push_str("\\f"),
+            ch if ch <= '\u{1f}' || ch == '\u{7f}' => {
+                escaped.push_str(&format!("\\u{:04X}", u32::from(ch)));
+            }
+            ch => escaped.push(ch),
+        }
+    }
+    escaped
 }
 
 pub(crate) fn slugify(input: &str) -> String {
@@ -114,9 +204,61 @@ pub(crate) fn dedupe<T: Eq + Clone>(values: &[T]) -> Vec<T> {
     deduped
 }
 
+/// Tracks ``` and ~~~ fenced code blocks while scanning markdown lines.
+///
+/// A fence opens on a run of at least three backticks or tildes indented by
+/// at most three spaces, and closes on a bare run of the same marker that is
+/// at least as long.
+#[derive(Debug, Default)]
+pub(crate) struct CodeFence {
+    open: Option<(char, usize)>,
+}
+
+impl CodeFence {
+    /// Feed the next `line`; true when it is a fence marker or inside a fence.
+    pub(crate) fn skips(&mut self, line: &str) -> bool {
+        let indent = line.len() - line.trim_start_matches(' ').len();
+        if indent > 3 {
+            return self.open.is_some();
+        }
+        let trimmed = &line[indent..];
+        let marker = trimmed.chars().next().filter(|ch| matches!(ch, '`' | '~'));
+        let run = marker.map_or(0, |marker| {
+            trimmed.chars().take_while(|ch| *ch == marker).count()
+        });
+        match (self.open, marker) {
+            (None, Some(marker)) if run >= 3 => {
+                self.open = Some((marker, run));
+                true
+            }
+            (Some((open, length)), Some(marker))
+                if marker == open && run >= length && trimmed[run..].trim().is_empty() =>
+            {
+                self.open = None;
+                true
+            }
+            (open, _) => open.is_some(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{dedupe, slugify};
+    use super::{dedupe, format_timestamp, parse_timestamp, slugify, toml_escape};
+
+    #[test]
+    fn toml_escape_round_trips_control_characters() {
+        let raw = "Tab\there \"quoted\" \\ cr\r\nbell\u{7}del\u{7f}";
+        let escaped = toml_escape(raw);
+        assert_eq!(
+            escaped,
+            "Tab\\there \\\"quoted\\\" \\\\ cr\\r\\nbell\\u0007del\\u007F"
+        );
+        let document = format!("value = \"{escaped}\"")
+            .parse::<toml_edit::DocumentMut>()
+            .expect("escaped value should parse");
+        assert_eq!(document["value"].as_str(), Some(raw));
+    }
 
     #[test]
     fn slugify_normalizes_words() {
@@ -129,6 +271,21 @@ mod tests {
         assert_eq!(slugify("!!!"), "untitled");
     }
 
+    #[test]
+    fn parse_timestamp_accepts_rfc3339_and_common_variants() {
+        let expected = "2025-01-02T03:04:05Z";
+        for input in [
+            "2025-01-02T03:04:05Z",
+            "2025-01-02T05:04:05+02:00",
+            "2025-01-02 03:04:05",
+            "2025-01-02T03:04:05",
+        ] {
+            let parsed = parse_timestamp(input).expect("timestamp should parse");
+            assert_eq!(format_timestamp(parsed), expected, "input `{input}`");
+        }
+        assert!(parse_timestamp("not-a-date").is_none());
+    }
+
     #[test]
     fn dedupe_preserves_first_seen_order() {
         let values = vec![
@@ -0,0 +1,430 @@
+use std::{collections::BTreeSet, fs, path::Path};
+
+use anyhow::{Context, Result, bail};
+use chrono::DateTime;
+use serde::Serialize;
+use toml_edit::{Item, Value};
+
+use crate::cli::{RfcIdSource, RfcValidateArgs, RfcValidateFormat};
+use crate::output;
+
+use super::{
+    config::RfcConfig,
+    document::{REFERENCE_FIELDS, RfcDocument, rfc_paths},
+    revise::{rewrite_rfc_heading, rfc_heading_index},
+    template::load_template,
+    util::{RFC_DIR, format_rfc_id, format_timestamp, parse_timestamp, rfc_file_id, timestamp_now},
+};
+
+/// Validate every RFC under the RFC directory.
+///
+/// Metadata must include `rfc`, `title`, `created`, and at least one author,
+/// timestamps must be RFC 3339, and the `# RFC NNNN: title` heading must match
+/// the metadata, as must the file name's numeric prefix. Each `##` section of
+/// the template `rfc new` uses (`[rfc] sections` when configured) must appear
+/// as a heading, and reference fields must point to existing RFC ids. `--fix`
+/// repairs what it can (renaming files or rewriting `rfc` per `--id-source`,
+/// backfilling `created`, normalizing parseable timestamps, rewriting
+/// headings, pruning dangling ids) and rewrites changed files.
+pub(crate) fn run(args: &RfcValidateArgs) -> Result<()> {
+    let rfc_dir = Path::new(RFC_DIR);
+    if !rfc_dir.is_dir() {
+        bail!("RFC directory does not exist at {}", rfc_dir.display());
+    }
+
+    let config = RfcConfig::load()?;
+    let sections = load_template(config.sections.as_deref())?
+        .lines()
+        .filter_map(|line| line.strip_prefix("## "))
+        .map(str::trim)
+        .filter(|section| !section.is_empty() && !section.contains("{{") && !section.contains("{%"))
+        .map(ToOwned::to_owned)
+        .collect::<Vec<_>>();
+    let mut results = Vec::new();
+    let mut documents = Vec::new();
+    // RFCs with metadata errors still exist, so references to their file ids
+    // are not dangling.
+    let mut unparsed_ids = BTreeSet::new();
+    for path in rfc_paths(rfc_dir)? {
+        let parsed = RfcDocument::read(&path).and_then(|document| {
+            document.id()?;
+            document.title()?;
+            Ok(document)
+        });
+        match parsed {
+            Ok(document) => documents.push(document),
+            Err(error) => {
+                unparsed_ids.extend(
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .and_then(rfc_file_id),
+                );
+                results.push(RfcValidationResultJson::new(
+                    path.display().to_string(),
+                    vec![format!("{error:#}")],
+                ));
+            }
+        }
+    }
+
+    // File ids are reconciled first: with `--id-source filename` they change
+    // the ids that references are checked against.
+    let mut pending = Vec::with_capacity(documents.len());
+    for document in &mut documents {
+        let before = document.render();
+        let problems = check_file_id(document, config.id_width, args)?;
+        pending.push((before, problems));
+    }
+
+    let mut known_ids = documents
+        .iter()
+        .map(RfcDocument::id)
+        .collect::<Result<BTreeSet<_>>>()?;
+    known_ids.extend(unparsed_ids);
+    for (document, (before, mut problems)) in documents.iter_mut().zip(pending) {
+        problems.extend(check_required_fields(document, args.fix)?);
+        problems.extend(check_timestamps(document, args.fix));
+        problems.extend(check_heading(document, config.id_width, args.fix)?);
+        problems.extend(check_sections(document, &sections));
+        problems.extend(check_references(
+            document,
+            &known_ids,
+            config.id_width,
+            args.fix,
+        )?);
+        if document.render() != before {
+            document.write()?;
+        }
+        results.push(RfcValidationResultJson::new(
+            document.path.display().to_string(),
+            problems,
+        ));
+    }
+
+    let failed = results.iter().filter(|result| !result.ok).count();
+    match args.format {
+        RfcValidateFormat::Text => {
+            for result in results.iter().filter(|result| result.ok) {
+                output::print_log(format!("ok {}", result.path));
+            }
+            for result in results.iter().filter(|result| !result.ok) {
+                for problem in &result.problems {
+                    output::print_error(format!("{}: {problem}", result.path));
+                }
+            }
+            if failed == 0 {
+                output::print_log(format!("validated {} RFC(s)", documents.len()));
+            }
+        }
+        RfcValidateFormat::Json => {
+            let summary = RfcValidationSummaryJson {
+                schema_version: 1,
+                total: results.len(),
+                passed: results.len() - failed,
+                failed,
+                results,
+            };
+            println!("{}", serde_json::to_string_pretty(&summary)?);
+        }
+    }
+
+    if failed > 0 {
+        bail!("RFC validation failed");
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct RfcValidationResultJson {
+    path: String,
+    ok: bool,
+    problems: Vec<String>,
+}
+
+impl RfcValidationResultJson {
+    fn new(path: String, problems: Vec<String>) -> Self {
+        Self {
+            path,
+            ok: problems.is_empty(),
+            problems,
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct RfcValidationSummaryJson {
+    schema_version: u32,
+    total: usize,
+    passed: usize,
+    failed: usize,
+    results: Vec<RfcValidationResultJson>,
+}
+
+/// Flag a missing `created` field or an empty `authors` list. `fix` backfills
+/// `created` from the first revision date (or now).
+fn check_required_fields(document: &mut RfcDocument, fix: bool) -> Result<Vec<String>> {
+    let mut problems = Vec::new();
+    if !document.metadata.contains_key("created") {
+        if fix {
+            document.ensure_created(&timestamp_now()?);
+            output::print_log(format!(
+                "fixed {}: backfilled `created`",
+                document.path.display()
+            ));
+        } else {
+            problems.push("missing required `created` field (use --fix to backfill it)".to_owned());
+        }
+    }
+    let has_author = document
+        .metadata
+        .get("authors")
+        .and_then(Item::as_array)
+        .is_some_and(|authors| authors.iter().any(|author| author.as_str().is_some()));
+    if !has_author {
+        problems.push("`authors` must list at least one author".to_owned());
+    }
+    Ok(problems)
+}
+
+/// Flag expected `sections` with no matching `## Section` heading.
+fn check_sections(document: &RfcDocument, sections: &[String]) -> Vec<String> {
+    sections
+        .iter()
+        .filter(|section| {
+            let heading = format!("## {}", section.trim());
+            !document.body.lines().any(|line| line.trim_end() == heading)
+        })
+        .map(|section| format!("missing `## {}` section", section.trim()))
+        .collect()
+}
+
+/// `rfc` metadata as written, or an integer `rfc` padded to `id_width` digits.
+fn metadata_rfc_id(document: &RfcDocument, id_width: usize) -> Result<String> {
+    let id = document.id()?;
+    Ok(match document.metadata.get("rfc").and_then(Item::as_str) {
+        Some(rfc_id) => rfc_id.to_owned(),
+        None => format_rfc_id(id, id_width),
+    })
+}
+
+/// Flag a file name whose numeric prefix disagrees with the `rfc` metadata,
+/// which breaks selecting the RFC by id.
+///
+/// With `--fix`, `--id-source metadata` renames the file to the metadata id
+/// (refusing to overwrite another file) and `--id-source filename` rewrites
+/// `rfc` to the file name's prefix.
+fn check_file_id(
+    document: &mut RfcDocument,
+    id_width: usize,
+    args: &RfcValidateArgs,
+) -> Result<Vec<String>> {
+    let id = document.id()?;
+    let file_name = document
+        .path
+        .file_name()
+        .map(|name| name.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let Some(file_id) = rfc_file_id(&file_name).filter(|file_id| *file_id != id) else {
+        return Ok(Vec::new());
+    };
+    let file_prefix = file_name
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect::<String>();
+    let rfc_id = metadata_rfc_id(document, id_width)?;
+    let mismatch =
+        format!("file name id `{file_prefix}` does not match metadata `rfc = \"{rfc_id}\"`");
+    if !args.fix {
+        return Ok(vec![format!(
+            "{mismatch} (use --fix to rename the file, or --fix --id-source filename to rewrite `rfc`)"
+        )]);
+    }
+
+    match args.id_source {
+        RfcIdSource::Metadata => {
+            let renamed = document
+                .path
+                .with_file_name(format!("{rfc_id}{}", &file_name[file_prefix.len()..]));
+            if renamed.exists() {
+                return Ok(vec![format!(
+                    "{mismatch}; cannot rename to {}: file already exists",
+                    renamed.display()
+                )]);
+            }
+            fs::rename(&document.path, &renamed).with_context(|| {
+                format!(
+                    "failed to rename {} to {}",
+                    document.path.display(),
+                    renamed.display()
+                )
+            })?;
+            output::print_log(format!(
+                "fixed {}: renamed to {}",
+                document.path.display(),
+                renamed.display()
+            ));
+            document.path = renamed;
+        }
+        RfcIdSource::Filename => {
+            if document
+                .metadata
+                .get("rfc")
+                .and_then(Item::as_str)
+                .is_some()
+            {
+                document.set_value("rfc", file_prefix.as_str());
+            } else {
+                document.set_value("rfc", i64::from(file_id));
+            }
+            output::print_log(format!(
+                "fixed {}: set `rfc` from `{rfc_id}` to `{file_prefix}`",
+                document.path.display()
+            ));
+        }
+    }
+    Ok(Vec::new())
+}
+
+/// Flag a `# RFC NNNN: title` heading that disagrees with the `rfc`/`title`
+/// metadata. An integer `rfc` is padded to `id_width` digits for the heading.
+/// `fix` rewrites the heading.
+fn check_heading(document: &mut RfcDocument, id_width: usize, fix: bool) -> Result<Vec<String>> {
+    let rfc_id = metadata_rfc_id(document, id_width)?;
+    let title = document.title()?.to_owned();
+    let mut problems = Vec::new();
+
+    let expected = format!("# RFC {rfc_id}: {title}");
+    let heading = rfc_heading_index(&document.body)
+        .and_then(|index| document.body.lines().nth(index))
+        .map(str::to_owned);
+    if heading.as_deref() == Some(expected.as_str()) {
+        return Ok(problems);
+    }
+
+    if fix {
+        document.body = rewrite_rfc_heading(&document.body, &rfc_id, &title);
+        output::print_log(format!(
+            "fixed {}: rewrote heading to `{expected}`",
+            document.path.display()
+        ));
+        return Ok(problems);
+    }
+    match heading {
+        Some(heading) => problems.push(format!(
+            "heading `{heading}` does not match metadata (expected `{expected}`; use --fix to rewrite)"
+        )),
+        None => problems.push(format!(
+            "missing `{expected}` heading (use --fix to add it)"
+        )),
+    }
+    Ok(problems)
+}
+
+/// Flag `created`, `last_updated`, and revision dates that are not RFC 3339,
+/// normalizing parseable ones when `fix` is set.
+fn check_timestamps(document: &mut RfcDocument, fix: bool) -> Vec<String> {
+    let path = document.path.display().to_string();
+    let mut problems = Vec::new();
+    let mut check = |label: &str, item: &mut Item| {
+        let Some(raw) = item.as_str().map(ToOwned::to_owned) else {
+            problems.push(format!("`{label}` must be a timestamp string"));
+            return;
+        };
+        if DateTime::parse_from_rfc3339(&raw).is_ok() {
+            return;
+        }
+        match parse_timestamp(&raw) {
+            None => problems.push(format!("`{label}` has invalid timestamp `{raw}`")),
+            Some(parsed) if fix => {
+                let normalized = format_timestamp(parsed);
+                output::print_log(format!(
+                    "fixed {path}: normalized `{label}` from `{raw}` to `{normalized}`"
+                ));
+                let mut normalized = Value::from(normalized);
+                if let Some(existing) = item.as_value() {
+                    *normalized.decor_mut() = existing.decor().clone();
+                }
+                *item = Item::Value(normalized);
+            }
+            Some(_) => problems.push(format!(
+                "`{label}` timestamp `{raw}` is not RFC 3339 (use --fix to normalize)"
+            )),
+        }
+    };
+
+    for key in ["created", "last_updated"] {
+        if let Some(item) = document.metadata.get_mut(key) {
+            check(key, item);
+        }
+    }
+    if let Some(revisions) = document
+        .metadata
+        .get_mut("revision")
+        .and_then(Item::as_array_of_tables_mut)
+    {
+        for (index, revision) in revisions.iter_mut().enumerate() {
+            if let Some(item) = revision.get_mut("date") {
+                check(&format!("revision[{index}].date"), item);
+            }
+        }
+    }
+    problems
+}
+
+/// Report reference ids with no matching RFC, or prune them when `fix` is set.
+fn check_references(
+    document: &mut RfcDocument,
+    known_ids: &BTreeSet<u32>,
+    id_width: usize,
+    fix: bool,
+) -> Result<Vec<String>> {
+    let mut problems = Vec::new();
+    let mut removed = Vec::new();
+    for field in REFERENCE_FIELDS {
+        let references = document.references(field)?;
+        let (kept, dangling): (Vec<u32>, Vec<u32>) = references
+            .into_iter()
+            .partition(|id| known_ids.contains(id));
+        if dangling.is_empty() {
+            continue;
+        }
+
+        if !fix {
+            for id in dangling {
+                problems.push(format!(
+                    "`{field}` references missing RFC {}",
+                    format_rfc_id(id, id_width)
+                ));
+            }
+            continue;
+        }
+
+        if kept.is_empty() {
+            document.metadata.remove(field);
+        } else {
+            document.set_references(field, &kept);
+        }
+        removed.extend(dangling);
+    }
+
+    if !removed.is_empty() {
+        removed.sort_unstable();
+        removed.dedup();
+        let list = removed
+            .iter()
+            .map(|id| format_rfc_id(*id, id_width))
+            .collect::<Vec<_>>()
+            .join(", ");
+        document.record_revision(
+            timestamp_now()?,
+            format!("Removed dangling references to RFC {list}"),
+            None,
+        )?;
+        output::print_log(format!(
+            "fixed {}: removed dangling references to RFC {list}",
+            document.path.display()
+        ));
+    }
+
+    Ok(problems)
+}
@@ -0,0 +1,67 @@
+//! Agent config files under a skill's `agents/` directory.
+//!
+//! `build.rs` includes this file directly, so it must stay dependency-free
+//! apart from `anyhow` and `serde_yaml`.
+
+use anyhow::{Context, Result, bail};
+use serde_yaml::Value;
+
+/// Agent config file names recognized under a skill's `agents/` directory.
+pub(crate) const KNOWN_AGENT_CONFIGS: [&str; 4] =
+    ["openai.yaml", "claude.yaml", "cursor.yaml", "gemini.yaml"];
+
+/// `interface` keys every agent config must set to a string.
+const REQUIRED_INTERFACE_KEYS: [&str; 2] = ["display_name", "default_prompt"];
+
+/// Fail unless `source` is YAML with an `interface` mapping that sets every
+/// [`REQUIRED_INTERFACE_KEYS`] entry to a string.
+pub(crate) fn ensure_agent_config_valid(source: &str) -> Result<()> {
+    let config = serde_yaml::from_str::<Value>(source).context("invalid agent config YAML")?;
+    let Some(interface) = config.get("interface") else {
+        bail!("missing `interface` mapping");
+    };
+    if !interface.is_mapping() {
+        bail!("`interface` must be a mapping");
+    }
+    for key in REQUIRED_INTERFACE_KEYS {
+        match interface.get(key) {
+            Some(Value::String(_)) => {}
+            None => bail!("`interface` is missing `{key}`"),
+            Some(_) => bail!("`interface.{key}` must be a string"),
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ensure_agent_config_valid;
+
+    #[test]
+    fn agent_config_requires_parsed_interface_keys() {
+        ensure_agent_config_valid(
+            "interface:\n  display_name: \"A\"\n  default_prompt: \"Use $a.\"\n",
+        )
+        .expect("complete config should pass");
+
+        for (source, expected) in [
+            (
+                "# interface: todo\nrules: []\n",
+                "missing `interface` mapping",
+            ),
+            ("interface: todo\n", "`interface` must be a mapping"),
+            (
+                "interface:\n  display_name: A\n",
+                "`interface` is missing `default_prompt`",
+            ),
+            (
+                "interface: {display_name: [A], default_prompt: p}\n",
+                "`interface.display_name` must be a string",
+            ),
+            ("interface: [\n", "invalid agent config YAML"),
+        ] {
+            let error = ensure_agent_config_valid(source).expect_err("expected error");
+            assert!(error.to_string().contains(expected), "{source}: {error}");
+        }
+    }
+}
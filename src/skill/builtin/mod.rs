@@ -1,12 +1,28 @@
+use std::collections::BTreeMap;
+
 use anyhow::{Context, Result};
 use serde::Deserialize;
 
+use super::metadata::SkillDeprecation;
+
 const BUILTIN_CATALOG_JSON: &str = include_str!(concat!(env!("OUT_DIR"), "/builtin_skills.json"));
 
 #[derive(Debug, Clone)]
 pub(crate) struct BuiltinSkill {
     pub(crate) name: String,
+    /// Position in `builtin-manifest.toml`.
+    pub(crate) order: usize,
     pub(crate) description: String,
+    /// Declared `version` from frontmatter.
+    pub(crate) version: Option<String>,
+    /// Declared `capabilities` from frontmatter.
+    pub(crate) capabilities: Vec<String>,
+    /// Declared `tags` from frontmatter.
+    pub(crate) tags: Vec<String>,
+    /// Localized descriptions keyed by language tag.
+    pub(crate) localized_descriptions: BTreeMap<String, String>,
+    /// Set when the skill declares `deprecated: true`.
+    pub(crate) deprecation: Option<SkillDeprecation>,
     pub(crate) files: Vec<BuiltinSkillFile>,
 }
 
@@ -14,6 +30,14 @@ pub(crate) struct BuiltinSkill {
 pub(crate) struct BuiltinSkillFile {
     pub(crate) path: String,
     pub(crate) content: String,
+    pub(crate) executable: bool,
+}
+
+impl BuiltinSkillFile {
+    /// Unix permission bits to apply when the file is written or archived.
+    pub(crate) fn mode(&self) -> u32 {
+        if self.executable { 0o755 } else { 0o644 }
+    }
 }
 
 pub(crate) fn load_skills() -> Result<Vec<BuiltinSkill>> {
@@ -24,13 +48,23 @@ pub(crate) fn load_skills() -> Result<Vec<BuiltinSkill>> {
         .into_iter()
         .map(|skill| BuiltinSkill {
             name: skill.name,
+            order: skill.order,
             description: skill.description,
+            version: skill.version,
+            capabilities: skill.capabilities,
+            tags: skill.tags,
+            localized_descriptions: skill.descriptions,
+            deprecation: skill.deprecation.map(|deprecation| SkillDeprecation {
+                note: deprecation.note,
+                replacement: deprecation.replacement,
+            }),
             files: skill
                 .files
                 .into_iter()
                 .map(|file| BuiltinSkillFile {
                     path: file.path,
                     content: file.content,
+                    executable: file.executable,
                 })
                 .collect(),
         })
@@ -47,12 +81,32 @@ struct BuiltinCatalogJson {
 #[derive(Debug, Deserialize)]
 struct BuiltinSkillJson {
     name: String,
+    #[serde(default)]
+    order: usize,
     description: String,
+    #[serde(default)]
+    version: Option<String>,
+    #[serde(default)]
+    capabilities: Vec<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    descriptions: BTreeMap<String, String>,
+    #[serde(default)]
+    deprecation: Option<BuiltinSkillDeprecationJson>,
     files: Vec<BuiltinSkillFileJson>,
 }
 
+#[derive(Debug, Deserialize)]
+struct BuiltinSkillDeprecationJson {
+    note: Option<String>,
+    replacement: Option<String>,
+}
+
 #[derive(Debug, Deserialize)]
 struct BuiltinSkillFileJson {
     path: String,
     content: String,
+    #[serde(default)]
+    executable: bool,
 }
@@ -1,5 +1,5 @@
 use std::{
-    collections::BTreeMap,
+    collections::{BTreeMap, btree_map::Entry},
     fs,
     path::{Path, PathBuf},
 };
@@ -11,14 +11,20 @@ use crate::cli::SkillListOrigin;
 
 use super::{
     builtin::BuiltinSkill,
-    metadata::{ensure_optional_openai_yaml_valid, read_skill_metadata},
+    metadata::{SkillDeprecation, ensure_agent_configs_valid, read_skill_metadata},
 };
 
 #[derive(Debug, Clone)]
 pub(crate) struct WorkspaceSkill {
     pub(crate) name: String,
     pub(crate) description: String,
+    pub(crate) capabilities: Vec<String>,
+    pub(crate) tags: Vec<String>,
+    pub(crate) localized_descriptions: BTreeMap<String, String>,
+    pub(crate) deprecation: Option<SkillDeprecation>,
     pub(crate) path: PathBuf,
+    /// Skills root the skill was discovered under.
+    pub(crate) root: PathBuf,
 }
 
 #[derive(Debug, Clone, Serialize)]
@@ -28,15 +34,83 @@ pub(crate) enum PreferredOrigin {
     Workspace,
 }
 
+impl PreferredOrigin {
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Builtin => "builtin",
+            Self::Workspace => "workspace",
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub(crate) struct SkillDiscoveryEntry {
     pub(crate) name: String,
+    /// Position of the matching built-in in `builtin-manifest.toml`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) order: Option<usize>,
     pub(crate) description: String,
+    pub(crate) capabilities: Vec<String>,
+    pub(crate) tags: Vec<String>,
+    /// Localized descriptions, applied by `skill list --lang`.
+    #[serde(skip)]
+    pub(crate) localized_descriptions: BTreeMap<String, String>,
+    pub(crate) deprecated: bool,
+    /// Migration hints for deprecated skills.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) deprecation: Option<SkillDeprecation>,
     pub(crate) builtin_available: bool,
     pub(crate) workspace_path: Option<String>,
+    pub(crate) workspace_root: Option<String>,
     pub(crate) preferred_origin: PreferredOrigin,
 }
 
+impl SkillDiscoveryEntry {
+    /// Entry for a built-in skill, preferred when no workspace copy exists.
+    fn from_builtin(skill: &BuiltinSkill) -> Self {
+        Self {
+            name: skill.name.clone(),
+            order: Some(skill.order),
+            description: skill.description.clone(),
+            capabilities: skill.capabilities.clone(),
+            tags: skill.tags.clone(),
+            localized_descriptions: skill.localized_descriptions.clone(),
+            deprecated: skill.deprecation.is_some(),
+            deprecation: skill.deprecation.clone(),
+            builtin_available: true,
+            workspace_path: None,
+            workspace_root: None,
+            preferred_origin: PreferredOrigin::Builtin,
+        }
+    }
+
+    /// Entry for a workspace skill; `builtin_order` is the manifest position of
+    /// a built-in with the same name, if any.
+    fn from_workspace(skill: &WorkspaceSkill, builtin_order: Option<usize>) -> Self {
+        Self {
+            name: skill.name.clone(),
+            order: builtin_order,
+            description: skill.description.clone(),
+            capabilities: skill.capabilities.clone(),
+            tags: skill.tags.clone(),
+            localized_descriptions: skill.localized_descriptions.clone(),
+            deprecated: skill.deprecation.is_some(),
+            deprecation: skill.deprecation.clone(),
+            builtin_available: builtin_order.is_some(),
+            workspace_path: Some(path_to_string(&skill.path)),
+            workspace_root: Some(path_to_string(&skill.root)),
+            preferred_origin: PreferredOrigin::Workspace,
+        }
+    }
+
+    /// Use the `lang` description when the skill has one.
+    pub(crate) fn localize(&mut self, lang: &str) {
+        if let Some(description) = self.localized_descriptions.get(lang) {
+            self.description = description.clone();
+        }
+    }
+}
+
 pub(crate) fn discover_workspace_skills(skills_root: &Path) -> Result<Vec<WorkspaceSkill>> {
     if !skills_root.exists() {
         return Ok(Vec::new());
@@ -73,18 +147,37 @@ pub(crate) fn discover_workspace_skills(skills_root: &Path) -> Result<Vec<Worksp
                 metadata.name
             );
         }
-        ensure_optional_openai_yaml_valid(&skill_path)?;
+        ensure_agent_configs_valid(&skill_path)?;
 
         skills.push(WorkspaceSkill {
             name: metadata.name,
             description: metadata.description,
+            capabilities: metadata.capabilities,
+            tags: metadata.tags,
+            localized_descriptions: metadata.localized_descriptions,
+            deprecation: metadata.deprecation,
             path: skill_path,
+            root: skills_root.to_path_buf(),
         });
     }
 
     Ok(skills)
 }
 
+/// Discover workspace skills across `roots`; earlier roots shadow later ones
+/// with the same skill name.
+pub(crate) fn discover_workspace_skills_in_roots(roots: &[PathBuf]) -> Result<Vec<WorkspaceSkill>> {
+    let mut index = BTreeMap::<String, WorkspaceSkill>::new();
+    for root in roots {
+        for skill in discover_workspace_skills(root)? {
+            if let Entry::Vacant(entry) = index.entry(skill.name.clone()) {
+                entry.insert(skill);
+            }
+        }
+    }
+    Ok(index.into_values().collect())
+}
+
 pub(crate) fn discover_skills(
     origin: SkillListOrigin,
     builtin_skills: &[BuiltinSkill],
@@ -93,52 +186,28 @@ pub(crate) fn discover_skills(
     match origin {
         SkillListOrigin::Builtin => builtin_skills
             .iter()
-            .map(|skill| SkillDiscoveryEntry {
-                name: skill.name.clone(),
-                description: skill.description.clone(),
-                builtin_available: true,
-                workspace_path: None,
-                preferred_origin: PreferredOrigin::Builtin,
-            })
+            .map(SkillDiscoveryEntry::from_builtin)
             .collect(),
         SkillListOrigin::Workspace => {
             let builtin = builtin_index(builtin_skills);
             workspace_skills
                 .iter()
-                .map(|skill| SkillDiscoveryEntry {
-                    name: skill.name.clone(),
-                    description: skill.description.clone(),
-                    builtin_available: builtin.contains_key(&skill.name),
-                    workspace_path: Some(path_to_string(&skill.path)),
-                    preferred_origin: PreferredOrigin::Workspace,
+                .map(|skill| {
+                    let order = builtin.get(&skill.name).map(|builtin| builtin.order);
+                    SkillDiscoveryEntry::from_workspace(skill, order)
                 })
                 .collect()
         }
         SkillListOrigin::All => {
             let mut index = BTreeMap::<String, SkillDiscoveryEntry>::new();
             for skill in builtin_skills {
-                index.insert(
-                    skill.name.clone(),
-                    SkillDiscoveryEntry {
-                        name: skill.name.clone(),
-                        description: skill.description.clone(),
-                        builtin_available: true,
-                        workspace_path: None,
-                        preferred_origin: PreferredOrigin::Builtin,
-                    },
-                );
+                index.insert(skill.name.clone(), SkillDiscoveryEntry::from_builtin(skill));
             }
             for skill in workspace_skills {
-                let builtin_available = index.contains_key(&skill.name);
+                let order = index.get(&skill.name).and_then(|entry| entry.order);
                 index.insert(
                     skill.name.clone(),
-                    SkillDiscoveryEntry {
-                        name: skill.name.clone(),
-                        description: skill.description.clone(),
-                        builtin_available,
-                        workspace_path: Some(path_to_string(&skill.path)),
-                        preferred_origin: PreferredOrigin::Workspace,
-                    },
+                    SkillDiscoveryEntry::from_workspace(skill, order),
                 );
             }
 
@@ -0,0 +1,163 @@
+use anyhow::Result;
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+use crate::cli::{SkillCatalogArgs, SkillCatalogFormat};
+
+use super::builtin::{self, BuiltinSkill};
+
+/// Print SHA-256 digests of the embedded built-in skill catalog.
+///
+/// Each skill digest covers its name, description, and every file (path,
+/// executable bit, content) in path order. The catalog digest covers the
+/// skill digests in name order, so it pins the exact built-in set a binary
+/// carries regardless of manifest order.
+pub(crate) fn run(args: SkillCatalogArgs) -> Result<()> {
+    let skills = builtin::load_skills()?;
+    let mut entries = skills
+        .iter()
+        .map(|skill| SkillDigestJson {
+            name: skill.name.clone(),
+            digest: skill_digest(skill),
+        })
+        .collect::<Vec<_>>();
+    entries.sort_by(|left, right| left.name.cmp(&right.name));
+    let digest = catalog_digest(&entries);
+
+    match args.format {
+        SkillCatalogFormat::Text if args.digest => println!("{digest}"),
+        SkillCatalogFormat::Text => {
+            for entry in &entries {
+                println!("{}\t{}", entry.name, entry.digest);
+            }
+            println!("catalog\t{digest}");
+        }
+        SkillCatalogFormat::Json => {
+            let payload = SkillCatalogJson {
+                schema_version: 1,
+                algorithm: "sha256",
+                digest,
+                skills: entries,
+            };
+            println!("{}", serde_json::to_string_pretty(&payload)?);
+        }
+    }
+    Ok(())
+}
+
+/// `sha256:<hex>` over one skill's name, description, and files.
+fn skill_digest(skill: &BuiltinSkill) -> String {
+    let mut hasher = Sha256::new();
+    update_field(&mut hasher, skill.name.as_bytes());
+    update_field(&mut hasher, skill.description.as_bytes());
+    let mut files = skill.files.iter().collect::<Vec<_>>();
+    files.sort_by(|left, right| left.path.cmp(&right.path));
+    for file in files {
+        update_field(&mut hasher, file.path.as_bytes());
+        update_field(&mut hasher, &[u8::from(file.executable)]);
+        update_field(&mut hasher, file.content.as_bytes());
+    }
+    format_digest(hasher)
+}
+
+/// `sha256:<hex>` over name-sorted skill digests.
+fn catalog_digest(entries: &[SkillDigestJson]) -> String {
+    let mut hasher = Sha256::new();
+    for entry in entries {
+        update_field(&mut hasher, entry.name.as_bytes());
+        update_field(&mut hasher, entry.digest.as_bytes());
+    }
+    format_digest(hasher)
+}
+
+/// Hash a length-prefixed field so adjacent fields cannot run together.
+fn update_field(hasher: &mut Sha256, bytes: &[u8]) {
+    hasher.update((bytes.len() as u64).to_le_bytes());
+    hasher.update(bytes);
+}
+
+fn format_digest(hasher: Sha256) -> String {
+    let hex = hasher
+        .finalize()
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect::<String>();
+    format!("sha256:{hex}")
+}
+
+#[derive(Debug, Serialize)]
+struct SkillDigestJson {
+    name: String,
+    digest: String,
+}
+
+#[derive(Debug, Serialize)]
+struct SkillCatalogJson {
+    schema_version: u32,
+    algorithm: &'static str,
+    digest: String,
+    skills: Vec<SkillDigestJson>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{SkillDigestJson, catalog_digest, skill_digest};
+    use crate::skill::builtin::{BuiltinSkill, BuiltinSkillFile};
+
+    fn fixture_skill(name: &str, files: &[(&str, &str)]) -> BuiltinSkill {
+        BuiltinSkill {
+            name: name.to_owned(),
+            order: 0,
+            description: format!("{name} description"),
+            version: None,
+            capabilities: Vec::new(),
+            tags: Vec::new(),
+            localized_descriptions: BTreeMap::new(),
+            deprecation: None,
+            files: files
+                .iter()
+                .map(|(path, content)| BuiltinSkillFile {
+                    path: (*path).to_owned(),
+                    content: (*content).to_owned(),
+                    executable: false,
+                })
+                .collect(),
+        }
+    }
+
+    fn fixture_catalog(skills: &[BuiltinSkill]) -> String {
+        let entries = skills
+            .iter()
+            .map(|skill| SkillDigestJson {
+                name: skill.name.clone(),
+                digest: skill_digest(skill),
+            })
+            .collect::<Vec<_>>();
+        catalog_digest(&entries)
+    }
+
+    #[test]
+    fn digests_are_stable_and_track_content() {
+        let original = [
+            fixture_skill("alpha", &[("SKILL.md", "a"), ("notes.md", "b")]),
+            fixture_skill("beta", &[("SKILL.md", "c")]),
+        ];
+        let reordered_files = [
+            fixture_skill("alpha", &[("notes.md", "b"), ("SKILL.md", "a")]),
+            fixture_skill("beta", &[("SKILL.md", "c")]),
+        ];
+        let edited = [
+            fixture_skill("alpha", &[("SKILL.md", "a"), ("notes.md", "b!")]),
+            fixture_skill("beta", &[("SKILL.md", "c")]),
+        ];
+
+        let digest = fixture_catalog(&original);
+        assert!(digest.starts_with("sha256:") && digest.len() == 71);
+        assert_eq!(digest, fixture_catalog(&original));
+        assert_eq!(digest, fixture_catalog(&reordered_files));
+        assert_ne!(digest, fixture_catalog(&edited));
+        assert_eq!(skill_digest(&original[1]), skill_digest(&edited[1]));
+    }
+}
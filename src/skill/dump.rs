@@ -8,11 +8,21 @@ use super::{builtin, materialize, paths, select};
 pub(crate) fn run(args: SkillDumpArgs) -> Result<()> {
     let skills = builtin::load_skills()?;
     let selected = select::select_builtin_skills(&skills, args.name.as_deref(), args.all)?;
-    let target_root = paths::resolve_dump_target(args.to.as_ref())?;
+    let target_root = paths::resolve_dump_target(args.to.as_ref(), args.global)?;
     let materialized = materialize::materialize_skills(&selected, &target_root, args.force)?;
+    materialize::print_deprecation_warnings(&selected);
 
-    for skill in materialized {
+    for skill in &materialized {
         output::print_path(skill.path.display());
     }
-    Ok(())
+    if args.print_prompts {
+        materialize::print_default_prompts(&materialized)?;
+    }
+    if !args.run_hooks {
+        materialize::print_skipped_post_install_hooks(&materialized);
+        return Ok(());
+    }
+    let runs = materialize::run_post_install_hooks(&materialized)?;
+    materialize::print_post_install_hooks(&runs);
+    materialize::ensure_post_install_hooks_succeeded(&runs)
 }
@@ -0,0 +1,44 @@
+use std::{env, path::Path, process::Command};
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::cli::SkillEditArgs;
+
+use super::{init::SKILLS_ROOT, metadata::read_skill_metadata};
+
+/// Open a workspace skill's `SKILL.md` (or directory) in `$EDITOR`.
+pub(crate) fn run(args: SkillEditArgs) -> Result<()> {
+    let skill_dir = Path::new(SKILLS_ROOT).join(&args.name);
+    if !skill_dir.join("SKILL.md").is_file() {
+        bail!(
+            "skill `{}` was not found under `{SKILLS_ROOT}`; create it with `agx skill new {}`",
+            args.name,
+            args.name
+        );
+    }
+    read_skill_metadata(&skill_dir).with_context(|| format!("skill `{}` is invalid", args.name))?;
+
+    let target = if args.dir {
+        skill_dir
+    } else {
+        skill_dir.join("SKILL.md")
+    };
+
+    let editor = env::var("EDITOR")
+        .or_else(|_| env::var("VISUAL"))
+        .map_err(|_| anyhow!("set `$EDITOR` to open skills for editing"))?;
+    let mut parts = editor.split_whitespace();
+    let program = parts
+        .next()
+        .ok_or_else(|| anyhow!("`$EDITOR` is empty; set it to an editor command"))?;
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(&target)
+        .status()
+        .with_context(|| format!("failed to launch editor `{editor}`"))?;
+    if !status.success() {
+        bail!("editor `{editor}` exited with {status}");
+    }
+    Ok(())
+}
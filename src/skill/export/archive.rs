@@ -0,0 +1,203 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::Write,
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use flate2::{Compression, write::GzEncoder};
+use sha2::{Digest, Sha256};
+use tar::Builder;
+
+use crate::skill::builtin::BuiltinSkill;
+
+/// Write `skills` into a single `.tar.gz` archive at `output`.
+pub(super) fn write_archive(output: &Path, skills: &[BuiltinSkill], dedup: bool) -> Result<()> {
+    if let Some(parent) = output.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create `{}`", parent.display()))?;
+    }
+
+    let archive_file =
+        File::create(output).with_context(|| format!("failed to create `{}`", output.display()))?;
+    encode_archive(archive_file, skills, dedup)?;
+    Ok(())
+}
+
+/// Encode `skills` as a gzip-compressed tar stream into `writer`.
+pub(crate) fn encode_archive<W: Write>(
+    writer: W,
+    skills: &[BuiltinSkill],
+    dedup: bool,
+) -> Result<W> {
+    let encoder = GzEncoder::new(writer, Compression::default());
+    let mut builder = Builder::new(encoder);
+    let mut stored = BTreeMap::<(String, u32), PathBuf>::new();
+
+    for skill in skills {
+        for file in &skill.files {
+            let archive_path = resolve_archive_path(&skill.name, &file.path)?;
+            if dedup {
+                let key = (sha256_hex(file.content.as_bytes()), file.mode());
+                if let Some(target) = stored.get(&key) {
+                    append_archive_link(&mut builder, &archive_path, target)?;
+                    continue;
+                }
+                stored.insert(key, archive_path.clone());
+            }
+            append_archive_file(
+                &mut builder,
+                &archive_path,
+                file.content.as_bytes(),
+                file.mode(),
+            )?;
+        }
+    }
+
+    let encoder = builder
+        .into_inner()
+        .context("failed to finalize skills tar archive")?;
+    encoder
+        .finish()
+        .context("failed to finalize skills gzip stream")
+}
+
+pub(crate) fn sha256_hex(bytes: &[u8]) -> String {
+    Sha256::digest(bytes)
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+pub(super) fn resolve_archive_path(skill_name: &str, relative_path: &str) -> Result<PathBuf> {
+    let relative = Path::new(relative_path);
+    if relative.is_absolute() {
+        bail!("skill file path `{relative_path}` must be relative");
+    }
+    for component in relative.components() {
+        if !matches!(component, Component::Normal(_)) {
+            bail!("skill file path `{relative_path}` must not contain traversal components");
+        }
+    }
+
+    Ok(Path::new(".agents/skills").join(skill_name).join(relative))
+}
+
+/// Append a hard link entry so `path` reuses the content stored at `target`.
+fn append_archive_link<W: Write>(
+    builder: &mut Builder<GzEncoder<W>>,
+    path: &Path,
+    target: &Path,
+) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_entry_type(tar::EntryType::Link);
+    header.set_size(0);
+    builder
+        .append_link(&mut header, path, target)
+        .with_context(|| format!("failed to append link `{}` to archive", path.display()))?;
+    Ok(())
+}
+
+fn append_archive_file<W: Write>(
+    builder: &mut Builder<GzEncoder<W>>,
+    path: &Path,
+    bytes: &[u8],
+    mode: u32,
+) -> Result<()> {
+    let mut header = tar::Header::new_gnu();
+    header.set_size(bytes.len() as u64);
+    header.set_mode(mode);
+    header.set_cksum();
+    builder
+        .append_data(&mut header, path, bytes)
+        .with_context(|| format!("failed to append `{}` to archive", path.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use flate2::read::GzDecoder;
+    use tar::{Archive, EntryType};
+
+    use super::encode_archive;
+    use crate::skill::builtin::{BuiltinSkill, BuiltinSkillFile};
+
+    fn skill(name: &str) -> BuiltinSkill {
+        BuiltinSkill {
+            name: name.to_owned(),
+            order: 0,
+            description: "Shares a reference.".to_owned(),
+            version: None,
+            capabilities: Vec::new(),
+            tags: Vec::new(),
+            localized_descriptions: Default::default(),
+            deprecation: None,
+            files: vec![
+                BuiltinSkillFile {
+                    path: "SKILL.md".to_owned(),
+                    content: format!("---\nname: {name}\ndescription: Shares a reference.\n---\n"),
+                    executable: false,
+                },
+                BuiltinSkillFile {
+                    path: "references/shared.md".to_owned(),
+                    content: "# Shared reference\n".repeat(64),
+                    executable: false,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn dedup_stores_identical_files_once() {
+        let skills = [skill("first"), skill("second")];
+        let archive = encode_archive(Vec::new(), &skills, true).expect("archive should encode");
+
+        let mut archive = Archive::new(GzDecoder::new(archive.as_slice()));
+        let mut regular = Vec::new();
+        let mut links = Vec::new();
+        for entry in archive.entries().expect("archive should read") {
+            let mut entry = entry.expect("entry should read");
+            let path = entry.path().expect("path").display().to_string();
+            match entry.header().entry_type() {
+                EntryType::Link => links.push((
+                    path,
+                    entry
+                        .link_name()
+                        .expect("link name")
+                        .expect("link target")
+                        .display()
+                        .to_string(),
+                )),
+                _ => {
+                    let mut content = String::new();
+                    entry.read_to_string(&mut content).expect("content");
+                    regular.push(path);
+                }
+            }
+        }
+        assert_eq!(
+            links,
+            vec![(
+                ".agents/skills/second/references/shared.md".to_owned(),
+                ".agents/skills/first/references/shared.md".to_owned()
+            )]
+        );
+        assert_eq!(regular.len(), 3);
+
+        let target = std::env::temp_dir().join(format!("agx-export-dedup-{}", std::process::id()));
+        let archive = encode_archive(Vec::new(), &skills, true).expect("archive should encode");
+        Archive::new(GzDecoder::new(archive.as_slice()))
+            .unpack(&target)
+            .expect("archive should unpack");
+        let unpacked =
+            std::fs::read_to_string(target.join(".agents/skills/second/references/shared.md"))
+                .expect("linked file should unpack");
+        assert_eq!(unpacked, skills[1].files[1].content);
+        std::fs::remove_dir_all(target).expect("failed to clean temp dir");
+    }
+}
@@ -0,0 +1,133 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+use crate::output;
+use crate::skill::builtin::BuiltinSkill;
+
+use super::archive::{resolve_archive_path, sha256_hex};
+
+/// Write a JSON inventory of `skills` to `output`: archive paths and sha256
+/// checksums per file, without file contents.
+pub(super) fn write_manifest(output: &Path, skills: &[BuiltinSkill]) -> Result<()> {
+    let mut entries = Vec::with_capacity(skills.len());
+    for skill in skills {
+        entries.push(SkillManifestEntryJson {
+            name: &skill.name,
+            description: &skill.description,
+            files: manifest_files(skill)?,
+        });
+    }
+
+    if let Some(parent) = output.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("failed to create `{}`", parent.display()))?;
+    }
+    let manifest = SkillManifestJson {
+        schema_version: 1,
+        skills: entries,
+    };
+    fs::write(output, serde_json::to_vec_pretty(&manifest)?)
+        .with_context(|| format!("failed to write `{}`", output.display()))
+}
+
+/// Manifest file entries for one skill, in catalog order.
+fn manifest_files(skill: &BuiltinSkill) -> Result<Vec<SkillManifestFileJson>> {
+    skill
+        .files
+        .iter()
+        .map(|file| {
+            Ok(SkillManifestFileJson {
+                path: resolve_archive_path(&skill.name, &file.path)?
+                    .to_string_lossy()
+                    .into_owned(),
+                sha256: sha256_hex(file.content.as_bytes()),
+                size: file.content.len() as u64,
+                executable: file.executable,
+            })
+        })
+        .collect()
+}
+
+/// Keep the skills whose description or files differ from the `--since`
+/// manifest (written earlier by `--manifest-only`), logging the unchanged ones.
+///
+/// Skills missing from the manifest count as changed.
+pub(super) fn changed_since(
+    manifest_path: &Path,
+    skills: Vec<BuiltinSkill>,
+) -> Result<Vec<BuiltinSkill>> {
+    let source = fs::read_to_string(manifest_path)
+        .with_context(|| format!("failed to read `{}`", manifest_path.display()))?;
+    let manifest: PriorManifestJson = serde_json::from_str(&source).with_context(|| {
+        format!(
+            "`{}` is not a skill export manifest",
+            manifest_path.display()
+        )
+    })?;
+
+    let mut changed = Vec::new();
+    let mut unchanged = Vec::new();
+    for skill in skills {
+        let mut files = manifest_files(&skill)?;
+        files.sort_by(|left, right| left.path.cmp(&right.path));
+        let same = manifest.skills.iter().any(|prior| {
+            let mut prior_files = prior.files.clone();
+            prior_files.sort_by(|left, right| left.path.cmp(&right.path));
+            prior.name == skill.name
+                && prior.description == skill.description
+                && prior_files == files
+        });
+        if same {
+            unchanged.push(skill.name);
+        } else {
+            changed.push(skill);
+        }
+    }
+
+    if !unchanged.is_empty() {
+        output::print_log(format!(
+            "unchanged since {}: {}",
+            manifest_path.display(),
+            unchanged.join(", ")
+        ));
+    }
+    Ok(changed)
+}
+
+#[derive(Debug, Serialize)]
+struct SkillManifestJson<'a> {
+    schema_version: u32,
+    skills: Vec<SkillManifestEntryJson<'a>>,
+}
+
+#[derive(Debug, Serialize)]
+struct SkillManifestEntryJson<'a> {
+    name: &'a str,
+    description: &'a str,
+    files: Vec<SkillManifestFileJson>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct SkillManifestFileJson {
+    path: String,
+    sha256: String,
+    size: u64,
+    executable: bool,
+}
+
+/// A manifest read back for `--since`.
+#[derive(Debug, Deserialize)]
+struct PriorManifestJson {
+    skills: Vec<PriorManifestEntryJson>,
+}
+
+#[derive(Debug, Deserialize)]
+struct PriorManifestEntryJson {
+    name: String,
+    description: String,
+    files: Vec<SkillManifestFileJson>,
+}
@@ -0,0 +1,87 @@
+mod archive;
+mod manifest;
+mod oci;
+
+use anyhow::{Result, bail};
+
+use crate::cli::{SkillExportArgs, SkillExportFormat, SkillExportOrigin};
+use crate::output;
+
+use super::builtin;
+
+pub(crate) use archive::{encode_archive, sha256_hex};
+
+pub(crate) fn run(args: SkillExportArgs) -> Result<()> {
+    let mut skills = match args.origin {
+        SkillExportOrigin::Builtin => builtin::load_skills()?,
+    };
+    if skills.is_empty() {
+        bail!("no built-in skills are available to export");
+    }
+    if !args.tags.is_empty() {
+        skills.retain(|skill| skill.tags.iter().any(|tag| args.tags.contains(tag)));
+        if skills.is_empty() {
+            bail!(
+                "no built-in skills are tagged with {}",
+                args.tags
+                    .iter()
+                    .map(|tag| format!("`{tag}`"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            );
+        }
+    }
+    if let Some(since) = &args.since {
+        skills = manifest::changed_since(since, skills)?;
+    }
+
+    if args.manifest_only {
+        if args.format == SkillExportFormat::Oci {
+            bail!("`--manifest-only` is only supported with `--format tar-gz`");
+        }
+        let output = args
+            .output
+            .as_ref()
+            .expect("`--output` is required without `--split`");
+        manifest::write_manifest(output, &skills)?;
+        output::print_path(output.display());
+        return Ok(());
+    }
+
+    if args.format == SkillExportFormat::Oci {
+        if args.split {
+            bail!("`--split` is only supported with `--format tar-gz`");
+        }
+        if args.dedup {
+            bail!("`--dedup` is only supported with `--format tar-gz`");
+        }
+        let output = args
+            .output
+            .as_ref()
+            .expect("`--output` is required without `--split`");
+        oci::write_oci_layout(output, &skills)?;
+        output::print_path(output.display());
+        return Ok(());
+    }
+
+    if args.split {
+        let output_dir = args
+            .output_dir
+            .as_ref()
+            .expect("`--split` requires `--output-dir`");
+        for skill in &skills {
+            let archive_path = output_dir.join(format!("{}.tar.gz", skill.name));
+            archive::write_archive(&archive_path, std::slice::from_ref(skill), args.dedup)?;
+            output::print_path(archive_path.display());
+        }
+        return Ok(());
+    }
+
+    let output = args
+        .output
+        .as_ref()
+        .expect("`--output` is required without `--split`");
+    archive::write_archive(output, &skills, args.dedup)?;
+    output::print_path(output.display());
+    Ok(())
+}
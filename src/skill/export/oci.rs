@@ -0,0 +1,135 @@
+use std::{collections::BTreeMap, fs, path::Path};
+
+use anyhow::{Context, Result};
+use serde::Serialize;
+
+use crate::skill::builtin::BuiltinSkill;
+
+use super::archive::{encode_archive, sha256_hex};
+
+const OCI_MANIFEST_MEDIA_TYPE: &str = "application/vnd.oci.image.manifest.v1+json";
+const OCI_INDEX_MEDIA_TYPE: &str = "application/vnd.oci.image.index.v1+json";
+const OCI_LAYER_MEDIA_TYPE: &str = "application/vnd.oci.image.layer.v1.tar+gzip";
+const SKILL_ARTIFACT_TYPE: &str = "application/vnd.agx.skill.v1";
+const SKILL_CONFIG_MEDIA_TYPE: &str = "application/vnd.agx.skill.config.v1+json";
+
+/// Write `skills` as an OCI image layout under `output`: one manifest per
+/// skill, each with a config blob and a single `.tar.gz` layer.
+pub(super) fn write_oci_layout(output: &Path, skills: &[BuiltinSkill]) -> Result<()> {
+    let blobs_dir = output.join("blobs/sha256");
+    fs::create_dir_all(&blobs_dir)
+        .with_context(|| format!("failed to create `{}`", blobs_dir.display()))?;
+    let layout_path = output.join("oci-layout");
+    fs::write(&layout_path, "{\"imageLayoutVersion\":\"1.0.0\"}\n")
+        .with_context(|| format!("failed to write `{}`", layout_path.display()))?;
+
+    let mut manifests = Vec::with_capacity(skills.len());
+    for skill in skills {
+        let layer = encode_archive(Vec::new(), std::slice::from_ref(skill), false)?;
+        let layer = write_oci_blob(&blobs_dir, OCI_LAYER_MEDIA_TYPE, &layer)?.with_annotations([(
+            "org.opencontainers.image.title",
+            format!("{}.tar.gz", skill.name),
+        )]);
+
+        let config = serde_json::to_vec(&SkillConfigJson {
+            schema_version: 1,
+            name: &skill.name,
+            description: &skill.description,
+        })?;
+        let config = write_oci_blob(&blobs_dir, SKILL_CONFIG_MEDIA_TYPE, &config)?;
+
+        let manifest = serde_json::to_vec(&OciManifestJson {
+            schema_version: 2,
+            media_type: OCI_MANIFEST_MEDIA_TYPE,
+            artifact_type: SKILL_ARTIFACT_TYPE,
+            config,
+            layers: vec![layer],
+            annotations: [
+                ("org.opencontainers.image.title", skill.name.clone()),
+                (
+                    "org.opencontainers.image.description",
+                    skill.description.clone(),
+                ),
+            ]
+            .into_iter()
+            .collect(),
+        })?;
+        manifests.push(
+            write_oci_blob(&blobs_dir, OCI_MANIFEST_MEDIA_TYPE, &manifest)?
+                .with_annotations([("org.opencontainers.image.ref.name", skill.name.clone())]),
+        );
+    }
+
+    let index = OciIndexJson {
+        schema_version: 2,
+        media_type: OCI_INDEX_MEDIA_TYPE,
+        manifests,
+    };
+    let index_path = output.join("index.json");
+    fs::write(&index_path, serde_json::to_vec_pretty(&index)?)
+        .with_context(|| format!("failed to write `{}`", index_path.display()))?;
+    Ok(())
+}
+
+/// Store `bytes` under its sha256 digest and return its descriptor.
+fn write_oci_blob(
+    blobs_dir: &Path,
+    media_type: &'static str,
+    bytes: &[u8],
+) -> Result<OciDescriptorJson> {
+    let hex = sha256_hex(bytes);
+    let path = blobs_dir.join(&hex);
+    fs::write(&path, bytes).with_context(|| format!("failed to write `{}`", path.display()))?;
+    Ok(OciDescriptorJson {
+        media_type,
+        digest: format!("sha256:{hex}"),
+        size: bytes.len() as u64,
+        annotations: BTreeMap::new(),
+    })
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OciDescriptorJson {
+    media_type: &'static str,
+    digest: String,
+    size: u64,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    annotations: BTreeMap<&'static str, String>,
+}
+
+impl OciDescriptorJson {
+    fn with_annotations<const N: usize>(
+        mut self,
+        annotations: [(&'static str, String); N],
+    ) -> Self {
+        self.annotations.extend(annotations);
+        self
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OciManifestJson {
+    schema_version: u32,
+    media_type: &'static str,
+    artifact_type: &'static str,
+    config: OciDescriptorJson,
+    layers: Vec<OciDescriptorJson>,
+    annotations: BTreeMap<&'static str, String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct OciIndexJson {
+    schema_version: u32,
+    media_type: &'static str,
+    manifests: Vec<OciDescriptorJson>,
+}
+
+#[derive(Debug, Serialize)]
+struct SkillConfigJson<'a> {
+    schema_version: u32,
+    name: &'a str,
+    description: &'a str,
+}
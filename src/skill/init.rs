@@ -1,12 +1,16 @@
-use std::{fs, path::Path};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
 
 use anyhow::{Context, Result, bail};
 use arboard::Clipboard;
 
-use crate::cli::{SkillInitArgs, SkillNewArgs};
+use crate::cli::{SkillAgentFormat, SkillInitArgs, SkillNewArgs};
 use crate::output;
+use crate::rfc::util::slugify;
 
-use super::{builtin, metadata::validate_skill_name};
+use super::{builtin, metadata::validate_skill_name, paths::global_skills_root};
 
 pub(crate) const SKILLS_ROOT: &str = ".agents/skills";
 const RECOMMENDED_PROMPT: &str = "Use $new-rfc-skill-creation-skill to create a project skill named `new-rfc` (new RFC). Ask for my feedback and keep iterating until I confirm the skill is correct.";
@@ -33,17 +37,83 @@ pub(crate) fn run(args: SkillInitArgs) -> Result<()> {
     Ok(())
 }
 
-/// Create a new skill scaffold under `.agents/skills`.
+/// Create a new skill scaffold under `.agents/skills`, or the user-global
+/// skills directory with `--global`.
 pub(crate) fn run_new(args: SkillNewArgs) -> Result<()> {
-    fs::create_dir_all(SKILLS_ROOT).with_context(|| format!("failed to create `{SKILLS_ROOT}`"))?;
-    output::print_path(SKILLS_ROOT);
-    scaffold_skill(&args.name)
+    let skills_root = if args.global {
+        global_skills_root()?
+    } else {
+        PathBuf::from(SKILLS_ROOT)
+    };
+    fs::create_dir_all(&skills_root)
+        .with_context(|| format!("failed to create `{}`", skills_root.display()))?;
+    output::print_path(skills_root.display());
+    let name = resolve_new_skill_name(&args.name, args.normalize)?;
+    let prompt = resolve_default_prompt(&name, args.prompt.as_deref())?;
+    let mut formats = Vec::new();
+    for format in &args.agent_formats {
+        if !formats.contains(format) {
+            formats.push(*format);
+        }
+    }
+    if formats.is_empty() {
+        formats.push(SkillAgentFormat::Openai);
+    }
+    scaffold_skill(&skills_root, &name, &prompt, &formats)
+}
+
+/// File name under `agents/` for an agent config format.
+fn agent_config_file(format: SkillAgentFormat) -> &'static str {
+    match format {
+        SkillAgentFormat::Openai => "openai.yaml",
+        SkillAgentFormat::Claude => "claude.yaml",
+        SkillAgentFormat::Cursor => "cursor.yaml",
+        SkillAgentFormat::Gemini => "gemini.yaml",
+    }
+}
+
+/// Expand `{name}`/`{title}` in a custom prompt, or use the scaffold default.
+fn resolve_default_prompt(name: &str, template: Option<&str>) -> Result<String> {
+    let Some(template) = template else {
+        return Ok(format!("Use ${name} to help with this task."));
+    };
+    if template.trim().is_empty() {
+        bail!("`--prompt` cannot be empty");
+    }
+    Ok(template
+        .trim()
+        .replace("{name}", name)
+        .replace("{title}", &title_case(name)))
 }
 
-fn scaffold_skill(name: &str) -> Result<()> {
-    validate_skill_name(name)?;
+/// Accept a valid name as-is; otherwise suggest its slugified form, and use it
+/// when `normalize` is set.
+fn resolve_new_skill_name(name: &str, normalize: bool) -> Result<String> {
+    let Err(error) = validate_skill_name(name) else {
+        return Ok(name.to_owned());
+    };
 
-    let skill_dir = Path::new(SKILLS_ROOT).join(name);
+    let suggestion = slugify(name);
+    if validate_skill_name(&suggestion).is_err() {
+        return Err(error);
+    }
+    if !normalize {
+        bail!(
+            "invalid skill name `{name}`: {error}; did you mean `{suggestion}`? (pass --normalize to use it)"
+        );
+    }
+
+    output::print_log(format!("normalized skill name `{name}` to `{suggestion}`"));
+    Ok(suggestion)
+}
+
+fn scaffold_skill(
+    skills_root: &Path,
+    name: &str,
+    default_prompt: &str,
+    formats: &[SkillAgentFormat],
+) -> Result<()> {
+    let skill_dir = skills_root.join(name);
     let agents_dir = skill_dir.join("agents");
     fs::create_dir_all(&agents_dir)
         .with_context(|| format!("failed to create `{}`", agents_dir.display()))?;
@@ -59,14 +129,14 @@ fn scaffold_skill(name: &str) -> Result<()> {
         ),
     )?;
 
-    let openai_yaml = agents_dir.join("openai.yaml");
-    write_if_missing(
-        &openai_yaml,
-        &format!(
-            "interface:\n  display_name: \"{title}\"\n  short_description: \"Describe this skill briefly\"\n  default_prompt: \"Use ${name} to help with this task.\"\n",
-            title = title_case(name)
-        ),
-    )?;
+    let agent_config = format!(
+        "interface:\n  display_name: \"{title}\"\n  short_description: \"Describe this skill briefly\"\n  default_prompt: \"{prompt}\"\n",
+        title = title_case(name),
+        prompt = default_prompt.replace('\\', "\\\\").replace('"', "\\\"")
+    );
+    for format in formats {
+        write_if_missing(&agents_dir.join(agent_config_file(*format)), &agent_config)?;
+    }
 
     Ok(())
 }
@@ -117,7 +187,7 @@ fn write_if_missing(path: &Path, content: &str) -> Result<()> {
     Ok(())
 }
 
-fn title_case(name: &str) -> String {
+pub(crate) fn title_case(name: &str) -> String {
     name.split('-')
         .filter(|part| !part.is_empty())
         .map(|part| {
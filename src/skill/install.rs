@@ -3,41 +3,76 @@ use std::path::PathBuf;
 use anyhow::Result;
 use serde::Serialize;
 
-use crate::cli::{SkillInstallArgs, SkillInstallFormat};
+use crate::cli::{SkillInstallArgs, SkillInstallFormat, SkillInstallOrigin};
 use crate::output;
 
-use super::{builtin, init::SKILLS_ROOT, materialize, select};
+use super::{
+    builtin,
+    init::SKILLS_ROOT,
+    materialize::{self, PostInstallHookRun},
+    paths, pins, select,
+};
 
 pub(crate) fn run(args: SkillInstallArgs) -> Result<()> {
-    let _origin = args.origin;
-    let skills = builtin::load_skills()?;
+    let skills = match args.origin {
+        SkillInstallOrigin::Builtin => builtin::load_skills()?,
+    };
     let selected = select::select_builtin_skills(&skills, args.name.as_deref(), args.all)?;
-    let target_root = args.to.unwrap_or_else(|| PathBuf::from(SKILLS_ROOT));
+    let target_root = match args.to {
+        Some(to) => to,
+        None if args.global => paths::global_skills_root()?,
+        None => PathBuf::from(SKILLS_ROOT),
+    };
+    pins::check_pins(&selected, &target_root, args.force)?;
     let installed = materialize::materialize_skills(&selected, &target_root, args.force)?;
+    pins::record_pins(&selected, &target_root)?;
+    materialize::print_deprecation_warnings(&selected);
+    let hook_runs = if args.run_hooks {
+        materialize::run_post_install_hooks(&installed)?
+    } else {
+        Vec::new()
+    };
 
     match args.format {
         SkillInstallFormat::Text => {
-            for skill in installed {
+            for skill in &installed {
                 let line = format!("{}\t{}", skill.name, skill.path.display());
                 output::print_log(line);
             }
+            if args.print_prompts {
+                materialize::print_default_prompts(&installed)?;
+            }
+            if args.run_hooks {
+                materialize::print_post_install_hooks(&hook_runs);
+            } else {
+                materialize::print_skipped_post_install_hooks(&installed);
+            }
         }
         SkillInstallFormat::Json => {
+            let mut entries = Vec::with_capacity(installed.len());
+            for item in installed {
+                let default_prompt = if args.print_prompts {
+                    item.default_prompt()?
+                } else {
+                    None
+                };
+                let post_install_hook = hook_runs.iter().find(|run| run.name == item.name).cloned();
+                entries.push(InstalledSkillJson {
+                    name: item.name,
+                    path: item.path.to_string_lossy().into_owned(),
+                    default_prompt,
+                    post_install_hook,
+                });
+            }
             let payload = SkillInstallResponseJson {
                 schema_version: 1,
-                installed: installed
-                    .into_iter()
-                    .map(|item| InstalledSkillJson {
-                        name: item.name,
-                        path: item.path.to_string_lossy().into_owned(),
-                    })
-                    .collect(),
+                installed: entries,
             };
             println!("{}", serde_json::to_string_pretty(&payload)?);
         }
     }
 
-    Ok(())
+    materialize::ensure_post_install_hooks_succeeded(&hook_runs)
 }
 
 #[derive(Debug, Serialize)]
@@ -50,4 +85,8 @@ struct SkillInstallResponseJson {
 struct InstalledSkillJson {
     name: String,
     path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_prompt: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    post_install_hook: Option<PostInstallHookRun>,
 }
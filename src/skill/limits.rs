@@ -0,0 +1,21 @@
+//! Frontmatter length limits from the skill spec.
+//!
+//! `build.rs` includes this file directly, so it must stay dependency-free
+//! apart from `anyhow`.
+
+use anyhow::{Result, bail};
+
+/// Maximum characters in a skill `name`.
+pub(crate) const MAX_NAME_CHARS: usize = 64;
+
+/// Maximum characters in a skill `description`, after `@file` resolution.
+pub(crate) const MAX_DESCRIPTION_CHARS: usize = 1024;
+
+/// Fail when `value` has more than `max` characters, naming the field `what`.
+pub(crate) fn ensure_max_chars(what: &str, value: &str, max: usize) -> Result<()> {
+    let count = value.chars().count();
+    if count > max {
+        bail!("{what} exceeds {max} characters (got {count})");
+    }
+    Ok(())
+}
@@ -1,42 +1,90 @@
-use std::path::Path;
+use std::collections::BTreeSet;
 
 use anyhow::Result;
 use serde::Serialize;
 
-use crate::cli::{SkillListArgs, SkillListFormat};
+use crate::cli::{SkillListArgs, SkillListFormat, SkillListSort};
+use crate::output;
 
 use super::{
     builtin,
     catalog::{self, SkillDiscoveryEntry},
-    init::SKILLS_ROOT,
+    paths::{global_skills_root, skill_search_roots},
 };
 
 pub(crate) fn run(args: SkillListArgs) -> Result<()> {
     let builtin_skills = builtin::load_skills()?;
-    let workspace_skills = catalog::discover_workspace_skills(Path::new(SKILLS_ROOT))?;
-    let entries = catalog::discover_skills(args.origin, &builtin_skills, &workspace_skills);
+    let mut roots = skill_search_roots();
+    if args.global {
+        roots.push(global_skills_root()?);
+    }
+    let workspace_skills = catalog::discover_workspace_skills_in_roots(&roots)?;
+    // Built-in entries never carry a workspace path, so look names up directly.
+    let installed = workspace_skills
+        .iter()
+        .map(|skill| skill.name.as_str())
+        .collect::<BTreeSet<_>>();
+    let mut entries = catalog::discover_skills(args.origin, &builtin_skills, &workspace_skills)
+        .into_iter()
+        .filter(|entry| !args.installed_only || installed.contains(entry.name.as_str()))
+        .filter(|entry| !args.available_only || !installed.contains(entry.name.as_str()))
+        .map(|mut entry| {
+            if let Some(lang) = &args.lang {
+                entry.localize(lang);
+            }
+            entry
+        })
+        .collect::<Vec<_>>();
+    if args.sort == SkillListSort::Manifest {
+        // Stable sort: workspace-only skills stay alphabetical after the built-ins.
+        entries.sort_by_key(|entry| (entry.order.is_none(), entry.order));
+    }
 
     match args.format {
         SkillListFormat::Text => print_text(&entries),
+        SkillListFormat::Table => print_table(&entries),
         SkillListFormat::Json => print_json(&entries)?,
+        SkillListFormat::Ndjson => print_ndjson(&entries)?,
     }
     Ok(())
 }
 
+const TEXT_HEADER: [&str; 5] = [
+    "name",
+    "preferred_origin",
+    "builtin_available",
+    "workspace_path",
+    "description",
+];
+
 fn print_text(entries: &[SkillDiscoveryEntry]) {
-    println!("name\tpreferred_origin\tbuiltin_available\tworkspace_path\tdescription");
+    println!("{}", TEXT_HEADER.join("\t"));
     for entry in entries {
-        println!(
-            "{}\t{}\t{}\t{}\t{}",
-            entry.name,
-            origin_to_text(&entry.preferred_origin),
-            entry.builtin_available,
-            entry.workspace_path.as_deref().unwrap_or("-"),
-            entry.description
-        );
+        println!("{}", text_row(entry).join("\t"));
     }
 }
 
+/// Same columns as `text`, padded to align in a terminal.
+fn print_table(entries: &[SkillDiscoveryEntry]) {
+    let rows = entries.iter().map(text_row).collect::<Vec<_>>();
+    output::print_table(&TEXT_HEADER, &rows);
+}
+
+fn text_row(entry: &SkillDiscoveryEntry) -> Vec<String> {
+    let marker = if entry.deprecated {
+        "[deprecated] "
+    } else {
+        ""
+    };
+    vec![
+        entry.name.clone(),
+        entry.preferred_origin.as_str().to_owned(),
+        entry.builtin_available.to_string(),
+        entry.workspace_path.as_deref().unwrap_or("-").to_owned(),
+        format!("{marker}{}", entry.description),
+    ]
+}
+
 fn print_json(entries: &[SkillDiscoveryEntry]) -> Result<()> {
     let payload = SkillListResponseJson {
         schema_version: 1,
@@ -46,11 +94,23 @@ fn print_json(entries: &[SkillDiscoveryEntry]) -> Result<()> {
     Ok(())
 }
 
-fn origin_to_text(origin: &catalog::PreferredOrigin) -> &'static str {
-    match origin {
-        catalog::PreferredOrigin::Builtin => "builtin",
-        catalog::PreferredOrigin::Workspace => "workspace",
+/// One compact JSON object per skill, then a `{schema_version, total}` summary.
+fn print_ndjson(entries: &[SkillDiscoveryEntry]) -> Result<()> {
+    for entry in entries {
+        println!("{}", serde_json::to_string(entry)?);
     }
+    let summary = SkillListSummaryJson {
+        schema_version: 1,
+        total: entries.len(),
+    };
+    println!("{}", serde_json::to_string(&summary)?);
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct SkillListSummaryJson {
+    schema_version: u32,
+    total: usize,
 }
 
 #[derive(Debug, Serialize)]
@@ -1,11 +1,18 @@
 use std::{
     fs,
     path::{Component, Path, PathBuf},
+    process::Command,
 };
 
 use anyhow::{Context, Result, bail};
+use serde::Serialize;
 
-use super::builtin::BuiltinSkill;
+use crate::output;
+
+use super::{
+    builtin::{BuiltinSkill, BuiltinSkillFile},
+    metadata::read_default_prompt,
+};
 
 #[derive(Debug, Clone)]
 pub(crate) struct MaterializedSkill {
@@ -13,6 +20,112 @@ pub(crate) struct MaterializedSkill {
     pub(crate) path: PathBuf,
 }
 
+/// Skill-relative path of the optional script run by `--run-hooks`.
+pub(crate) const POST_INSTALL_HOOK: &str = "hooks/post-install.sh";
+
+impl MaterializedSkill {
+    /// Default prompt from the materialized `agents/openai.yaml`, if any.
+    pub(crate) fn default_prompt(&self) -> Result<Option<String>> {
+        read_default_prompt(&self.path)
+    }
+
+    fn has_post_install_hook(&self) -> bool {
+        self.path.join(POST_INSTALL_HOOK).is_file()
+    }
+}
+
+/// Captured result of running one skill's post-install hook.
+#[derive(Debug, Clone, Serialize)]
+pub(crate) struct PostInstallHookRun {
+    #[serde(skip)]
+    pub(crate) name: String,
+    pub(crate) exit_code: Option<i32>,
+    pub(crate) stdout: String,
+    pub(crate) stderr: String,
+}
+
+impl PostInstallHookRun {
+    fn succeeded(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+/// Run `hooks/post-install.sh` with `sh` inside each skill directory that has
+/// one, capturing output and exit status.
+pub(crate) fn run_post_install_hooks(
+    skills: &[MaterializedSkill],
+) -> Result<Vec<PostInstallHookRun>> {
+    let mut runs = Vec::new();
+    for skill in skills.iter().filter(|skill| skill.has_post_install_hook()) {
+        let output = Command::new("sh")
+            .arg(POST_INSTALL_HOOK)
+            .current_dir(&skill.path)
+            .output()
+            .with_context(|| {
+                format!("failed to run post-install hook for skill `{}`", skill.name)
+            })?;
+        runs.push(PostInstallHookRun {
+            name: skill.name.clone(),
+            exit_code: output.status.code(),
+            stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+        });
+    }
+    Ok(runs)
+}
+
+/// Log each hook's exit status and quote its output.
+pub(crate) fn print_post_install_hooks(runs: &[PostInstallHookRun]) {
+    for run in runs {
+        let status = run
+            .exit_code
+            .map_or_else(|| "a signal".to_owned(), |code| format!("code {code}"));
+        output::print_log(format!(
+            "post-install hook for `{}` exited with {status}",
+            run.name
+        ));
+        for line in run.stdout.lines().chain(run.stderr.lines()) {
+            output::print_quote(line);
+        }
+    }
+}
+
+/// Fail when any hook exited unsuccessfully.
+pub(crate) fn ensure_post_install_hooks_succeeded(runs: &[PostInstallHookRun]) -> Result<()> {
+    let failed = runs
+        .iter()
+        .filter(|run| !run.succeeded())
+        .map(|run| format!("`{}`", run.name))
+        .collect::<Vec<_>>();
+    if failed.is_empty() {
+        return Ok(());
+    }
+    bail!(
+        "post-install hook failed for skill(s) {}",
+        failed.join(", ")
+    )
+}
+
+/// Hint about hooks that were skipped because `--run-hooks` was not passed.
+pub(crate) fn print_skipped_post_install_hooks(skills: &[MaterializedSkill]) {
+    for skill in skills.iter().filter(|skill| skill.has_post_install_hook()) {
+        output::print_hint(format!(
+            "skill `{}` has `{POST_INSTALL_HOOK}`; pass --run-hooks to run it",
+            skill.name
+        ));
+    }
+}
+
+/// Print each skill's default prompt as a hint, skipping skills without one.
+pub(crate) fn print_default_prompts(skills: &[MaterializedSkill]) -> Result<()> {
+    for skill in skills {
+        if let Some(prompt) = skill.default_prompt()? {
+            output::print_hint(format!("{}: {prompt}", skill.name));
+        }
+    }
+    Ok(())
+}
+
 pub(crate) fn materialize_skills(
     skills: &[BuiltinSkill],
     target_root: &Path,
@@ -36,6 +149,7 @@ pub(crate) fn materialize_skills(
             }
             fs::write(&file_path, &file.content)
                 .with_context(|| format!("failed to write `{}`", file_path.display()))?;
+            apply_file_mode(&file_path, file)?;
         }
 
         materialized.push(MaterializedSkill {
@@ -47,6 +161,29 @@ pub(crate) fn materialize_skills(
     Ok(materialized)
 }
 
+/// Warn about each deprecated skill being materialized, naming its
+/// replacement when declared.
+pub(crate) fn print_deprecation_warnings(skills: &[BuiltinSkill]) {
+    for skill in skills {
+        if let Some(deprecation) = &skill.deprecation {
+            output::print_warning(deprecation.warning(&skill.name));
+        }
+    }
+}
+
+#[cfg(unix)]
+fn apply_file_mode(path: &Path, file: &BuiltinSkillFile) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+
+    fs::set_permissions(path, fs::Permissions::from_mode(file.mode()))
+        .with_context(|| format!("failed to set permissions on `{}`", path.display()))
+}
+
+#[cfg(not(unix))]
+fn apply_file_mode(_path: &Path, _file: &BuiltinSkillFile) -> Result<()> {
+    Ok(())
+}
+
 fn preflight_materialize(skills: &[BuiltinSkill], target_root: &Path, force: bool) -> Result<()> {
     let mut conflicts = Vec::new();
 
@@ -96,3 +233,98 @@ fn resolve_skill_file_destination(skill_dir: &Path, relative_path: &str) -> Resu
     }
     Ok(skill_dir.join(relative))
 }
+
+#[cfg(all(test, unix))]
+mod tests {
+    use std::{fs, os::unix::fs::PermissionsExt};
+
+    use super::{materialize_skills, run_post_install_hooks};
+    use crate::skill::builtin::{BuiltinSkill, BuiltinSkillFile};
+
+    fn temp_target(label: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!(
+            "agx-materialize-{label}-{}-{}",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+        ))
+    }
+
+    #[test]
+    fn materialize_preserves_executable_files() {
+        let target = temp_target("mode");
+        let skill = BuiltinSkill {
+            name: "scripted".to_owned(),
+            order: 0,
+            description: "Runs a script.".to_owned(),
+            version: None,
+            capabilities: Vec::new(),
+            tags: Vec::new(),
+            localized_descriptions: Default::default(),
+            deprecation: None,
+            files: vec![
+                BuiltinSkillFile {
+                    path: "SKILL.md".to_owned(),
+                    content: "---\nname: scripted\ndescription: Runs a script.\n---\n".to_owned(),
+                    executable: false,
+                },
+                BuiltinSkillFile {
+                    path: "scripts/run.sh".to_owned(),
+                    content: "#!/bin/sh\necho ok\n".to_owned(),
+                    executable: true,
+                },
+            ],
+        };
+
+        materialize_skills(&[skill], &target, false).expect("materialize should succeed");
+
+        let mode = |relative: &str| {
+            fs::metadata(target.join("scripted").join(relative))
+                .expect("materialized file should exist")
+                .permissions()
+                .mode()
+                & 0o777
+        };
+        assert_eq!(mode("scripts/run.sh"), 0o755);
+        assert_eq!(mode("SKILL.md"), 0o644);
+        fs::remove_dir_all(&target).expect("failed to clean up materialized skills");
+    }
+
+    #[test]
+    fn post_install_hook_runs_only_when_requested() {
+        let target = temp_target("hook");
+        let skill = BuiltinSkill {
+            name: "hooked".to_owned(),
+            order: 0,
+            description: "Has a hook.".to_owned(),
+            version: None,
+            capabilities: Vec::new(),
+            tags: Vec::new(),
+            localized_descriptions: Default::default(),
+            deprecation: None,
+            files: vec![
+                BuiltinSkillFile {
+                    path: "SKILL.md".to_owned(),
+                    content: "---\nname: hooked\ndescription: Has a hook.\n---\n".to_owned(),
+                    executable: false,
+                },
+                BuiltinSkillFile {
+                    path: "hooks/post-install.sh".to_owned(),
+                    content: "touch hook-ran\necho done\n".to_owned(),
+                    executable: false,
+                },
+            ],
+        };
+
+        let materialized =
+            materialize_skills(&[skill], &target, false).expect("materialize should succeed");
+        let marker = target.join("hooked/hook-ran");
+        assert!(!marker.exists(), "materializing must not run hooks");
+
+        let runs = run_post_install_hooks(&materialized).expect("hook should run");
+        assert!(marker.exists());
+        assert_eq!(runs.len(), 1);
+        assert_eq!(runs[0].exit_code, Some(0));
+        assert_eq!(runs[0].stdout, "done\n");
+        fs::remove_dir_all(&target).expect("failed to clean up materialized skills");
+    }
+}
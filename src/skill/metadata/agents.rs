@@ -0,0 +1,117 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result, bail};
+use serde_yaml::Value;
+
+use crate::skill::agent_config::{KNOWN_AGENT_CONFIGS, ensure_agent_config_valid};
+
+/// Fail when `agents/` holds entries other than [`KNOWN_AGENT_CONFIGS`].
+pub(crate) fn ensure_only_known_agent_configs(skill_path: &Path) -> Result<()> {
+    let agents_dir = skill_path.join("agents");
+    if !agents_dir.is_dir() {
+        return Ok(());
+    }
+
+    let mut unexpected = Vec::new();
+    for entry in fs::read_dir(&agents_dir)
+        .with_context(|| format!("failed to read `{}`", agents_dir.display()))?
+    {
+        let file_name = entry?.file_name().to_string_lossy().into_owned();
+        if !KNOWN_AGENT_CONFIGS.contains(&file_name.as_str()) {
+            unexpected.push(format!("`{file_name}`"));
+        }
+    }
+    if unexpected.is_empty() {
+        return Ok(());
+    }
+
+    unexpected.sort();
+    bail!(
+        "unexpected file(s) in `agents/`: {}; expected only {}",
+        unexpected.join(", "),
+        KNOWN_AGENT_CONFIGS
+            .iter()
+            .map(|name| format!("`{name}`"))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+/// Check that every [`KNOWN_AGENT_CONFIGS`] file present under `agents/`
+/// declares the `interface` mapping agents read the skill's display name and
+/// prompt from.
+pub(crate) fn ensure_agent_configs_valid(skill_path: &Path) -> Result<()> {
+    for config in KNOWN_AGENT_CONFIGS {
+        let config_path = skill_path.join("agents").join(config);
+        if !config_path.exists() {
+            continue;
+        }
+        let config_text = fs::read_to_string(&config_path)
+            .with_context(|| format!("failed to read `{}`", config_path.display()))?;
+        ensure_agent_config_valid(&config_text)
+            .with_context(|| format!("invalid agent config `{}`", config_path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Read `interface.default_prompt` from the first agent config present, in
+/// [`KNOWN_AGENT_CONFIGS`] order.
+pub(crate) fn read_default_prompt(skill_path: &Path) -> Result<Option<String>> {
+    let Some(config_path) = KNOWN_AGENT_CONFIGS
+        .iter()
+        .map(|config| skill_path.join("agents").join(config))
+        .find(|path| path.is_file())
+    else {
+        return Ok(None);
+    };
+    let source = fs::read_to_string(&config_path)
+        .with_context(|| format!("failed to read `{}`", config_path.display()))?;
+    default_prompt_from_config(&source)
+        .with_context(|| format!("failed to parse `{}`", config_path.display()))
+}
+
+/// `interface.default_prompt` from agent config YAML; blank prompts are `None`.
+fn default_prompt_from_config(source: &str) -> Result<Option<String>> {
+    let config = serde_yaml::from_str::<Value>(source).context("invalid agent config YAML")?;
+    let prompt = match config
+        .get("interface")
+        .and_then(|interface| interface.get("default_prompt"))
+    {
+        None | Some(Value::Null) => return Ok(None),
+        Some(Value::String(prompt)) => prompt.trim(),
+        Some(_) => bail!("`interface.default_prompt` must be a string"),
+    };
+    Ok((!prompt.is_empty()).then(|| prompt.to_owned()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::default_prompt_from_config;
+
+    #[test]
+    fn default_prompt_reads_quoted_and_block_scalars() {
+        let quoted = "# interface: ignored\ninterface:\n  display_name: \"Quoted\"\n  default_prompt: \"Use $quoted to say \\\"hi\\\"\\n\" # trailing\n";
+        assert_eq!(
+            default_prompt_from_config(quoted).expect("config should parse"),
+            Some("Use $quoted to say \"hi\"".to_owned())
+        );
+
+        let block = "interface:\n    default_prompt: |\n      Use $block\n      across lines.\n";
+        assert_eq!(
+            default_prompt_from_config(block).expect("config should parse"),
+            Some("Use $block\nacross lines.".to_owned())
+        );
+
+        let flow = "interface: {default_prompt: 'It''s $flow'}\n";
+        assert_eq!(
+            default_prompt_from_config(flow).expect("config should parse"),
+            Some("It's $flow".to_owned())
+        );
+        assert_eq!(
+            default_prompt_from_config("interface:\n  display_name: x\n")
+                .expect("config should parse"),
+            None
+        );
+    }
+}
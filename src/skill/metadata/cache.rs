@@ -0,0 +1,138 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{LazyLock, Mutex, PoisonError},
+    time::SystemTime,
+};
+
+use anyhow::Result;
+
+use super::{SkillMetadata, parse_skill_metadata};
+
+/// Parsed metadata keyed by skill directory and key-check mode, so commands
+/// that read the same skill more than once (for example listing and then
+/// validating) parse each `SKILL.md` only once per process.
+static METADATA_CACHE: LazyLock<Mutex<HashMap<(PathBuf, bool), CachedMetadata>>> =
+    LazyLock::new(Mutex::default);
+
+#[cfg(test)]
+thread_local! {
+    /// Uncached metadata parses on this thread, for cache tests.
+    static METADATA_PARSES: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+}
+
+struct CachedMetadata {
+    /// Files the metadata was read from: `SKILL.md` plus `@` description files.
+    sources: Vec<FileStamp>,
+    metadata: SkillMetadata,
+}
+
+/// Modification time and size of a source file; a change to either
+/// invalidates the cached metadata.
+#[derive(PartialEq, Eq)]
+struct FileStamp {
+    path: PathBuf,
+    modified: SystemTime,
+    len: u64,
+}
+
+impl FileStamp {
+    fn read(path: &Path) -> Option<Self> {
+        let metadata = fs::metadata(path).ok()?;
+        Some(Self {
+            path: path.to_path_buf(),
+            modified: metadata.modified().ok()?,
+            len: metadata.len(),
+        })
+    }
+
+    fn is_current(&self) -> bool {
+        Self::read(&self.path).as_ref() == Some(self)
+    }
+}
+
+/// Read skill metadata, optionally skipping the built-in allowed-keys check
+/// (for example when a JSON Schema governs the frontmatter instead).
+///
+/// Results are cached for the rest of the process and reused while the
+/// modification time and size of every source file are unchanged.
+pub(crate) fn read_skill_metadata_with(
+    skill_path: &Path,
+    check_keys: bool,
+) -> Result<SkillMetadata> {
+    let key = (skill_path.to_path_buf(), check_keys);
+    {
+        let cache = METADATA_CACHE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(cached) = cache.get(&key)
+            && cached.sources.iter().all(FileStamp::is_current)
+        {
+            return Ok(cached.metadata.clone());
+        }
+    }
+
+    let skill_md_path = skill_path.join("SKILL.md");
+    let before = FileStamp::read(&skill_md_path);
+    let mut description_files = Vec::new();
+    let metadata = parse_skill_metadata(skill_path, check_keys, &mut description_files)?;
+    #[cfg(test)]
+    METADATA_PARSES.with(|parses| parses.set(parses.get() + 1));
+
+    // Only cache when `SKILL.md` did not change while it was being parsed.
+    let after = FileStamp::read(&skill_md_path);
+    let sources = std::iter::once(after.filter(|after| before.as_ref() == Some(after)))
+        .chain(description_files.iter().map(|path| FileStamp::read(path)))
+        .collect::<Option<Vec<_>>>();
+    if let Some(sources) = sources {
+        METADATA_CACHE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(
+                key,
+                CachedMetadata {
+                    sources,
+                    metadata: metadata.clone(),
+                },
+            );
+    }
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::METADATA_PARSES;
+    use crate::skill::metadata::read_skill_metadata;
+
+    #[test]
+    fn repeated_reads_hit_cache_until_skill_md_changes() {
+        let skill_dir =
+            std::env::temp_dir().join(format!("agx-metadata-cache-{}", std::process::id()));
+        fs::create_dir_all(&skill_dir).expect("failed to create skill dir");
+        let skill_md = skill_dir.join("SKILL.md");
+        fs::write(&skill_md, "---\nname: cached\ndescription: First.\n---\n")
+            .expect("failed to write SKILL.md");
+        let parses = || METADATA_PARSES.with(|parses| parses.get());
+
+        let start = parses();
+        let first = read_skill_metadata(&skill_dir).expect("metadata should parse");
+        let second = read_skill_metadata(&skill_dir).expect("metadata should parse");
+        assert_eq!(first.description, "First.");
+        assert_eq!(second.description, "First.");
+        assert_eq!(parses() - start, 1);
+
+        fs::write(
+            &skill_md,
+            "---\nname: cached\ndescription: Second one.\n---\n",
+        )
+        .expect("failed to rewrite SKILL.md");
+        let changed = read_skill_metadata(&skill_dir).expect("metadata should parse");
+        assert_eq!(changed.description, "Second one.");
+        assert_eq!(parses() - start, 2);
+
+        fs::remove_dir_all(skill_dir).expect("failed to clean skill dir");
+    }
+}
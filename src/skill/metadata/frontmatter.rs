@@ -0,0 +1,248 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use serde_yaml::{Mapping, Value};
+
+use super::{SkillDeprecation, validate_skill_name};
+
+const ALLOWED_FRONTMATTER_KEYS: [&str; 11] = [
+    "name",
+    "description",
+    "requires",
+    "version",
+    "license",
+    "allowed-tools",
+    "capabilities",
+    "tags",
+    "deprecated",
+    "deprecation_note",
+    "replacement",
+];
+
+/// Prefix of localized description keys such as `description.fr`.
+pub(super) const LOCALIZED_DESCRIPTION_PREFIX: &str = "description.";
+
+/// Parsed `SKILL.md` frontmatter as a JSON value, for JSON Schema checks.
+pub(crate) fn read_frontmatter_json(skill_path: &Path) -> Result<serde_json::Value> {
+    let source = read_skill_source(skill_path)?;
+    let metadata = parse_frontmatter_map(extract_frontmatter(&source)?)?;
+    serde_json::to_value(Value::Mapping(metadata))
+        .context("SKILL.md frontmatter cannot be represented as JSON")
+}
+
+/// Read `SKILL.md` with CRLF line endings normalized to LF.
+pub(super) fn read_skill_source(skill_path: &Path) -> Result<String> {
+    let skill_md_path = skill_path.join("SKILL.md");
+    let source = fs::read_to_string(&skill_md_path)
+        .with_context(|| format!("failed to read `{}`", skill_md_path.display()))?;
+    Ok(source.replace("\r\n", "\n"))
+}
+
+/// Resolve a `@<file>` description reference to the trimmed contents of that
+/// file, relative to the skill directory. Other values pass through unchanged.
+/// Referenced files are appended to `description_files`.
+pub(super) fn resolve_description(
+    skill_path: &Path,
+    description: String,
+    description_files: &mut Vec<PathBuf>,
+) -> Result<String> {
+    let Some(relative) = description.strip_prefix('@') else {
+        return Ok(description);
+    };
+    let relative_path = Path::new(relative);
+    if relative.is_empty()
+        || !relative_path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+    {
+        bail!("frontmatter `description` reference `{description}` must be a relative file path");
+    }
+
+    let path = skill_path.join(relative_path);
+    let text = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "failed to read description file `{}` referenced by frontmatter",
+            path.display()
+        )
+    })?;
+    description_files.push(path);
+    Ok(text.trim().to_owned())
+}
+
+/// Read the markdown body of `SKILL.md` following the frontmatter block.
+pub(crate) fn read_skill_body(skill_path: &Path) -> Result<String> {
+    let source = read_skill_source(skill_path)?;
+    let frontmatter = extract_frontmatter(&source)?;
+    let body_start = 4 + frontmatter.len() + "\n---".len();
+    Ok(source[body_start..].to_owned())
+}
+
+pub(super) fn extract_frontmatter(source: &str) -> Result<&str> {
+    if !source.starts_with("---\n") {
+        bail!("SKILL.md must start with YAML frontmatter marker `---`");
+    }
+
+    let rest = &source[4..];
+    if let Some(end) = rest.find("\n---\n") {
+        return Ok(&rest[..end]);
+    }
+    if let Some(end) = rest.find("\n---") {
+        return Ok(&rest[..end]);
+    }
+
+    bail!("SKILL.md is missing closing YAML frontmatter marker `---`")
+}
+
+pub(super) fn parse_frontmatter_map(frontmatter: &str) -> Result<Mapping> {
+    let value = serde_yaml::from_str::<Value>(frontmatter)
+        .context("failed to parse SKILL.md frontmatter as YAML")?;
+    match value {
+        Value::Mapping(map) => Ok(map),
+        Value::Null => Ok(Mapping::new()),
+        _ => bail!("SKILL.md frontmatter must be a YAML mapping"),
+    }
+}
+
+pub(super) fn validate_frontmatter_keys(metadata: &Mapping) -> Result<()> {
+    for key in metadata.keys() {
+        let Some(key) = key.as_str() else {
+            bail!("frontmatter keys must be strings");
+        };
+        if ALLOWED_FRONTMATTER_KEYS.contains(&key) || is_localized_description_key(key) {
+            continue;
+        }
+        bail!(
+            "unexpected frontmatter key `{key}`; allowed keys are `description.<lang>` and {}",
+            ALLOWED_FRONTMATTER_KEYS
+                .iter()
+                .map(|key| format!("`{key}`"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        );
+    }
+    Ok(())
+}
+
+/// `description.<lang>` with a language tag of ASCII letters, digits, and `-`.
+fn is_localized_description_key(key: &str) -> bool {
+    key.strip_prefix(LOCALIZED_DESCRIPTION_PREFIX)
+        .is_some_and(|lang| {
+            !lang.is_empty()
+                && lang
+                    .chars()
+                    .all(|ch| ch.is_ascii_alphanumeric() || ch == '-')
+        })
+}
+
+/// Read an optional scalar frontmatter value as a string; null counts as absent.
+pub(super) fn frontmatter_string(metadata: &Mapping, key: &str) -> Result<Option<String>> {
+    match metadata.get(key) {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(value)) => Ok(Some(value.trim().to_owned())),
+        Some(_) => bail!("frontmatter `{key}` must be a string"),
+    }
+}
+
+/// Read `deprecated`, `deprecation_note`, and `replacement`; the hints are only
+/// allowed alongside `deprecated: true`.
+pub(super) fn parse_deprecation(metadata: &Mapping) -> Result<Option<SkillDeprecation>> {
+    let deprecated = match metadata.get("deprecated") {
+        None | Some(Value::Null) => false,
+        Some(Value::Bool(deprecated)) => *deprecated,
+        Some(_) => bail!("frontmatter `deprecated` must be a boolean"),
+    };
+    let deprecation = SkillDeprecation {
+        note: frontmatter_string(metadata, "deprecation_note")?.filter(|note| !note.is_empty()),
+        replacement: frontmatter_string(metadata, "replacement")?
+            .filter(|replacement| !replacement.is_empty()),
+    };
+    if let Some(replacement) = &deprecation.replacement {
+        validate_skill_name(replacement)
+            .with_context(|| format!("invalid frontmatter `replacement` `{replacement}`"))?;
+    }
+    if !deprecated {
+        if deprecation != SkillDeprecation::default() {
+            bail!("frontmatter `deprecation_note` and `replacement` require `deprecated: true`");
+        }
+        return Ok(None);
+    }
+    Ok(Some(deprecation))
+}
+
+/// Read an optional frontmatter list of strings; absent means empty.
+pub(super) fn frontmatter_string_list(metadata: &Mapping, key: &str) -> Result<Vec<String>> {
+    let Some(value) = metadata.get(key) else {
+        return Ok(Vec::new());
+    };
+    let Some(entries) = value.as_sequence() else {
+        bail!("frontmatter `{key}` must be a list of strings");
+    };
+
+    entries
+        .iter()
+        .map(|entry| {
+            entry
+                .as_str()
+                .map(|value| value.trim().to_owned())
+                .ok_or_else(|| anyhow::anyhow!("frontmatter `{key}` must be a list of strings"))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        frontmatter_string_list, parse_deprecation, parse_frontmatter_map,
+        validate_frontmatter_keys,
+    };
+
+    #[test]
+    fn requires_accepts_block_and_flow_lists() {
+        let block = parse_frontmatter_map("name: a\nrequires:\n  - git\n  - cargo\n")
+            .expect("frontmatter should parse");
+        assert_eq!(
+            frontmatter_string_list(&block, "requires").expect("requires should parse"),
+            vec!["git", "cargo"]
+        );
+
+        let flow =
+            parse_frontmatter_map("name: a\nrequires: [git]\n").expect("frontmatter should parse");
+        assert_eq!(
+            frontmatter_string_list(&flow, "requires").expect("requires should parse"),
+            vec!["git"]
+        );
+    }
+
+    #[test]
+    fn deprecation_hints_build_install_warning() {
+        let map = parse_frontmatter_map(
+            "name: old\ndeprecated: true\ndeprecation_note: Merged upstream.\nreplacement: new-skill\n",
+        )
+        .expect("frontmatter should parse");
+        let deprecation = parse_deprecation(&map)
+            .expect("deprecation should parse")
+            .expect("skill should be deprecated");
+        assert_eq!(
+            deprecation.warning("old"),
+            "skill `old` is deprecated; use `new-skill` instead: Merged upstream."
+        );
+
+        let orphan =
+            parse_frontmatter_map("name: a\nreplacement: b\n").expect("frontmatter should parse");
+        assert!(parse_deprecation(&orphan).is_err());
+    }
+
+    #[test]
+    fn unknown_keys_are_rejected() {
+        let map = parse_frontmatter_map("name: a\nextra: b\n").expect("frontmatter should parse");
+        let error = validate_frontmatter_keys(&map).expect_err("expected error");
+        assert!(
+            error
+                .to_string()
+                .contains("unexpected frontmatter key `extra`")
+        );
+    }
+}
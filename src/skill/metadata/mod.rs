@@ -0,0 +1,228 @@
+mod agents;
+mod cache;
+mod frontmatter;
+
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Result, bail};
+use serde::Serialize;
+
+use super::{
+    limits::{MAX_DESCRIPTION_CHARS, MAX_NAME_CHARS, ensure_max_chars},
+    version::SkillVersion,
+};
+
+pub(crate) use agents::{
+    ensure_agent_configs_valid, ensure_only_known_agent_configs, read_default_prompt,
+};
+pub(crate) use cache::read_skill_metadata_with;
+pub(crate) use frontmatter::{read_frontmatter_json, read_skill_body};
+
+#[derive(Debug, Clone)]
+pub(crate) struct SkillMetadata {
+    pub(crate) name: String,
+    pub(crate) description: String,
+    /// Tools or binaries the skill expects on `PATH`.
+    pub(crate) requires: Vec<String>,
+    /// Semver `version`, when the skill declares one.
+    pub(crate) version: Option<SkillVersion>,
+    /// Declared capabilities, surfaced to agents through `skill list`.
+    pub(crate) capabilities: Vec<String>,
+    /// Free-form labels used to filter `skill export --tag`.
+    pub(crate) tags: Vec<String>,
+    /// Localized descriptions keyed by language tag (from `description.<lang>`).
+    pub(crate) localized_descriptions: BTreeMap<String, String>,
+    /// Set when the skill declares `deprecated: true`.
+    pub(crate) deprecation: Option<SkillDeprecation>,
+}
+
+/// Migration hints for a skill marked `deprecated: true`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub(crate) struct SkillDeprecation {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) note: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub(crate) replacement: Option<String>,
+}
+
+impl SkillDeprecation {
+    /// Warning shown when a deprecated skill named `name` is materialized.
+    pub(crate) fn warning(&self, name: &str) -> String {
+        let mut message = format!("skill `{name}` is deprecated");
+        if let Some(replacement) = &self.replacement {
+            message.push_str(&format!("; use `{replacement}` instead"));
+        }
+        if let Some(note) = &self.note {
+            message.push_str(&format!(": {note}"));
+        }
+        message
+    }
+}
+
+pub(crate) fn read_skill_metadata(skill_path: &Path) -> Result<SkillMetadata> {
+    read_skill_metadata_with(skill_path, true)
+}
+
+/// Parse skill metadata without the cache, recording `@` description files
+/// read along the way in `description_files`.
+fn parse_skill_metadata(
+    skill_path: &Path,
+    check_keys: bool,
+    description_files: &mut Vec<PathBuf>,
+) -> Result<SkillMetadata> {
+    let source = frontmatter::read_skill_source(skill_path)?;
+    let metadata = frontmatter::parse_frontmatter_map(frontmatter::extract_frontmatter(&source)?)?;
+
+    if check_keys {
+        frontmatter::validate_frontmatter_keys(&metadata)?;
+    }
+
+    let name = frontmatter::frontmatter_string(&metadata, "name")?
+        .ok_or_else(|| anyhow::anyhow!("missing required `name` in frontmatter"))?;
+    validate_skill_name(&name)?;
+
+    let description = frontmatter::frontmatter_string(&metadata, "description")?
+        .ok_or_else(|| anyhow::anyhow!("missing required `description` in frontmatter"))?;
+    let description = frontmatter::resolve_description(skill_path, description, description_files)?;
+    if description.trim().is_empty() {
+        bail!("frontmatter `description` cannot be empty");
+    }
+    ensure_max_chars("description", &description, MAX_DESCRIPTION_CHARS)?;
+
+    let version = frontmatter::frontmatter_string(&metadata, "version")?
+        .map(|version| SkillVersion::parse(&version))
+        .transpose()?;
+
+    let capabilities = frontmatter::frontmatter_string_list(&metadata, "capabilities")?;
+    if capabilities.iter().any(String::is_empty) {
+        bail!("frontmatter `capabilities` must contain non-empty strings");
+    }
+    let tags = frontmatter::frontmatter_string_list(&metadata, "tags")?;
+    if tags.iter().any(String::is_empty) {
+        bail!("frontmatter `tags` must contain non-empty strings");
+    }
+    if frontmatter::frontmatter_string_list(&metadata, "allowed-tools")?
+        .iter()
+        .any(String::is_empty)
+    {
+        bail!("frontmatter `allowed-tools` must contain non-empty strings");
+    }
+    frontmatter::frontmatter_string(&metadata, "license")?;
+
+    let mut localized_descriptions = BTreeMap::new();
+    for (key, value) in &metadata {
+        let Some(lang) = key
+            .as_str()
+            .and_then(|key| key.strip_prefix(frontmatter::LOCALIZED_DESCRIPTION_PREFIX))
+        else {
+            continue;
+        };
+        let Some(value) = value.as_str() else {
+            bail!("frontmatter `description.{lang}` must be a string");
+        };
+        let localized = frontmatter::resolve_description(
+            skill_path,
+            value.trim().to_owned(),
+            description_files,
+        )?;
+        if localized.is_empty() {
+            bail!("frontmatter `description.{lang}` cannot be empty");
+        }
+        localized_descriptions.insert(lang.to_owned(), localized);
+    }
+
+    Ok(SkillMetadata {
+        name,
+        description,
+        requires: frontmatter::frontmatter_string_list(&metadata, "requires")?,
+        version,
+        capabilities,
+        tags,
+        localized_descriptions,
+        deprecation: frontmatter::parse_deprecation(&metadata)?,
+    })
+}
+
+pub(crate) fn validate_skill_name(name: &str) -> Result<()> {
+    if name.is_empty() {
+        bail!("skill name cannot be empty");
+    }
+    ensure_max_chars("skill name", name, MAX_NAME_CHARS)?;
+    if name.starts_with('-') || name.ends_with('-') || name.contains("--") {
+        bail!("skill name must not start/end with `-` or contain consecutive `-`");
+    }
+    if !name
+        .chars()
+        .all(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || ch == '-')
+    {
+        bail!("skill name must contain only lowercase letters, digits, and `-`");
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{read_skill_metadata, validate_skill_name};
+
+    #[test]
+    fn spec_optional_keys_are_allowed_and_checked() {
+        let skill_dir =
+            std::env::temp_dir().join(format!("agx-metadata-spec-keys-{}", std::process::id()));
+        fs::create_dir_all(&skill_dir).expect("failed to create skill dir");
+        let skill_md = skill_dir.join("SKILL.md");
+        fs::write(
+            &skill_md,
+            "---\nname: spec\ndescription: Spec keys.\nlicense: MIT\nallowed-tools: [Read, Bash]\nversion: 1.2.0\n---\n",
+        )
+        .expect("failed to write SKILL.md");
+        read_skill_metadata(&skill_dir).expect("spec keys should be allowed");
+
+        fs::write(
+            &skill_md,
+            "---\nname: spec\ndescription: Spec keys.\nallowed-tools: Read\n---\n",
+        )
+        .expect("failed to rewrite SKILL.md");
+        let error = read_skill_metadata(&skill_dir).expect_err("expected error");
+        assert!(error.to_string().contains("`allowed-tools` must be a list"));
+
+        fs::remove_dir_all(skill_dir).expect("failed to clean skill dir");
+    }
+
+    #[test]
+    fn name_and_description_lengths_are_capped_at_spec_limits() {
+        assert!(validate_skill_name(&"a".repeat(64)).is_ok());
+        let error = validate_skill_name(&"a".repeat(65)).expect_err("expected error");
+        assert_eq!(
+            error.to_string(),
+            "skill name exceeds 64 characters (got 65)"
+        );
+
+        let skill_dir =
+            std::env::temp_dir().join(format!("agx-metadata-lengths-{}", std::process::id()));
+        fs::create_dir_all(&skill_dir).expect("failed to create skill dir");
+        let skill_md = skill_dir.join("SKILL.md");
+        let write_description = |description: &str| {
+            fs::write(
+                &skill_md,
+                format!("---\nname: lengths\ndescription: {description}\n---\n"),
+            )
+            .expect("failed to write SKILL.md");
+        };
+
+        write_description(&"é".repeat(1024));
+        read_skill_metadata(&skill_dir).expect("1024 characters should be allowed");
+        write_description(&"d".repeat(1025));
+        let error = read_skill_metadata(&skill_dir).expect_err("expected error");
+        assert_eq!(
+            error.to_string(),
+            "description exceeds 1024 characters (got 1025)"
+        );
+
+        fs::remove_dir_all(skill_dir).expect("failed to clean skill dir");
+    }
+}
@@ -1,12 +1,23 @@
+pub(crate) mod agent_config;
 pub(crate) mod builtin;
 pub(crate) mod catalog;
+pub(crate) mod digest;
 pub(crate) mod dump;
+pub(crate) mod edit;
 pub(crate) mod export;
 pub(crate) mod init;
 pub(crate) mod install;
+pub(crate) mod limits;
 pub(crate) mod list;
 pub(crate) mod materialize;
 pub(crate) mod metadata;
 pub(crate) mod paths;
+pub(crate) mod pins;
+pub(crate) mod remove;
+pub(crate) mod rename;
+pub(crate) mod root;
 pub(crate) mod select;
+pub(crate) mod selftest;
 pub(crate) mod validate;
+pub(crate) mod version;
+pub(crate) mod which;
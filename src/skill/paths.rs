@@ -6,19 +6,49 @@ use std::{
 use anyhow::{Context, Result, bail};
 use toml_edit::DocumentMut;
 
+use super::init::SKILLS_ROOT;
+
 pub(crate) const SKILL_DUMP_ROOT: &str = ".agents/skills";
 
-pub(crate) fn resolve_dump_target(to: Option<&PathBuf>) -> Result<PathBuf> {
+/// Environment variable listing workspace skill roots in precedence order.
+pub(crate) const SKILLS_PATH_ENV: &str = "AGX_SKILLS_PATH";
+
+/// Workspace skill roots searched by `skill list` and `skill validate`.
+///
+/// `AGX_SKILLS_PATH` uses the platform path-list separator (`:` on Unix);
+/// earlier roots shadow later ones by skill name. Defaults to `.agents/skills`.
+pub(crate) fn skill_search_roots() -> Vec<PathBuf> {
+    let roots = env::var_os(SKILLS_PATH_ENV)
+        .map(|value| {
+            env::split_paths(&value)
+                .filter(|root| !root.as_os_str().is_empty())
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    if roots.is_empty() {
+        return vec![PathBuf::from(SKILLS_ROOT)];
+    }
+    roots
+}
+
+/// User-global skills directory: `<config dir>/agx/skills`, for example
+/// `~/.config/agx/skills` on Linux.
+pub(crate) fn global_skills_root() -> Result<PathBuf> {
+    let config_dir = dirs::config_dir()
+        .ok_or_else(|| anyhow::anyhow!("could not determine the user config directory"))?;
+    Ok(config_dir.join("agx/skills"))
+}
+
+pub(crate) fn resolve_dump_target(to: Option<&PathBuf>, global: bool) -> Result<PathBuf> {
     if let Some(path) = to {
         return Ok(path.clone());
     }
-
-    let roots = discover_project_roots()?;
-    if let Some(root) = roots.workspace_root {
-        return Ok(root.join(SKILL_DUMP_ROOT));
+    if global {
+        return global_skills_root();
     }
-    if let Some(root) = roots.crate_root {
-        return Ok(root.join(SKILL_DUMP_ROOT));
+
+    if let Some(root) = discover_project_roots()?.skills_root() {
+        return Ok(root);
     }
 
     bail!(
@@ -26,13 +56,27 @@ pub(crate) fn resolve_dump_target(to: Option<&PathBuf>) -> Result<PathBuf> {
     )
 }
 
+/// Cargo roots above the current directory.
 #[derive(Debug, Clone)]
-struct ProjectRoots {
-    workspace_root: Option<PathBuf>,
-    crate_root: Option<PathBuf>,
+pub(crate) struct ProjectRoots {
+    /// Nearest ancestor whose `Cargo.toml` declares `[workspace]`.
+    pub(crate) workspace_root: Option<PathBuf>,
+    /// Nearest ancestor with a `Cargo.toml`.
+    pub(crate) crate_root: Option<PathBuf>,
+}
+
+impl ProjectRoots {
+    /// Project skills directory: `.agents/skills` under the workspace root,
+    /// else under the crate root.
+    pub(crate) fn skills_root(&self) -> Option<PathBuf> {
+        self.workspace_root
+            .as_ref()
+            .or(self.crate_root.as_ref())
+            .map(|root| root.join(SKILL_DUMP_ROOT))
+    }
 }
 
-fn discover_project_roots() -> Result<ProjectRoots> {
+pub(crate) fn discover_project_roots() -> Result<ProjectRoots> {
     let cwd = env::current_dir().context("failed to resolve current directory")?;
     let mut crate_root = None;
     let mut workspace_root = None;
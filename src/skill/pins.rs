@@ -0,0 +1,100 @@
+//! Workspace pins for installed built-in skills.
+//!
+//! `skill install` records each built-in's version and content checksum in
+//! `builtin-pins.toml` under the install root, and refuses to replace a pinned
+//! skill with different built-in content unless `--force` is passed.
+
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result, bail};
+use toml_edit::{DocumentMut, Item, Table, value};
+
+use super::{builtin::BuiltinSkill, export::sha256_hex};
+
+/// Pin file name, relative to the skills root.
+pub(crate) const PINS_FILE: &str = "builtin-pins.toml";
+
+/// Content checksum of a built-in skill: sha256 over each file's path, mode,
+/// and content in catalog order.
+pub(crate) fn skill_checksum(skill: &BuiltinSkill) -> String {
+    let mut bytes = Vec::new();
+    for file in &skill.files {
+        bytes.extend_from_slice(file.path.as_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(format!("{:o}", file.mode()).as_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(file.content.as_bytes());
+        bytes.push(0);
+    }
+    format!("sha256:{}", sha256_hex(&bytes))
+}
+
+/// Fail when a selected skill is pinned to different built-in content,
+/// unless `force` is set.
+pub(crate) fn check_pins(skills: &[BuiltinSkill], target_root: &Path, force: bool) -> Result<()> {
+    if force {
+        return Ok(());
+    }
+    let pins = read_pins(target_root)?;
+    let mut conflicts = Vec::new();
+    for skill in skills {
+        let Some(pin) = pins.get(&skill.name).and_then(Item::as_table) else {
+            continue;
+        };
+        let Some(pinned) = pin.get("checksum").and_then(Item::as_str) else {
+            bail!(
+                "pin for skill `{}` in `{}` is missing `checksum`",
+                skill.name,
+                target_root.join(PINS_FILE).display()
+            );
+        };
+        if pinned == skill_checksum(skill) {
+            continue;
+        }
+        let describe = |version: Option<&str>, checksum: &str| match version {
+            Some(version) => format!("{version} ({checksum})"),
+            None => checksum.to_owned(),
+        };
+        conflicts.push(format!(
+            "`{}` is pinned to {}; built-in is {}",
+            skill.name,
+            describe(pin.get("version").and_then(Item::as_str), pinned),
+            describe(skill.version.as_deref(), &skill_checksum(skill))
+        ));
+    }
+    if conflicts.is_empty() {
+        return Ok(());
+    }
+    bail!(
+        "refusing to replace pinned skill(s) without --force:\n  {}",
+        conflicts.join("\n  ")
+    )
+}
+
+/// Record the version and checksum of each installed skill.
+pub(crate) fn record_pins(skills: &[BuiltinSkill], target_root: &Path) -> Result<()> {
+    let mut pins = read_pins(target_root)?;
+    for skill in skills {
+        let mut pin = Table::new();
+        if let Some(version) = &skill.version {
+            pin["version"] = value(version.as_str());
+        }
+        pin["checksum"] = value(skill_checksum(skill));
+        pins[skill.name.as_str()] = Item::Table(pin);
+    }
+    let path = target_root.join(PINS_FILE);
+    fs::write(&path, pins.to_string())
+        .with_context(|| format!("failed to write `{}`", path.display()))
+}
+
+fn read_pins(target_root: &Path) -> Result<DocumentMut> {
+    let path = target_root.join(PINS_FILE);
+    if !path.is_file() {
+        return Ok(DocumentMut::new());
+    }
+    let source = fs::read_to_string(&path)
+        .with_context(|| format!("failed to read `{}`", path.display()))?;
+    source
+        .parse::<DocumentMut>()
+        .with_context(|| format!("failed to parse `{}`", path.display()))
+}
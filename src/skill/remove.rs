@@ -0,0 +1,74 @@
+use std::{
+    fs,
+    io::{self, BufRead, IsTerminal, Write},
+    path::Path,
+};
+
+use anyhow::{Context, Result, bail};
+
+use crate::cli::SkillRemoveArgs;
+use crate::output;
+
+use super::{init::SKILLS_ROOT, metadata::validate_skill_name};
+
+/// Delete a workspace skill directory under `.agents/skills`.
+///
+/// The name must be a valid skill name and the directory must hold a
+/// `SKILL.md`. The resolved directory (after following symlinks) must sit
+/// directly inside the skills root, so a symlinked skill never deletes files
+/// elsewhere. Without `--force` the removal is confirmed interactively, which
+/// requires a terminal on stdin.
+pub(crate) fn run(args: SkillRemoveArgs) -> Result<()> {
+    validate_skill_name(&args.name)?;
+    let skills_root = Path::new(SKILLS_ROOT);
+    let skill_dir = skills_root.join(&args.name);
+    if !skill_dir.join("SKILL.md").is_file() {
+        bail!("skill `{}` was not found under `{SKILLS_ROOT}`", args.name);
+    }
+
+    let resolved_root = fs::canonicalize(skills_root)
+        .with_context(|| format!("failed to resolve `{SKILLS_ROOT}`"))?;
+    let resolved_dir = fs::canonicalize(&skill_dir)
+        .with_context(|| format!("failed to resolve `{}`", skill_dir.display()))?;
+    if resolved_dir.parent() != Some(resolved_root.as_path()) {
+        bail!(
+            "refusing to remove `{}`: it resolves to `{}`, outside `{SKILLS_ROOT}`",
+            skill_dir.display(),
+            resolved_dir.display()
+        );
+    }
+
+    if !args.force && !confirm_removal(&skill_dir)? {
+        output::print_log("aborted; nothing was removed");
+        return Ok(());
+    }
+    fs::remove_dir_all(&skill_dir)
+        .with_context(|| format!("failed to remove `{}`", skill_dir.display()))?;
+    output::print_path(skill_dir.display());
+    Ok(())
+}
+
+/// Ask on stderr whether to remove `skill_dir`; only `y`/`yes` confirms.
+fn confirm_removal(skill_dir: &Path) -> Result<bool> {
+    let stdin = io::stdin();
+    if !stdin.is_terminal() {
+        bail!(
+            "refusing to remove `{}` without confirmation; pass --force when stdin is not a terminal",
+            skill_dir.display()
+        );
+    }
+    eprint!(
+        "remove `{}` and everything in it? [y/N] ",
+        skill_dir.display()
+    );
+    io::stderr().flush()?;
+    let mut answer = String::new();
+    stdin
+        .lock()
+        .read_line(&mut answer)
+        .context("failed to read confirmation")?;
+    Ok(matches!(
+        answer.trim().to_ascii_lowercase().as_str(),
+        "y" | "yes"
+    ))
+}
@@ -0,0 +1,187 @@
+use std::{fs, path::Path};
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::cli::SkillRenameArgs;
+use crate::output;
+
+use super::{
+    init::{SKILLS_ROOT, title_case},
+    metadata::{read_skill_metadata, validate_skill_name},
+};
+
+/// Rename a workspace skill directory and keep its metadata in sync.
+///
+/// Rewrites the `name:` frontmatter field in `SKILL.md`. In `agents/*.yaml`,
+/// `$<old>` mentions in `default_prompt` become `$<new>`, and a
+/// `display_name` still matching the scaffolded title of the old name is
+/// retitled.
+pub(crate) fn run(args: SkillRenameArgs) -> Result<()> {
+    validate_skill_name(&args.old)?;
+    validate_skill_name(&args.new)?;
+    let skills_root = Path::new(SKILLS_ROOT);
+    let old_dir = skills_root.join(&args.old);
+    let new_dir = skills_root.join(&args.new);
+    if !old_dir.join("SKILL.md").is_file() {
+        bail!("skill `{}` was not found under `{SKILLS_ROOT}`", args.old);
+    }
+    if args.old == args.new {
+        bail!("skill `{}` already has that name", args.old);
+    }
+    if new_dir.exists() {
+        bail!(
+            "cannot rename skill `{}`: `{}` already exists",
+            args.old,
+            new_dir.display()
+        );
+    }
+
+    let skill_md = old_dir.join("SKILL.md");
+    let source = fs::read_to_string(&skill_md)
+        .with_context(|| format!("failed to read `{}`", skill_md.display()))?;
+    let renamed = set_frontmatter_name(&source, &args.new)?;
+    fs::rename(&old_dir, &new_dir).with_context(|| {
+        format!(
+            "failed to rename `{}` to `{}`",
+            old_dir.display(),
+            new_dir.display()
+        )
+    })?;
+    let skill_md = new_dir.join("SKILL.md");
+    fs::write(&skill_md, renamed)
+        .with_context(|| format!("failed to write `{}`", skill_md.display()))?;
+    update_agent_configs(&new_dir.join("agents"), &args.old, &args.new)?;
+
+    read_skill_metadata(&new_dir)
+        .with_context(|| format!("renamed skill `{}` is invalid", args.new))?;
+    output::print_path(new_dir.display());
+    Ok(())
+}
+
+/// Replace the top-level `name:` frontmatter line.
+fn set_frontmatter_name(source: &str, name: &str) -> Result<String> {
+    let Some(rest) = source.strip_prefix("---\n") else {
+        bail!("SKILL.md must start with YAML frontmatter marker `---`");
+    };
+    let end = rest
+        .find("\n---")
+        .ok_or_else(|| anyhow!("SKILL.md is missing closing YAML frontmatter marker `---`"))?;
+    let (frontmatter, body) = rest.split_at(end);
+
+    let mut replaced = false;
+    let lines = frontmatter
+        .lines()
+        .map(|existing| {
+            if !replaced && existing.starts_with("name:") {
+                replaced = true;
+                format!("name: {name}")
+            } else {
+                existing.to_owned()
+            }
+        })
+        .collect::<Vec<_>>();
+    if !replaced {
+        bail!("SKILL.md frontmatter has no `name` field");
+    }
+    Ok(format!("---\n{}{body}", lines.join("\n")))
+}
+
+/// Point `default_prompt` mentions and the scaffolded `display_name` in
+/// `agents/*.yaml` at the new skill name.
+fn update_agent_configs(agents_dir: &Path, old: &str, new: &str) -> Result<()> {
+    if !agents_dir.is_dir() {
+        return Ok(());
+    }
+
+    let old_title = title_case(old);
+    let mut entries = fs::read_dir(agents_dir)
+        .with_context(|| format!("failed to read `{}`", agents_dir.display()))?
+        .collect::<std::io::Result<Vec<_>>>()
+        .with_context(|| format!("failed to read `{}`", agents_dir.display()))?;
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+        let path = entry.path();
+        if !path.is_file() || path.extension().and_then(|ext| ext.to_str()) != Some("yaml") {
+            continue;
+        }
+        let source = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read `{}`", path.display()))?;
+
+        let mut updated = source
+            .lines()
+            .map(|line| {
+                let Some((key, value)) = line.split_once(':') else {
+                    return line.to_owned();
+                };
+                match key.trim() {
+                    "default_prompt" => {
+                        format!("{key}:{}", replace_skill_mentions(value, old, new))
+                    }
+                    "display_name" if value.trim().trim_matches('"') == old_title => {
+                        format!("{key}:{}", value.replacen(&old_title, &title_case(new), 1))
+                    }
+                    _ => line.to_owned(),
+                }
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        if source.ends_with('\n') {
+            updated.push('\n');
+        }
+        if updated == source {
+            continue;
+        }
+        fs::write(&path, updated)
+            .with_context(|| format!("failed to write `{}`", path.display()))?;
+        output::print_log(format!("updated skill name in `{}`", path.display()));
+    }
+    Ok(())
+}
+
+/// Replace `$old` skill mentions with `$new`, leaving longer names such as
+/// `$old-extra` alone.
+fn replace_skill_mentions(text: &str, old: &str, new: &str) -> String {
+    let mention = format!("${old}");
+    let mut replaced = String::new();
+    let mut rest = text;
+    while let Some(start) = rest.find(&mention) {
+        let end = start + mention.len();
+        let continues = rest[end..]
+            .chars()
+            .next()
+            .is_some_and(|ch| ch.is_ascii_alphanumeric() || ch == '-');
+        replaced.push_str(&rest[..start]);
+        if continues {
+            replaced.push_str(&mention);
+        } else {
+            replaced.push('$');
+            replaced.push_str(new);
+        }
+        rest = &rest[end..];
+    }
+    replaced.push_str(rest);
+    replaced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{replace_skill_mentions, set_frontmatter_name};
+
+    #[test]
+    fn replace_skill_mentions_skips_longer_names() {
+        assert_eq!(
+            replace_skill_mentions("Use $triage, not $triage-bot or triage.", "triage", "sort"),
+            "Use $sort, not $triage-bot or triage."
+        );
+    }
+
+    #[test]
+    fn set_frontmatter_name_rewrites_only_the_name_field() {
+        let source = "---\nname: old\ndescription: name: old\n---\n\n# Old\n";
+        assert_eq!(
+            set_frontmatter_name(source, "new").expect("name should be rewritten"),
+            "---\nname: new\ndescription: name: old\n---\n\n# Old\n"
+        );
+    }
+}
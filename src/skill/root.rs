@@ -0,0 +1,65 @@
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use serde::Serialize;
+
+use crate::cli::{SkillRootArgs, SkillRootFormat};
+
+use super::paths::{discover_project_roots, global_skills_root, skill_search_roots};
+
+/// Report the roots `skill` commands resolve from the current directory.
+///
+/// `skills_root` is where `skill dump` writes by default; `search_roots` are
+/// the workspace roots `skill list`/`skill validate` read (`AGX_SKILLS_PATH`),
+/// in precedence order.
+pub(crate) fn run(args: SkillRootArgs) -> Result<()> {
+    let roots = discover_project_roots()?;
+    let report = SkillRootJson {
+        schema_version: 1,
+        workspace_root: roots.workspace_root.as_deref().map(display),
+        crate_root: roots.crate_root.as_deref().map(display),
+        skills_root: roots.skills_root().as_deref().map(display),
+        search_roots: skill_search_roots()
+            .iter()
+            .map(PathBuf::as_path)
+            .map(display)
+            .collect(),
+        global_root: global_skills_root().ok().as_deref().map(display),
+    };
+
+    match args.format {
+        SkillRootFormat::Text => {
+            let fields = [
+                ("workspace_root", &report.workspace_root),
+                ("crate_root", &report.crate_root),
+                ("skills_root", &report.skills_root),
+            ];
+            for (label, value) in fields {
+                println!("{label}\t{}", value.as_deref().unwrap_or("-"));
+            }
+            for root in &report.search_roots {
+                println!("search_root\t{root}");
+            }
+            println!(
+                "global_root\t{}",
+                report.global_root.as_deref().unwrap_or("-")
+            );
+        }
+        SkillRootFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+    }
+    Ok(())
+}
+
+fn display(path: &Path) -> String {
+    path.to_string_lossy().into_owned()
+}
+
+#[derive(Debug, Serialize)]
+struct SkillRootJson {
+    schema_version: u32,
+    workspace_root: Option<String>,
+    crate_root: Option<String>,
+    skills_root: Option<String>,
+    search_roots: Vec<String>,
+    global_root: Option<String>,
+}
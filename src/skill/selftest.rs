@@ -0,0 +1,125 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+use flate2::read::GzDecoder;
+use tar::Archive;
+
+use crate::output;
+
+use super::{builtin, export::encode_archive, materialize::materialize_skills};
+
+/// Dump every built-in skill, export the same set, unpack the archive, and
+/// require both trees to match byte for byte (and by file mode on unix).
+pub(crate) fn run() -> Result<()> {
+    let skills = builtin::load_skills()?;
+    if skills.is_empty() {
+        bail!("no built-in skills are available to test");
+    }
+
+    let scratch = std::env::temp_dir().join(format!(
+        "agx-selftest-{}-{}",
+        std::process::id(),
+        chrono::Utc::now().timestamp_nanos_opt().unwrap_or_default()
+    ));
+    let result = round_trip(&skills, &scratch);
+    let _ = fs::remove_dir_all(&scratch);
+    result?;
+
+    output::print_log(format!(
+        "round-tripped {} built-in skill(s) through dump and export",
+        skills.len()
+    ));
+    Ok(())
+}
+
+fn round_trip(skills: &[builtin::BuiltinSkill], scratch: &Path) -> Result<()> {
+    let dumped = scratch.join("dump");
+    materialize_skills(skills, &dumped, false)?;
+
+    let archive = encode_archive(Vec::new(), skills, false)?;
+    let imported = scratch.join("import");
+    Archive::new(GzDecoder::new(archive.as_slice()))
+        .unpack(&imported)
+        .context("failed to unpack exported skills archive")?;
+
+    if let Some(divergence) = first_divergence(&dumped, &imported.join(".agents/skills"))? {
+        bail!("skill round-trip diverged: {divergence}");
+    }
+    Ok(())
+}
+
+/// Describe the first difference between two directory trees, if any.
+fn first_divergence(dumped: &Path, imported: &Path) -> Result<Option<String>> {
+    let dumped_files = relative_files(dumped)?;
+    let imported_files = relative_files(imported)?;
+    if let Some(path) = dumped_files.difference(&imported_files).next() {
+        return Ok(Some(format!(
+            "`{}` was dumped but not exported",
+            path.display()
+        )));
+    }
+    if let Some(path) = imported_files.difference(&dumped_files).next() {
+        return Ok(Some(format!(
+            "`{}` was exported but not dumped",
+            path.display()
+        )));
+    }
+
+    for path in &dumped_files {
+        let (left, right) = (dumped.join(path), imported.join(path));
+        let read = |path: &Path| {
+            fs::read(path).with_context(|| format!("failed to read `{}`", path.display()))
+        };
+        if read(&left)? != read(&right)? {
+            return Ok(Some(format!(
+                "`{}` differs between dump and export",
+                path.display()
+            )));
+        }
+        if file_mode(&left)? != file_mode(&right)? {
+            return Ok(Some(format!(
+                "`{}` has a different file mode after export",
+                path.display()
+            )));
+        }
+    }
+    Ok(None)
+}
+
+fn relative_files(root: &Path) -> Result<BTreeSet<PathBuf>> {
+    let mut files = BTreeSet::new();
+    let mut pending = vec![root.to_path_buf()];
+    while let Some(dir) = pending.pop() {
+        for entry in
+            fs::read_dir(&dir).with_context(|| format!("failed to read `{}`", dir.display()))?
+        {
+            let path = entry?.path();
+            if path.is_dir() {
+                pending.push(path);
+            } else if let Ok(relative) = path.strip_prefix(root) {
+                files.insert(relative.to_path_buf());
+            }
+        }
+    }
+    Ok(files)
+}
+
+#[cfg(unix)]
+fn file_mode(path: &Path) -> Result<u32> {
+    use std::os::unix::fs::PermissionsExt;
+
+    Ok(fs::metadata(path)
+        .with_context(|| format!("failed to read `{}`", path.display()))?
+        .permissions()
+        .mode()
+        & 0o777)
+}
+
+#[cfg(not(unix))]
+fn file_mode(_path: &Path) -> Result<u32> {
+    Ok(0)
+}
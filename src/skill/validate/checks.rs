@@ -0,0 +1,118 @@
+use std::{collections::BTreeSet, env, fs, path::Path};
+
+use anyhow::{Context, Result, anyhow, bail};
+use jsonschema::Validator;
+
+use crate::output;
+use crate::skill::metadata::{SkillMetadata, read_frontmatter_json, read_skill_body};
+
+/// Compile the JSON Schema at `path` for frontmatter validation.
+pub(super) fn load_schema(path: &Path) -> Result<Validator> {
+    let source =
+        fs::read_to_string(path).with_context(|| format!("failed to read `{}`", path.display()))?;
+    let schema = serde_json::from_str(&source)
+        .with_context(|| format!("failed to parse JSON Schema `{}`", path.display()))?;
+    jsonschema::validator_for(&schema)
+        .map_err(|error| anyhow!("invalid JSON Schema `{}`: {error}", path.display()))
+}
+
+/// Fail with every schema violation in the skill's frontmatter, keyed by path.
+pub(super) fn ensure_matches_schema(skill_path: &Path, schema: &Validator) -> Result<()> {
+    let frontmatter = read_frontmatter_json(skill_path)?;
+    let violations = schema
+        .iter_errors(&frontmatter)
+        .map(|error| {
+            let path = error.instance_path.to_string();
+            let path = if path.is_empty() {
+                "/".to_owned()
+            } else {
+                path
+            };
+            format!("`{path}`: {error}")
+        })
+        .collect::<Vec<_>>();
+    if violations.is_empty() {
+        return Ok(());
+    }
+    bail!(
+        "frontmatter does not match schema: {}",
+        violations.join("; ")
+    )
+}
+
+/// Fail when any `requires` tool cannot be found on `PATH`.
+pub(super) fn ensure_required_tools(metadata: &SkillMetadata) -> Result<()> {
+    let search_path = env::var_os("PATH").unwrap_or_default();
+    let missing = metadata
+        .requires
+        .iter()
+        .filter(|tool| {
+            !env::split_paths(&search_path)
+                .any(|dir| dir.join(tool).is_file() || dir.join(format!("{tool}.exe")).is_file())
+        })
+        .map(|tool| format!("`{tool}`"))
+        .collect::<Vec<_>>();
+    if missing.is_empty() {
+        return Ok(());
+    }
+    bail!("missing required tool(s) on PATH: {}", missing.join(", "))
+}
+
+/// Fail when any file in the skill directory contains CRLF line endings.
+pub(super) fn ensure_lf_line_endings(skill_path: &Path) -> Result<()> {
+    let mut crlf_files = Vec::new();
+    collect_crlf_files(skill_path, skill_path, &mut crlf_files)?;
+    if crlf_files.is_empty() {
+        return Ok(());
+    }
+    bail!(
+        "CRLF line endings found in {}; convert to LF",
+        crlf_files.join(", ")
+    )
+}
+
+fn collect_crlf_files(root: &Path, current: &Path, crlf_files: &mut Vec<String>) -> Result<()> {
+    let mut entries = fs::read_dir(current)
+        .with_context(|| format!("failed to read `{}`", current.display()))?
+        .collect::<std::io::Result<Vec<_>>>()
+        .with_context(|| format!("failed to read `{}`", current.display()))?;
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_crlf_files(root, &path, crlf_files)?;
+            continue;
+        }
+        let bytes =
+            fs::read(&path).with_context(|| format!("failed to read `{}`", path.display()))?;
+        if bytes.windows(2).any(|pair| pair == b"\r\n") {
+            let relative = path.strip_prefix(root).unwrap_or(&path);
+            crlf_files.push(format!("`{}`", relative.display()));
+        }
+    }
+    Ok(())
+}
+
+/// Warn about `$name` tokens in the `SKILL.md` body with no matching skill.
+pub(super) fn warn_unknown_skill_refs(skill_path: &Path, known: &BTreeSet<String>) -> Result<()> {
+    let body = read_skill_body(skill_path)?;
+    let mut reported = BTreeSet::new();
+    for (index, _) in body.match_indices('$') {
+        let name = body[index + 1..]
+            .chars()
+            .take_while(|ch| ch.is_ascii_lowercase() || ch.is_ascii_digit() || *ch == '-')
+            .collect::<String>();
+        let name = name.trim_end_matches('-');
+        if !name.starts_with(|ch: char| ch.is_ascii_lowercase()) || known.contains(name) {
+            continue;
+        }
+        if reported.insert(name.to_owned()) {
+            output::print_warning(format!(
+                "{}: references unknown skill `${name}`",
+                skill_path.display()
+            ));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    path::{Path, PathBuf},
+};
+
+use anyhow::{Context, Result, bail};
+
+use crate::skill::builtin;
+
+pub(super) fn discover_skill_paths(target: &Path) -> Result<Vec<PathBuf>> {
+    if target.join("SKILL.md").is_file() {
+        return Ok(vec![target.to_path_buf()]);
+    }
+
+    if !target.is_dir() {
+        bail!(
+            "expected a skill directory or skills root directory, found `{}`",
+            target.display()
+        );
+    }
+
+    let skills = list_skill_dirs(target)?;
+    if skills.is_empty() {
+        bail!("no skills found under `{}`", target.display());
+    }
+    Ok(skills)
+}
+
+/// Skill directories across search roots; earlier roots shadow later ones by
+/// folder name and missing roots are skipped.
+pub(super) fn discover_skill_paths_in_roots(roots: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    if let [root] = roots {
+        return discover_skill_paths(root);
+    }
+
+    let mut seen = BTreeSet::new();
+    let mut skills = Vec::new();
+    for root in roots.iter().filter(|root| root.is_dir()) {
+        for path in list_skill_dirs(root)? {
+            if seen.insert(path.file_name().map(ToOwned::to_owned)) {
+                skills.push(path);
+            }
+        }
+    }
+
+    if skills.is_empty() {
+        let searched = roots
+            .iter()
+            .map(|root| format!("`{}`", root.display()))
+            .collect::<Vec<_>>()
+            .join(", ");
+        bail!("no skills found under {searched}");
+    }
+    Ok(skills)
+}
+
+/// Sorted child directories of `root` that contain `SKILL.md`.
+fn list_skill_dirs(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut skills = Vec::new();
+    for entry in
+        fs::read_dir(root).with_context(|| format!("failed to read `{}`", root.display()))?
+    {
+        let entry = entry?;
+        let path = entry.path();
+        if !path.is_dir() {
+            continue;
+        }
+        if path.join("SKILL.md").is_file() {
+            skills.push(path);
+        }
+    }
+
+    skills.sort();
+    Ok(skills)
+}
+
+/// Names of built-in skills plus workspace skill folders containing `SKILL.md`.
+pub(super) fn known_skill_names(roots: &[PathBuf]) -> Result<BTreeSet<String>> {
+    let mut names = builtin::load_skills()?
+        .into_iter()
+        .map(|skill| skill.name)
+        .collect::<BTreeSet<_>>();
+
+    for root in roots.iter().filter(|root| root.is_dir()) {
+        for path in list_skill_dirs(root)? {
+            if let Some(name) = path.file_name().and_then(|value| value.to_str()) {
+                names.insert(name.to_owned());
+            }
+        }
+    }
+    Ok(names)
+}
@@ -0,0 +1,103 @@
+use std::{
+    fs,
+    path::{Component, Path},
+};
+
+use anyhow::{Context, Result, bail};
+
+use crate::skill::metadata::read_skill_body;
+
+/// Fail when a relative link in the `SKILL.md` body points at a file that is
+/// missing or outside the skill directory, reporting `SKILL.md` line numbers.
+///
+/// Inline links, images, and reference definitions are checked; URLs with a
+/// scheme, `#` anchors, absolute paths, and fenced code blocks are ignored.
+pub(super) fn ensure_local_links_exist(skill_path: &Path) -> Result<()> {
+    let skill_md_path = skill_path.join("SKILL.md");
+    let source = fs::read_to_string(&skill_md_path)
+        .with_context(|| format!("failed to read `{}`", skill_md_path.display()))?
+        .replace("\r\n", "\n");
+    let body = read_skill_body(skill_path)?;
+    // The body starts on the closing `---` line, so both splits share it.
+    let line_offset = source.split('\n').count() - body.split('\n').count();
+
+    let mut problems = Vec::new();
+    let mut fence: Option<&str> = None;
+    for (index, line) in body.split('\n').enumerate() {
+        let trimmed = line.trim_start();
+        if let Some(marker) = ["```", "~~~"]
+            .into_iter()
+            .find(|marker| trimmed.starts_with(marker))
+        {
+            match fence {
+                Some(open) if open == marker => fence = None,
+                None => fence = Some(marker),
+                Some(_) => {}
+            }
+            continue;
+        }
+        if fence.is_some() {
+            continue;
+        }
+        for target in local_link_targets(line) {
+            let line_number = line_offset + index + 1;
+            if escapes_directory(target) {
+                problems.push(format!(
+                    "line {line_number}: `{target}` points outside the skill directory"
+                ));
+            } else if !skill_path.join(target).exists() {
+                problems.push(format!("line {line_number}: missing file `{target}`"));
+            }
+        }
+    }
+    if problems.is_empty() {
+        return Ok(());
+    }
+    bail!("broken link(s) in `SKILL.md`: {}", problems.join("; "))
+}
+
+/// Relative link targets on one markdown line, without `#fragment` or title.
+fn local_link_targets(line: &str) -> Vec<&str> {
+    let mut targets = Vec::new();
+    let mut rest = line;
+    while let Some(start) = rest.find("](") {
+        rest = &rest[start + 2..];
+        let end = rest.find(')').unwrap_or(rest.len());
+        targets.push(&rest[..end]);
+        rest = &rest[end..];
+    }
+    let trimmed = line.trim_start();
+    if trimmed.starts_with('[')
+        && let Some(end) = trimmed.find("]:")
+    {
+        targets.push(&trimmed[end + 2..]);
+    }
+
+    targets
+        .into_iter()
+        .filter_map(|target| {
+            let target = target.trim().trim_start_matches('<');
+            let target = target.split_whitespace().next().unwrap_or_default();
+            let target = target.trim_end_matches('>');
+            let target = target.split('#').next().unwrap_or_default();
+            let has_scheme = target
+                .split_once(':')
+                .is_some_and(|(scheme, _)| !scheme.contains('/'));
+            (!target.is_empty() && !has_scheme && !target.starts_with('/')).then_some(target)
+        })
+        .collect()
+}
+
+/// Whether `target` climbs above its starting directory with `..`.
+fn escapes_directory(target: &str) -> bool {
+    let mut depth = 0usize;
+    for component in Path::new(target).components() {
+        match component {
+            Component::ParentDir if depth == 0 => return true,
+            Component::ParentDir => depth -= 1,
+            Component::Normal(_) => depth += 1,
+            _ => {}
+        }
+    }
+    false
+}
@@ -0,0 +1,178 @@
+mod checks;
+mod discover;
+mod links;
+
+use std::path::Path;
+
+use anyhow::{Result, bail};
+use serde::Serialize;
+
+use crate::cli::{SkillValidateArgs, SkillValidateFormat};
+use crate::output;
+
+use super::{
+    metadata::{
+        SkillMetadata, ensure_agent_configs_valid, ensure_only_known_agent_configs,
+        read_skill_metadata_with,
+    },
+    paths::skill_search_roots,
+};
+
+/// Validate one skill or all skills under a skills root directory.
+pub(crate) fn run(args: SkillValidateArgs) -> Result<()> {
+    let roots = skill_search_roots();
+    let skills = match args.name.as_deref() {
+        Some(name) => {
+            let target = roots
+                .iter()
+                .map(|root| root.join(name))
+                .find(|candidate| candidate.join("SKILL.md").is_file())
+                .unwrap_or_else(|| roots[0].join(name));
+            discover::discover_skill_paths(&target)?
+        }
+        None => discover::discover_skill_paths_in_roots(&roots)?,
+    };
+    let schema = args
+        .schema
+        .as_deref()
+        .map(checks::load_schema)
+        .transpose()?;
+    let known_skills = if args.check_skill_refs {
+        Some(discover::known_skill_names(&roots)?)
+    } else {
+        None
+    };
+
+    let mut results = Vec::with_capacity(skills.len());
+    for skill in &skills {
+        // Run every check so one report lists all of a skill's problems.
+        let mut errors = Vec::new();
+        let mut record = |result: Result<()>| {
+            if let Err(error) = result {
+                errors.push(format!("{error:#}"));
+            }
+        };
+        match validate_skill(skill, schema.is_none()) {
+            Ok(metadata) if args.check_requires => record(checks::ensure_required_tools(&metadata)),
+            Ok(_) => {}
+            Err(error) => record(Err(error)),
+        }
+        if let Some(schema) = &schema {
+            record(checks::ensure_matches_schema(skill, schema));
+        }
+        if args.check_eol {
+            record(checks::ensure_lf_line_endings(skill));
+        }
+        if args.check_links {
+            record(links::ensure_local_links_exist(skill));
+        }
+        if args.strict {
+            record(ensure_only_known_agent_configs(skill));
+        }
+        if errors.is_empty()
+            && let Some(known) = &known_skills
+        {
+            checks::warn_unknown_skill_refs(skill, known)?;
+        }
+        results.push(SkillValidationResultJson {
+            skill: skill
+                .file_name()
+                .map(|name| name.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+            path: skill.to_string_lossy().into_owned(),
+            ok: errors.is_empty(),
+            errors,
+        });
+    }
+
+    let failed = results.iter().filter(|result| !result.ok).count();
+    let summary = SkillValidationSummaryJson {
+        schema_version: 1,
+        total: results.len(),
+        passed: results.len() - failed,
+        failed,
+        results,
+    };
+
+    match args.format {
+        SkillValidateFormat::Text => {
+            for result in &summary.results {
+                if result.ok {
+                    output::print_log(format!("ok {}", result.path));
+                }
+                for error in &result.errors {
+                    output::print_error(format!("{}: {error}", result.path));
+                }
+            }
+            output::print_log(format!(
+                "validated {} skill(s), {} failed",
+                summary.total, summary.failed
+            ));
+        }
+        SkillValidateFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&summary)?);
+        }
+        SkillValidateFormat::Ndjson => {
+            for result in &summary.results {
+                println!("{}", serde_json::to_string(result)?);
+            }
+            let totals = SkillValidationTotalsJson {
+                schema_version: summary.schema_version,
+                total: summary.total,
+                passed: summary.passed,
+                failed: summary.failed,
+            };
+            println!("{}", serde_json::to_string(&totals)?);
+        }
+    }
+
+    if failed > 0 {
+        bail!("skill validation failed");
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct SkillValidationResultJson {
+    skill: String,
+    path: String,
+    ok: bool,
+    /// Every failed check for this skill, in check order.
+    errors: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct SkillValidationSummaryJson {
+    schema_version: u32,
+    total: usize,
+    passed: usize,
+    failed: usize,
+    results: Vec<SkillValidationResultJson>,
+}
+
+/// Final `--format ndjson` line, after one line per skill result.
+#[derive(Debug, Serialize)]
+struct SkillValidationTotalsJson {
+    schema_version: u32,
+    total: usize,
+    passed: usize,
+    failed: usize,
+}
+
+fn validate_skill(skill_path: &Path, check_keys: bool) -> Result<SkillMetadata> {
+    let metadata = read_skill_metadata_with(skill_path, check_keys)?;
+
+    let folder_name = skill_path
+        .file_name()
+        .and_then(|value| value.to_str())
+        .ok_or_else(|| anyhow::anyhow!("invalid skill directory name"))?;
+    if folder_name != metadata.name {
+        bail!(
+            "skill folder `{folder_name}` does not match frontmatter name `{}`",
+            metadata.name
+        );
+    }
+
+    ensure_agent_configs_valid(skill_path)?;
+    Ok(metadata)
+}
@@ -0,0 +1,226 @@
+use std::{fmt, fs, path::Path};
+
+use anyhow::{Context, Result, anyhow, bail};
+
+use crate::{
+    cli::{SkillVersionArgs, SkillVersionBump},
+    output,
+};
+
+use super::{init::SKILLS_ROOT, metadata::read_skill_metadata};
+
+/// Semver `MAJOR.MINOR.PATCH` version declared in skill frontmatter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct SkillVersion {
+    major: u64,
+    minor: u64,
+    patch: u64,
+}
+
+impl SkillVersion {
+    pub(crate) fn parse(input: &str) -> Result<Self> {
+        let invalid = || anyhow!("invalid skill version `{input}`; expected `MAJOR.MINOR.PATCH`");
+        let mut parts = input.trim().split('.');
+        let mut component = || -> Result<u64> {
+            let part = parts.next().ok_or_else(invalid)?;
+            if part.is_empty() || !part.chars().all(|ch| ch.is_ascii_digit()) {
+                return Err(invalid());
+            }
+            part.parse().map_err(|_| invalid())
+        };
+        let version = Self {
+            major: component()?,
+            minor: component()?,
+            patch: component()?,
+        };
+        if parts.next().is_some() {
+            return Err(invalid());
+        }
+        Ok(version)
+    }
+
+    fn bump(self, bump: SkillVersionBump) -> Self {
+        match bump {
+            SkillVersionBump::Major => Self {
+                major: self.major + 1,
+                minor: 0,
+                patch: 0,
+            },
+            SkillVersionBump::Minor => Self {
+                minor: self.minor + 1,
+                patch: 0,
+                ..self
+            },
+            SkillVersionBump::Patch => Self {
+                patch: self.patch + 1,
+                ..self
+            },
+        }
+    }
+}
+
+impl fmt::Display for SkillVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// Bump or set a workspace skill's version in `SKILL.md` and agent configs.
+pub(crate) fn run(args: SkillVersionArgs) -> Result<()> {
+    let skill_dir = Path::new(SKILLS_ROOT).join(&args.name);
+    if !skill_dir.join("SKILL.md").is_file() {
+        bail!(
+            "skill `{}` was not found under `{SKILLS_ROOT}`; create it with `agx skill new {}`",
+            args.name,
+            args.name
+        );
+    }
+    let metadata = read_skill_metadata(&skill_dir)
+        .with_context(|| format!("skill `{}` is invalid", args.name))?;
+
+    let next = if let Some(version) = &args.set {
+        SkillVersion::parse(version)?
+    } else {
+        let bump = args.bump.expect("clap requires --bump or --set");
+        let current = metadata.version.ok_or_else(|| {
+            anyhow!(
+                "skill `{}` has no `version` in frontmatter; use --set to add one",
+                args.name
+            )
+        })?;
+        current.bump(bump)
+    };
+
+    let skill_md = skill_dir.join("SKILL.md");
+    let source = fs::read_to_string(&skill_md)
+        .with_context(|| format!("failed to read `{}`", skill_md.display()))?;
+    fs::write(&skill_md, set_frontmatter_version(&source, next)?)
+        .with_context(|| format!("failed to write `{}`", skill_md.display()))?;
+
+    if let Some(previous) = metadata.version {
+        update_agent_config_versions(&skill_dir.join("agents"), previous, next)?;
+    }
+
+    match metadata.version {
+        Some(previous) => output::print_log(format!(
+            "updated skill `{}` version {previous} -> {next}",
+            args.name
+        )),
+        None => output::print_log(format!("set skill `{}` version {next}", args.name)),
+    }
+    Ok(())
+}
+
+/// Replace the top-level `version:` frontmatter line, or append one before the
+/// closing marker.
+fn set_frontmatter_version(source: &str, version: SkillVersion) -> Result<String> {
+    let Some(rest) = source.strip_prefix("---\n") else {
+        bail!("SKILL.md must start with YAML frontmatter marker `---`");
+    };
+    let end = rest
+        .find("\n---")
+        .ok_or_else(|| anyhow!("SKILL.md is missing closing YAML frontmatter marker `---`"))?;
+    let (frontmatter, body) = rest.split_at(end);
+
+    let line = format!("version: {version}");
+    let mut replaced = false;
+    let mut lines = frontmatter
+        .lines()
+        .map(|existing| {
+            if existing.starts_with("version:") {
+                replaced = true;
+                line.clone()
+            } else {
+                existing.to_owned()
+            }
+        })
+        .collect::<Vec<_>>();
+    if !replaced {
+        lines.push(line);
+    }
+    Ok(format!("---\n{}{body}", lines.join("\n")))
+}
+
+/// Rewrite `version` entries matching `previous` in `agents/*.yaml`.
+fn update_agent_config_versions(
+    agents_dir: &Path,
+    previous: SkillVersion,
+    next: SkillVersion,
+) -> Result<()> {
+    if !agents_dir.is_dir() {
+        return Ok(());
+    }
+
+    let previous = previous.to_string();
+    let next = next.to_string();
+    let mut entries = fs::read_dir(agents_dir)
+        .with_context(|| format!("failed to read `{}`", agents_dir.display()))?
+        .collect::<std::io::Result<Vec<_>>>()
+        .with_context(|| format!("failed to read `{}`", agents_dir.display()))?;
+    entries.sort_by_key(|entry| entry.path());
+
+    for entry in entries {
+        let path = entry.path();
+        if !path.is_file() || path.extension().and_then(|ext| ext.to_str()) != Some("yaml") {
+            continue;
+        }
+        let source = fs::read_to_string(&path)
+            .with_context(|| format!("failed to read `{}`", path.display()))?;
+
+        let mut changed = false;
+        let mut updated = source
+            .lines()
+            .map(|line| {
+                let Some((key, value)) = line.split_once(':') else {
+                    return line.to_owned();
+                };
+                let value = value.trim().trim_matches('"').trim_matches('\'');
+                if key.trim() != "version" || value != previous {
+                    return line.to_owned();
+                }
+                changed = true;
+                line.replacen(&previous, &next, 1)
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        if !changed {
+            continue;
+        }
+        if source.ends_with('\n') {
+            updated.push('\n');
+        }
+        fs::write(&path, updated)
+            .with_context(|| format!("failed to write `{}`", path.display()))?;
+        output::print_log(format!("updated version in `{}`", path.display()));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SkillVersion, set_frontmatter_version};
+    use crate::cli::SkillVersionBump;
+
+    #[test]
+    fn bump_resets_lower_components() {
+        let version = SkillVersion::parse("1.2.3").expect("version should parse");
+        assert_eq!(version.bump(SkillVersionBump::Patch).to_string(), "1.2.4");
+        assert_eq!(version.bump(SkillVersionBump::Minor).to_string(), "1.3.0");
+        assert_eq!(version.bump(SkillVersionBump::Major).to_string(), "2.0.0");
+    }
+
+    #[test]
+    fn parse_rejects_non_semver() {
+        for input in ["1.2", "1.2.3.4", "v1.2.3", "1..3"] {
+            assert!(SkillVersion::parse(input).is_err(), "{input} should fail");
+        }
+    }
+
+    #[test]
+    fn set_frontmatter_version_appends_missing_field() {
+        let version = SkillVersion::parse("0.1.0").expect("version should parse");
+        let updated = set_frontmatter_version("---\nname: a\n---\n\n# A\n", version)
+            .expect("frontmatter should update");
+        assert_eq!(updated, "---\nname: a\nversion: 0.1.0\n---\n\n# A\n");
+    }
+}
@@ -0,0 +1,50 @@
+use anyhow::{Result, anyhow};
+use serde::Serialize;
+
+use crate::cli::{SkillListOrigin, SkillWhichArgs, SkillWhichFormat};
+
+use super::{
+    builtin,
+    catalog::{self, PreferredOrigin},
+    paths::skill_search_roots,
+};
+
+/// Report where `skill which <name>` resolves, using `skill list` precedence.
+pub(crate) fn run(args: SkillWhichArgs) -> Result<()> {
+    let builtin_skills = builtin::load_skills()?;
+    let workspace_skills = catalog::discover_workspace_skills_in_roots(&skill_search_roots())?;
+    let entry = catalog::discover_skills(SkillListOrigin::All, &builtin_skills, &workspace_skills)
+        .into_iter()
+        .find(|entry| entry.name == args.name)
+        .ok_or_else(|| {
+            anyhow!(
+                "skill `{}` was not found in workspace skills roots or built-in skills",
+                args.name
+            )
+        })?;
+
+    let resolution = SkillWhichJson {
+        schema_version: 1,
+        name: entry.name,
+        resolved_from: entry.preferred_origin,
+        path: entry.workspace_path,
+    };
+    match args.format {
+        SkillWhichFormat::Text => println!(
+            "{}\t{}\t{}",
+            resolution.name,
+            resolution.resolved_from.as_str(),
+            resolution.path.as_deref().unwrap_or("-")
+        ),
+        SkillWhichFormat::Json => println!("{}", serde_json::to_string_pretty(&resolution)?),
+    }
+    Ok(())
+}
+
+#[derive(Debug, Serialize)]
+struct SkillWhichJson {
+    schema_version: u32,
+    name: String,
+    resolved_from: PreferredOrigin,
+    path: Option<String>,
+}
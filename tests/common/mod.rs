@@ -48,6 +48,16 @@ impl TestWorkspace {
             .expect("failed to execute agx")
     }
 
+    pub fn run_cli_with_env(&self, args: &[&str], envs: &[(&str, &str)]) -> Output {
+        Command::new(env!("CARGO_BIN_EXE_agx"))
+            .current_dir(&self.root)
+            .env("AGX_DISABLE_CLIPBOARD", "1")
+            .envs(envs.iter().copied())
+            .args(args)
+            .output()
+            .expect("failed to execute agx")
+    }
+
     pub fn run_cli_in(&self, relative_dir: &str, args: &[&str]) -> Output {
         Command::new(env!("CARGO_BIN_EXE_agx"))
             .current_dir(self.root.join(relative_dir))
@@ -82,6 +92,13 @@ impl TestWorkspace {
         self.run_rfc(&command_args)
     }
 
+    pub fn run_rfc_validate(&self, args: &[&str]) -> Output {
+        let mut command_args = Vec::with_capacity(args.len() + 1);
+        command_args.push("validate");
+        command_args.extend_from_slice(args);
+        self.run_rfc(&command_args)
+    }
+
     pub fn run_rfc_in(&self, relative_dir: &str, args: &[&str]) -> Output {
         let mut command_args = Vec::with_capacity(args.len() + 1);
         command_args.push("rfc");
@@ -49,6 +49,28 @@ fn latest_revision_timestamp(markdown: &str) -> Option<String> {
     latest
 }
 
+/// Write `rfc/<file_name>` with `rfc = "<rfc_id>"`, extra frontmatter lines, and
+/// a body following the `# RFC <rfc_id>: <title>` heading.
+fn write_rfc(root: &Path, file_name: &str, rfc_id: &str, title: &str, extra: &str, body: &str) {
+    fs::write(
+        root.join("rfc").join(file_name),
+        format!(
+            "+++\nrfc = \"{rfc_id}\"\ntitle = \"{title}\"\nauthors = [\"Roger\"]\ncreated = \"2025-01-01T00:00:00Z\"\nlast_updated = \"2025-01-01T00:00:00Z\"\n{extra}+++\n\n# RFC {rfc_id}: {title}\n{body}"
+        ),
+    )
+    .expect("failed to write RFC");
+}
+
+/// Make `root` a crate whose project template expects no `##` sections.
+fn write_sectionless_template(root: &Path) {
+    write_package_manifest(root);
+    fs::write(
+        root.join("rfc/0000-template.md"),
+        "+++\nrfc = \"{{ rfc_id }}\"\ntitle = \"{{ title }}\"\n+++\n\n# RFC {{ rfc_id }}: {{ title }}\n",
+    )
+    .expect("failed to write template");
+}
+
 fn write_package_manifest(root: &Path) {
     fs::write(
         root.join("Cargo.toml"),
@@ -69,6 +91,7 @@ fn create_mode_writes_expected_metadata_and_heading() {
         "DISC-123",
         "--tracking_issue",
         "ISSUE-42",
+        "--allow-dangling-refs",
         "--prerequisite",
         "0000",
         "--supersedes",
@@ -109,6 +132,39 @@ fn create_mode_writes_expected_metadata_and_heading() {
     assert!(content.contains("## Future possibilities"));
 }
 
+#[test]
+fn abstract_is_rendered_into_metadata_and_summary() {
+    let workspace = TestWorkspace::new("abstract");
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--abstract",
+        "Adds \"quoted\" parsing.",
+        "Abstract RFC",
+    ]);
+    assert!(
+        create.status.success(),
+        "command failed:\n{}",
+        output_stderr(&create)
+    );
+
+    let file = workspace.path().join("rfc/0001-abstract-rfc.md");
+    let content = fs::read_to_string(&file).expect("failed to read created RFC");
+    assert!(content.contains("abstract = \"Adds \\\"quoted\\\" parsing.\""));
+    assert!(content.contains("## Summary\n\nAdds \"quoted\" parsing.\n"));
+
+    let revise = workspace.run_rfc_revise(&["--abstract", "Replaced abstract.", "1"]);
+    assert!(
+        revise.status.success(),
+        "revision failed:\n{}",
+        output_stderr(&revise)
+    );
+    let content = fs::read_to_string(&file).expect("failed to read revised RFC");
+    assert!(content.contains("abstract = \"Replaced abstract.\""));
+    assert!(content.contains("## Summary\n\nReplaced abstract.\n\n## Motivation"));
+    assert!(!content.contains("quoted"));
+}
+
 #[test]
 fn create_mode_resolves_title_references_to_rfc_ids() {
     let workspace = TestWorkspace::new("title-references");
@@ -240,6 +296,7 @@ fn revision_mode_appends_lists_overwrites_fields_and_adds_revision_entry() {
         "DISC-999",
         "--tracking_issue",
         "ISSUE-999",
+        "--allow-dangling-refs",
         "--prerequisite",
         "0000",
         "--supersedes",
@@ -278,430 +335,3665 @@ fn revision_mode_appends_lists_overwrites_fields_and_adds_revision_entry() {
 }
 
 #[test]
-fn revision_mode_accepts_numeric_selector_as_rfc_id() {
-    let workspace = TestWorkspace::new("revision-id-selector");
-    let create = workspace.run_rfc_new(&["--author", "Roger", "Original RFC"]);
+fn configured_id_width_pads_new_rfc_ids() {
+    let workspace = TestWorkspace::new("configured-id-width");
+    fs::write(workspace.path().join(".agx.toml"), "[rfc]\nid_width = 5\n")
+        .expect("failed to write .agx.toml");
+
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Wide RFC"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let path = workspace.path().join("rfc/00001-wide-rfc.md");
+    let content = fs::read_to_string(&path).expect("failed to read created RFC");
+    assert!(content.contains("rfc = \"00001\"\n"));
+    assert!(content.contains("# RFC 00001: Wide RFC"));
+
+    let next = workspace.run_rfc_new(&["--author", "Roger", "--prerequisite", "1", "Next RFC"]);
+    assert!(next.status.success(), "{}", output_stderr(&next));
+    assert!(workspace.path().join("rfc/00002-next-rfc.md").is_file());
+
+    let reserved = workspace.run_rfc_new(&["--author", "Roger", "--id", "12345", "Reserved"]);
+    assert!(reserved.status.success(), "{}", output_stderr(&reserved));
+    assert!(workspace.path().join("rfc/12345-reserved.md").is_file());
+    let too_wide = workspace.run_rfc_new(&["--author", "Roger", "--id", "123456", "Too wide"]);
+    assert!(!too_wide.status.success());
+    assert!(output_stderr(&too_wide).contains("does not fit in 5 digits"));
+
+    let revise = workspace.run_rfc_revise(&["--author", "Alice", "1"]);
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+    let validate = workspace.run_rfc(&["validate"]);
+    assert!(validate.status.success(), "{}", output_stderr(&validate));
+}
+
+#[test]
+fn configured_id_width_is_used_when_rendering_ids() {
+    let workspace = TestWorkspace::new("configured-id-width-render");
+    fs::write(workspace.path().join(".agx.toml"), "[rfc]\nid_width = 5\n")
+        .expect("failed to write .agx.toml");
+    let first = workspace.run_rfc_new(&["--author", "Roger", "Wide RFC"]);
+    assert!(first.status.success(), "{}", output_stderr(&first));
+    let second = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--link-inline",
+        "--abstract",
+        "Extends [[1]].",
+        "Follow-up RFC",
+    ]);
+    assert!(second.status.success(), "{}", output_stderr(&second));
+    let second_path = workspace.path().join("rfc/00002-follow-up-rfc.md");
+    let content = fs::read_to_string(&second_path).expect("failed to read second RFC");
+    assert!(
+        content.contains("[RFC 00001](00001-wide-rfc.md)"),
+        "{content}"
+    );
+
+    let env = workspace.run_rfc(&["env", "2"]);
+    assert!(env.status.success(), "{}", output_stderr(&env));
+    assert!(output_stdout(&env).contains("AGX_RFC_ID=00002\n"));
+
+    let index = workspace.run_rfc(&["index", "--stdout"]);
+    assert!(index.status.success(), "{}", output_stderr(&index));
+    let index = output_stdout(&index);
+    assert!(
+        index.contains("| 00001 | [Wide RFC](00001-wide-rfc.md) |"),
+        "{index}"
+    );
+
+    let graph = workspace.run_rfc(&["graph"]);
+    assert!(graph.status.success(), "{}", output_stderr(&graph));
+    assert!(output_stdout(&graph).contains("rfc00002 -> rfc00001 [label=\"prerequisite\"]"));
+
+    let cycle = workspace.run_rfc_revise(&["--prerequisite", "2", "1"]);
+    assert!(!cycle.status.success(), "cycle unexpectedly accepted");
+    assert!(
+        output_stderr(&cycle).contains("00001 -> 00002 -> 00001"),
+        "{}",
+        output_stderr(&cycle)
+    );
+}
+
+#[test]
+fn revision_entries_use_configured_change_defaults() {
+    let workspace = TestWorkspace::new("configured-change-defaults");
+    fs::write(
+        workspace.path().join(".agx.toml"),
+        "[rfc]\ninitial_change = \"Created\"\nrevised_change = \"Updated\"\n",
+    )
+    .expect("failed to write .agx.toml");
+
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Configured RFC"]);
     assert!(
         create.status.success(),
         "initial create failed:\n{}",
         output_stderr(&create)
     );
-
-    let revise = workspace.run_rfc_revise(&["1"]);
+    let revise = workspace.run_rfc_revise(&["--force-revision", "1"]);
     assert!(
         revise.status.success(),
         "revision failed:\n{}",
         output_stderr(&revise)
     );
-    assert_eq!(output_stdout(&revise).trim(), "rfc/0001-original-rfc.md");
 
-    let file = workspace.path().join("rfc/0001-original-rfc.md");
+    let file = workspace.path().join("rfc/0001-configured-rfc.md");
     let content = fs::read_to_string(file).expect("failed to read revised RFC");
-    assert!(content.contains("change = \"Revised\""));
-}
-
-#[test]
-fn create_mode_requires_some_title_input() {
-    let workspace = TestWorkspace::new("missing-title");
-    let output = workspace.run_rfc_new(&["--author", "Roger"]);
-
-    assert!(!output.status.success(), "command unexpectedly succeeded");
-    assert!(output_stderr(&output).contains("missing <title>"));
+    assert!(content.contains("change = \"Created\""));
+    assert!(content.contains("change = \"Updated\""));
+    assert!(!content.contains("change = \"Initial draft\""));
+    assert!(!content.contains("change = \"Revised\""));
 }
 
 #[test]
-fn rfc_new_help_output_includes_command_docs_and_examples() {
-    let workspace = TestWorkspace::new("help-output");
-    let output = workspace.run_rfc(&["new", "--help"]);
+fn multiline_arrays_config_formats_lists_and_revise_preserves_it() {
+    let workspace = TestWorkspace::new("multiline-arrays");
+    fs::write(
+        workspace.path().join(".agx.toml"),
+        "[rfc]\nmultiline_arrays = true\nsort_authors = true\n",
+    )
+    .expect("failed to write .agx.toml");
 
+    let create = workspace.run_rfc_new(&["--author", "Zed", "--author", "Amy", "Listed RFC"]);
     assert!(
-        output.status.success(),
-        "help command failed:\n{}",
-        output_stderr(&output)
+        create.status.success(),
+        "initial create failed:\n{}",
+        output_stderr(&create)
+    );
+    let file = workspace.path().join("rfc/0001-listed-rfc.md");
+    let content = fs::read_to_string(&file).expect("failed to read created RFC");
+    assert!(
+        content.contains("authors = [\n    \"Amy\",\n    \"Zed\",\n]"),
+        "authors were not rendered multi-line:\n{content}"
     );
 
-    let help = output_stdout(&output);
-    assert!(help.contains("Create a new RFC markdown file with TOML metadata."));
-    assert!(help.contains("Creates a new RFC file from `rfc/0000-template.md`"));
-    assert!(help.contains("Add an author to metadata."));
-    assert!(help.contains("List prerequisite RFC references (id or title)"));
-    assert!(help.contains("Examples:"));
-    assert!(help.contains("agx rfc new --author Roger --title \"Add parser support\""));
+    let revise = workspace.run_rfc_revise(&[
+        "--author",
+        "Bob",
+        "--allow-dangling-refs",
+        "--prerequisite",
+        "7",
+        "1",
+    ]);
+    assert!(
+        revise.status.success(),
+        "revision failed:\n{}",
+        output_stderr(&revise)
+    );
+    let content = fs::read_to_string(&file).expect("failed to read revised RFC");
+    assert!(
+        content.contains("authors = [\n    \"Amy\",\n    \"Bob\",\n    \"Zed\",\n]"),
+        "revise did not preserve multi-line authors:\n{content}"
+    );
+    assert!(content.contains("prerequisite = [\n    7,\n]"));
 }
 
 #[test]
-fn root_help_lists_rfc_init_and_skill_subcommands() {
-    let workspace = TestWorkspace::new("root-help-output");
-    let output = workspace.run_cli(&["--help"]);
+fn revision_mode_latest_selector_targets_highest_numbered_rfc() {
+    let workspace = TestWorkspace::new("revise-latest");
+    for title in ["First RFC", "Second RFC"] {
+        let create = workspace.run_rfc_new(&["--author", "Roger", title]);
+        assert!(create.status.success(), "{}", output_stderr(&create));
+    }
 
+    let revise = workspace.run_rfc_revise(&["--title", "Second RFC Revised", "latest"]);
     assert!(
-        output.status.success(),
-        "help command failed:\n{}",
-        output_stderr(&output)
+        revise.status.success(),
+        "revision failed:\n{}",
+        output_stderr(&revise)
     );
 
-    let help = output_stdout(&output);
-    assert!(help.contains("Manage agent workflow tooling"));
-    assert!(help.contains("\n  rfc "));
-    assert!(help.contains("\n  skill "));
-    assert!(!help.contains("\n  init "));
+    let second = fs::read_to_string(workspace.path().join("rfc/0002-second-rfc.md"))
+        .expect("failed to read second RFC");
+    assert!(second.contains("# RFC 0002: Second RFC Revised"));
+    let first = fs::read_to_string(workspace.path().join("rfc/0001-first-rfc.md"))
+        .expect("failed to read first RFC");
+    assert!(!first.contains("Revised"));
 }
 
 #[test]
-fn create_mode_prefers_template_from_crate_root() {
-    let workspace = TestWorkspace::new("crate-template");
+fn configured_sections_replace_embedded_template_body() {
+    let workspace = TestWorkspace::new("configured-sections");
     fs::write(
-        workspace.path().join("Cargo.toml"),
-        "[package]\nname = \"crate-template\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+        workspace.path().join(".agx.toml"),
+        "[rfc]\nsections = [\"Summary\", \"Motivation\", \"Open Issues\"]\n",
     )
-    .expect("failed to write crate manifest");
-    write_template(
-        &workspace.path().join("rfc/0000-template.md"),
-        "crate-root-template",
-    );
+    .expect("failed to write .agx.toml");
 
-    let output = workspace.run_rfc_new(&["--author", "Roger", "Crate Template"]);
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Sectioned RFC"]);
     assert!(
-        output.status.success(),
-        "command failed:\n{}",
-        output_stderr(&output)
+        create.status.success(),
+        "initial create failed:\n{}",
+        output_stderr(&create)
     );
 
-    let file = workspace.path().join("rfc/0001-crate-template.md");
-    let content = fs::read_to_string(file).expect("failed to read created RFC");
-    assert!(content.contains("crate-root-template"));
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-sectioned-rfc.md"))
+        .expect("failed to read created RFC");
+    let headings = content
+        .lines()
+        .filter(|line| line.starts_with("## "))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        headings,
+        vec!["## Summary", "## Motivation", "## Open Issues"]
+    );
+    assert!(content.contains("# RFC 0001: Sectioned RFC"));
 }
 
 #[test]
-fn create_mode_prefers_workspace_root_template_from_member_crate() {
-    let workspace = TestWorkspace::new("workspace-template");
-    fs::write(
-        workspace.path().join("Cargo.toml"),
-        "[workspace]\nmembers = [\"crates/member\"]\nresolver = \"2\"\n",
-    )
-    .expect("failed to write workspace manifest");
-    fs::create_dir_all(workspace.path().join("crates/member/rfc"))
-        .expect("failed to create member rfc directory");
-    fs::write(
-        workspace.path().join("crates/member/Cargo.toml"),
-        "[package]\nname = \"member\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
-    )
-    .expect("failed to write member manifest");
-    write_template(
-        &workspace.path().join("rfc/0000-template.md"),
-        "workspace-root-template",
-    );
-
-    let output = workspace.run_rfc_new_in("crates/member", &["--author", "Roger", "Workspace RFC"]);
+fn no_template_writes_metadata_and_heading_only() {
+    let workspace = TestWorkspace::new("no-template");
+    let create = workspace.run_rfc_new(&[
+        "--no-template",
+        "--author",
+        "Roger",
+        "--allow-dangling-refs",
+        "--prerequisite",
+        "7",
+        "Quick Draft",
+    ]);
     assert!(
-        output.status.success(),
-        "command failed:\n{}",
-        output_stderr(&output)
+        create.status.success(),
+        "create failed:\n{}",
+        output_stderr(&create)
     );
 
-    let file = workspace
-        .path()
-        .join("crates/member/rfc/0001-workspace-rfc.md");
-    let content = fs::read_to_string(file).expect("failed to read created RFC");
-    assert!(content.contains("workspace-root-template"));
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-quick-draft.md"))
+        .expect("failed to read created RFC");
+    assert!(content.starts_with("+++\nrfc = \"0001\"\ntitle = \"Quick Draft\"\n"));
+    assert!(content.contains("authors = [\"Roger\"]"));
+    assert!(content.contains("prerequisite = [7]"));
+    assert!(content.contains("[[revision]]"));
+    assert!(content.contains("change = \"Initial draft\""));
+    assert!(content.ends_with("+++\n\n# RFC 0001: Quick Draft\n"));
+    assert!(!content.contains("## "));
 }
 
 #[test]
-fn member_crate_resolves_reference_titles_from_workspace_rfc_directory() {
-    let workspace = TestWorkspace::new("workspace-reference-resolution");
-    fs::write(
-        workspace.path().join("Cargo.toml"),
-        "[workspace]\nmembers = [\"crates/member\"]\nresolver = \"2\"\n",
-    )
-    .expect("failed to write workspace manifest");
-    fs::create_dir_all(workspace.path().join("crates/member/rfc"))
-        .expect("failed to create member rfc directory");
-    fs::write(
-        workspace.path().join("crates/member/Cargo.toml"),
-        "[package]\nname = \"member\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
-    )
-    .expect("failed to write member manifest");
+fn create_mode_id_override_backfills_reserved_number() {
+    let workspace = TestWorkspace::new("create-id-override");
+    for title in ["First RFC", "Second RFC"] {
+        let create = workspace.run_rfc_new(&["--author", "Roger", title]);
+        assert!(create.status.success(), "{}", output_stderr(&create));
+    }
 
-    let base = workspace.run_rfc_new(&["--author", "Roger", "--title", "Workspace Base"]);
+    let create = workspace.run_rfc_new(&["--author", "Roger", "--id", "0005", "Reserved RFC"]);
     assert!(
-        base.status.success(),
-        "base create failed:\n{}",
-        output_stderr(&base)
+        create.status.success(),
+        "create with --id failed:\n{}",
+        output_stderr(&create)
     );
+    let content = fs::read_to_string(workspace.path().join("rfc/0005-reserved-rfc.md"))
+        .expect("failed to read RFC 0005");
+    assert!(content.contains("rfc = \"0005\""));
+    assert!(content.contains("# RFC 0005: Reserved RFC"));
 
-    let output = workspace.run_rfc_new_in(
-        "crates/member",
-        &[
-            "--author",
-            "Roger",
-            "--prerequisite",
-            "Workspace Base",
-            "--title",
-            "Member RFC",
-        ],
-    );
+    let duplicate = workspace.run_rfc_new(&["--author", "Roger", "--id", "2", "Another RFC"]);
     assert!(
-        output.status.success(),
-        "command failed:\n{}",
-        output_stderr(&output)
+        !duplicate.status.success(),
+        "duplicate id unexpectedly accepted"
     );
-
-    let file = workspace
-        .path()
-        .join("crates/member/rfc/0001-member-rfc.md");
-    let content = fs::read_to_string(file).expect("failed to read created RFC");
-    assert!(content.contains("prerequisite = [1]"));
+    assert!(output_stderr(&duplicate).contains("RFC 0002 already exists"));
 }
 
 #[test]
-fn create_mode_prefers_workspace_template_over_member_template() {
-    let workspace = TestWorkspace::new("workspace-precedence");
-    fs::write(
-        workspace.path().join("Cargo.toml"),
-        "[workspace]\nmembers = [\"crates/member\"]\nresolver = \"2\"\n",
-    )
-    .expect("failed to write workspace manifest");
-    fs::create_dir_all(workspace.path().join("crates/member/rfc"))
-        .expect("failed to create member rfc directory");
-    fs::write(
-        workspace.path().join("crates/member/Cargo.toml"),
-        "[package]\nname = \"member\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
-    )
-    .expect("failed to write member manifest");
-    write_template(
-        &workspace.path().join("rfc/0000-template.md"),
-        "workspace-root-template",
-    );
-    write_template(
-        &workspace.path().join("crates/member/rfc/0000-template.md"),
-        "member-template",
-    );
+fn revision_entries_record_revising_author() {
+    let workspace = TestWorkspace::new("revision-by");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Attributed RFC"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
 
-    let output =
-        workspace.run_rfc_new_in("crates/member", &["--author", "Roger", "Workspace Wins"]);
+    let revise = workspace.run_rfc_revise(&["--author", "Alice", "1"]);
     assert!(
-        output.status.success(),
-        "command failed:\n{}",
-        output_stderr(&output)
+        revise.status.success(),
+        "revision failed:\n{}",
+        output_stderr(&revise)
     );
 
-    let file = workspace
-        .path()
-        .join("crates/member/rfc/0001-workspace-wins.md");
-    let content = fs::read_to_string(file).expect("failed to read created RFC");
-    assert!(content.contains("workspace-root-template"));
-    assert!(!content.contains("member-template"));
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-attributed-rfc.md"))
+        .expect("failed to read revised RFC");
+    assert!(content.contains("change = \"Initial draft\"\nby = \"Roger\"\n"));
+    assert!(content.contains("change = \"Revised\"\nby = \"Alice\"\n"));
 }
 
 #[test]
-fn create_mode_falls_back_to_embedded_template_when_project_template_missing() {
-    let workspace = TestWorkspace::new("embedded-template");
-    fs::remove_file(workspace.path().join("rfc/0000-template.md"))
-        .expect("failed to remove seeded template");
+fn revise_records_latest_author_as_updated_by() {
+    let workspace = TestWorkspace::new("updated-by");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Audited RFC"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let path = workspace.path().join("rfc/0001-audited-rfc.md");
+    let content = fs::read_to_string(&path).expect("failed to read created RFC");
+    assert!(!content.contains("updated_by"));
 
-    let output = workspace.run_rfc_new(&["--author", "Roger", "Embedded Template"]);
-    assert!(
+    for author in ["Alice", "Bob"] {
+        let revise = workspace.run_rfc_revise(&["--author", author, "1"]);
+        assert!(revise.status.success(), "{}", output_stderr(&revise));
+    }
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert!(content.contains("updated_by = \"Bob\"\n"), "{content}");
+    assert_eq!(content.matches("updated_by").count(), 1);
+
+    let list = workspace.run_rfc(&["list", "--format", "json"]);
+    let payload: Value = serde_json::from_str(&output_stdout(&list)).expect("invalid JSON");
+    assert_eq!(payload["rfcs"][0]["updated_by"], "Bob");
+}
+
+#[test]
+fn labels_are_deduped_and_custom_frontmatter_survives_revise() {
+    let workspace = TestWorkspace::new("labels");
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--label",
+        "api",
+        "--label",
+        "api",
+        "Labeled RFC",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let path = workspace.path().join("rfc/0001-labeled-rfc.md");
+    let content = fs::read_to_string(&path).expect("failed to read created RFC");
+    assert!(content.contains("labels = [\"api\"]\n"), "{content}");
+
+    let custom = "team = \"core\"  # owning team\n\n[custom]\nreviewers = [\"alice\", \"bob\"]\nweight = 3\n\n";
+    let content = content.replacen("[[revision]]", &format!("{custom}[[revision]]"), 1);
+    fs::write(&path, content).expect("failed to add custom frontmatter");
+
+    let revise = workspace.run_rfc_revise(&["--label", "security", "--label", "api", "1"]);
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert!(
+        content.contains("labels = [\"api\", \"security\"]\n"),
+        "{content}"
+    );
+    assert!(content.contains(custom), "{content}");
+}
+
+#[test]
+fn rfc_list_filters_by_label_case_insensitively() {
+    let workspace = TestWorkspace::new("list-labels");
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--label",
+        "parser",
+        "--label",
+        "frontend",
+        "--label",
+        "parser",
+        "Parser RFC",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-parser-rfc.md"))
+        .expect("failed to read created RFC");
+    assert!(
+        content.contains("labels = [\"parser\", \"frontend\"]\n"),
+        "{content}"
+    );
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--no-template",
+        "--label",
+        "docs",
+        "Docs RFC",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+
+    let list = workspace.run_rfc(&["list", "--label", "PARSER", "--format", "json"]);
+    assert!(list.status.success(), "{}", output_stderr(&list));
+    let payload: Value = serde_json::from_str(&output_stdout(&list)).expect("invalid JSON");
+    let rfcs = payload["rfcs"].as_array().expect("rfcs should be an array");
+    assert_eq!(rfcs.len(), 1);
+    assert_eq!(rfcs[0]["rfc"], 1);
+    assert_eq!(rfcs[0]["labels"], serde_json::json!(["parser", "frontend"]));
+
+    let list = workspace.run_rfc(&["list", "--label", "docs"]);
+    let stdout = output_stdout(&list);
+    assert!(stdout.contains("0002\tDocs RFC"), "{stdout}");
+    assert!(!stdout.contains("Parser RFC"), "{stdout}");
+
+    let list = workspace.run_rfc(&["list", "--label", "unknown", "--format", "json"]);
+    assert!(list.status.success(), "{}", output_stderr(&list));
+    let payload: Value = serde_json::from_str(&output_stdout(&list)).expect("invalid JSON");
+    assert_eq!(payload["rfcs"], serde_json::json!([]));
+}
+
+#[test]
+fn revise_preserves_crlf_line_endings() {
+    let workspace = TestWorkspace::new("revise-crlf");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Windows RFC"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let path = workspace.path().join("rfc/0001-windows-rfc.md");
+    let content = fs::read_to_string(&path).expect("failed to read created RFC");
+    fs::write(&path, content.replace('\n', "\r\n")).expect("failed to write CRLF RFC");
+
+    let revise = workspace.run_rfc_revise(&["--author", "Alice", "--abstract", "Summary.", "1"]);
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert!(
+        content.contains("updated_by = \"Alice\"\r\n"),
+        "{content:?}"
+    );
+    assert_eq!(
+        content.matches('\n').count(),
+        content.matches("\r\n").count(),
+        "{content:?}"
+    );
+}
+
+#[test]
+fn revise_without_changes_skips_revision_unless_forced() {
+    let workspace = TestWorkspace::new("revise-noop");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Stable RFC"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let path = workspace.path().join("rfc/0001-stable-rfc.md");
+    let original = fs::read_to_string(&path).expect("failed to read created RFC");
+
+    let revise = workspace.run_rfc_revise(&["1"]);
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+    assert!(
+        output_stdout(&revise).contains(
+            "log: no changes to rfc/0001-stable-rfc.md; pass --force to record a revision anyway"
+        ),
+        "{}",
+        output_stdout(&revise)
+    );
+    let help = workspace.run_rfc(&["revise", "--help"]);
+    assert!(
+        output_stdout(&help)
+            .contains("`--force` implies both `--allow-backward` and `--force-revision`"),
+        "{}",
+        output_stdout(&help)
+    );
+    let revise = workspace.run_rfc_revise(&["--author", "Roger", "1"]);
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+    assert_eq!(
+        fs::read_to_string(&path).expect("failed to read RFC"),
+        original
+    );
+
+    let transitions_only = workspace.run_rfc_revise(&["--allow-backward", "1"]);
+    assert!(
+        transitions_only.status.success(),
+        "{}",
+        output_stderr(&transitions_only)
+    );
+    assert_eq!(
+        fs::read_to_string(&path).expect("failed to read RFC"),
+        original
+    );
+
+    let forced = workspace.run_rfc_revise(&["--force", "1"]);
+    assert!(forced.status.success(), "{}", output_stderr(&forced));
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert_eq!(content.matches("[[revision]]").count(), 2, "{content}");
+
+    let forced_revision = workspace.run_rfc_revise(&["--force-revision", "1"]);
+    assert!(
+        forced_revision.status.success(),
+        "{}",
+        output_stderr(&forced_revision)
+    );
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert_eq!(content.matches("[[revision]]").count(), 3, "{content}");
+}
+
+#[test]
+fn fixed_dates_pin_metadata_and_revision_timestamps() {
+    let workspace = TestWorkspace::new("fixed-date");
+    let create = workspace.run_cli_with_env(
+        &["rfc", "new", "--author", "Roger", "Pinned RFC"],
+        &[("AGX_NOW", "2024-02-03T04:05:06+01:00")],
+    );
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let path = workspace.path().join("rfc/0001-pinned-rfc.md");
+    let content = fs::read_to_string(&path).expect("failed to read created RFC");
+    assert!(content.contains("created = \"2024-02-03T03:05:06Z\"\n"));
+    assert!(content.contains("last_updated = \"2024-02-03T03:05:06Z\"\n"));
+    assert!(content.contains("date = \"2024-02-03T03:05:06Z\"\n"));
+
+    let revise =
+        workspace.run_rfc_revise(&["--force-revision", "--date", "2024-05-06T07:08:09Z", "1"]);
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert_eq!(
+        last_updated_timestamp(&content).as_deref(),
+        Some("2024-05-06T07:08:09Z")
+    );
+    assert_eq!(
+        latest_revision_timestamp(&content).as_deref(),
+        Some("2024-05-06T07:08:09Z")
+    );
+
+    let revise = workspace.run_cli_with_env(
+        &["rfc", "revise", "--force-revision", "1"],
+        &[("SOURCE_DATE_EPOCH", "0")],
+    );
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert_eq!(
+        last_updated_timestamp(&content).as_deref(),
+        Some("1970-01-01T00:00:00Z")
+    );
+
+    let invalid = workspace.run_rfc_revise(&["--force-revision", "--date", "yesterday", "1"]);
+    assert!(!invalid.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&invalid).contains("invalid --date"));
+}
+
+#[test]
+fn status_shortcuts_set_status_and_reject_conflicting_status() {
+    let workspace = TestWorkspace::new("status-shortcuts");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "--draft", "Shortcut RFC"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let path = workspace.path().join("rfc/0001-shortcut-rfc.md");
+    let content = fs::read_to_string(&path).expect("failed to read created RFC");
+    assert!(content.contains("status = \"draft\"\n"));
+
+    let revise = workspace.run_rfc_revise(&["--final", "1"]);
+    assert!(
+        revise.status.success(),
+        "revision failed:\n{}",
+        output_stderr(&revise)
+    );
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert!(content.contains("status = \"final\"\n"));
+    assert!(!content.contains("status = \"draft\""));
+
+    let conflict = workspace.run_rfc_new(&["--final", "--status", "draft", "Conflicting RFC"]);
+    assert!(!conflict.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&conflict).contains("`--final` conflicts with `--status draft`"));
+}
+
+#[test]
+fn status_defaults_to_draft_and_rejects_backward_transitions() {
+    let workspace = TestWorkspace::new("status-transitions");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Lifecycle RFC"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let path = workspace.path().join("rfc/0001-lifecycle-rfc.md");
+    let content = fs::read_to_string(&path).expect("failed to read created RFC");
+    assert!(content.contains("status = \"draft\"\n"));
+
+    let unknown = workspace.run_rfc_new(&["--status", "pending", "Unknown status"]);
+    assert!(!unknown.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&unknown).contains("unknown status `pending`"));
+
+    let accept = workspace.run_rfc_revise(&["--status", "accepted", "1"]);
+    assert!(accept.status.success(), "{}", output_stderr(&accept));
+
+    let backward = workspace.run_rfc_revise(&["--allow-frozen", "--status", "draft", "1"]);
+    assert!(!backward.status.success(), "command unexpectedly succeeded");
+    assert!(
+        output_stderr(&backward)
+            .contains("cannot change status from `accepted` to `draft`; pass --force")
+    );
+    let content = fs::read_to_string(&path).expect("failed to read RFC");
+    assert!(content.contains("status = \"accepted\"\n"));
+
+    let forced = workspace.run_rfc_revise(&["--allow-frozen", "--force", "--status", "draft", "1"]);
+    assert!(forced.status.success(), "{}", output_stderr(&forced));
+    let content = fs::read_to_string(&path).expect("failed to read RFC");
+    assert!(content.contains("status = \"draft\"\n"));
+
+    let propose = workspace.run_rfc_revise(&["--status", "proposed", "1"]);
+    assert!(propose.status.success(), "{}", output_stderr(&propose));
+    let allowed = workspace.run_rfc_revise(&["--allow-backward", "--status", "draft", "1"]);
+    assert!(allowed.status.success(), "{}", output_stderr(&allowed));
+    let content = fs::read_to_string(&path).expect("failed to read RFC");
+    assert!(content.contains("status = \"draft\"\n"));
+}
+
+#[test]
+fn revise_removes_authors_and_agents_but_keeps_one_author() {
+    let workspace = TestWorkspace::new("revise-remove-authors");
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--author",
+        "Alice",
+        "--agent",
+        "codex",
+        "Shared RFC",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let path = workspace.path().join("rfc/0001-shared-rfc.md");
+
+    let remove = workspace.run_rfc_revise(&[
+        "--remove-author",
+        "Alice",
+        "--remove-agent",
+        "codex",
+        "--remove-author",
+        "Nobody",
+        "1",
+    ]);
+    assert!(remove.status.success(), "{}", output_stderr(&remove));
+    assert!(output_stderr(&remove).contains("`authors` does not list `Nobody`; nothing to remove"));
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert!(content.contains("authors = [\"Roger\"]\n"));
+    assert!(!content.contains("agents"));
+
+    let last = workspace.run_rfc_revise(&["--remove-author", "Roger", "1"]);
+    assert!(!last.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&last).contains("cannot remove the last author"));
+    let content = fs::read_to_string(&path).expect("failed to read RFC");
+    assert!(content.contains("authors = [\"Roger\"]\n"));
+}
+
+#[test]
+fn from_issue_sets_tracking_issue_and_needs_a_title_offline() {
+    let workspace = TestWorkspace::new("from-issue");
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--from-issue",
+        "ISSUE-42",
+        "Issue driven RFC",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-issue-driven-rfc.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("tracking_issue = \"ISSUE-42\"\n"));
+
+    let untitled = workspace.run_rfc_new(&["--author", "Roger", "--from-issue", "ISSUE-42"]);
+    assert!(!untitled.status.success(), "command unexpectedly succeeded");
+    let stderr = output_stderr(&untitled);
+    assert!(stderr.contains("`--from-issue ISSUE-42` only sets `tracking_issue`"));
+    assert!(stderr.contains("--fetch"));
+
+    let both = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--from-issue",
+        "ISSUE-42",
+        "--tracking_issue",
+        "ISSUE-7",
+        "Ambiguous issue",
+    ]);
+    assert!(!both.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&both).contains("cannot be used with"));
+    assert!(
+        !workspace
+            .path()
+            .join("rfc/0002-ambiguous-issue.md")
+            .exists()
+    );
+}
+
+#[test]
+fn revise_change_records_custom_revision_message() {
+    let workspace = TestWorkspace::new("revise-change");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Change RFC"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+
+    let revise = workspace.run_rfc_revise(&[
+        "--change",
+        "Incorporated \"security\" feedback\nfrom review",
+        "1",
+    ]);
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+    let path = workspace.path().join("rfc/0001-change-rfc.md");
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert!(
+        content.contains("change = \"Incorporated \\\"security\\\" feedback\\nfrom review\"\n")
+    );
+
+    let validate = workspace.run_rfc(&["validate"]);
+    assert!(validate.status.success(), "{}", output_stderr(&validate));
+    let default = workspace.run_rfc_revise(&["--force-revision", "1"]);
+    assert!(default.status.success(), "{}", output_stderr(&default));
+    let content = fs::read_to_string(&path).expect("failed to read revised RFC");
+    assert!(content.contains("change = \"Revised\"\n"));
+}
+
+#[test]
+fn revision_body_file_replaces_body_and_keeps_metadata() {
+    let workspace = TestWorkspace::new("revision-body-file");
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--discussion",
+        "https://example.com/d/1",
+        "Body RFC",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    fs::write(
+        workspace.path().join("regenerated.md"),
+        "# RFC 9999: Stale heading\n\n## Summary\n\nRegenerated body.\n",
+    )
+    .expect("failed to write body file");
+
+    let revise = workspace.run_rfc_revise(&["--body-file", "regenerated.md", "1"]);
+    assert!(
+        revise.status.success(),
+        "revision failed:\n{}",
+        output_stderr(&revise)
+    );
+
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-body-rfc.md"))
+        .expect("failed to read revised RFC");
+    assert!(content.contains("discussion = \"https://example.com/d/1\""));
+    assert!(content.contains("change = \"Revised\""));
+    assert!(content.ends_with("+++\n\n# RFC 0001: Body RFC\n\n## Summary\n\nRegenerated body.\n"));
+    assert!(!content.contains("## Motivation"));
+
+    fs::write(workspace.path().join("blank.md"), "\n  \n").expect("failed to write body file");
+    let blank = workspace.run_rfc_revise(&["--body-file", "blank.md", "1"]);
+    assert!(!blank.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&blank).contains("is empty"));
+}
+
+#[test]
+fn conflicting_reference_fields_warn_and_fail_under_strict() {
+    let workspace = TestWorkspace::new("conflicting-references");
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--allow-dangling-refs",
+        "--prerequisite",
+        "9",
+        "--supersedes",
+        "9",
+        "Conflicted references",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    assert!(
+        output_stderr(&create)
+            .contains("warning: RFC 0009 is listed in both `prerequisite` and `supersedes`")
+    );
+
+    let strict = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--strict",
+        "--allow-dangling-refs",
+        "--prerequisite",
+        "1",
+        "--supersedes",
+        "1",
+        "Strict references",
+    ]);
+    assert!(!strict.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&strict).contains("listed in both `prerequisite` and `supersedes`"));
+    assert!(
+        !workspace
+            .path()
+            .join("rfc/0002-strict-references.md")
+            .exists()
+    );
+}
+
+#[test]
+fn create_mode_escapes_control_characters_in_title_and_author() {
+    let workspace = TestWorkspace::new("create-control-characters");
+    let create = workspace.run_rfc_new(&["--author", "Ro\u{7}ger", "Tabbed\ttitle\rhere"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-tabbed-title-here.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("title = \"Tabbed\\ttitle\\rhere\""));
+    assert!(content.contains("authors = [\"Ro\\u0007ger\"]"));
+
+    let frontmatter = content
+        .strip_prefix("+++\n")
+        .and_then(|rest| rest.split_once("\n+++\n"))
+        .map(|(frontmatter, _)| frontmatter)
+        .expect("frontmatter should be delimited");
+    let metadata = frontmatter
+        .parse::<toml_edit::DocumentMut>()
+        .expect("frontmatter should parse");
+    assert_eq!(metadata["title"].as_str(), Some("Tabbed\ttitle\rhere"));
+    assert_eq!(metadata["authors"][0].as_str(), Some("Ro\u{7}ger"));
+}
+
+#[test]
+fn retitle_warns_about_stale_slug_unless_keep_filename_or_rename() {
+    let workspace = TestWorkspace::new("retitle-filename");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Old Name"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+
+    let stale = workspace.run_rfc_revise(&["--title", "New Name", "1"]);
+    assert!(stale.status.success(), "{}", output_stderr(&stale));
+    assert_eq!(output_stdout(&stale).trim(), "rfc/0001-old-name.md");
+    assert!(output_stderr(&stale).contains("pass --rename to move it to rfc/0001-new-name.md"));
+
+    let kept = workspace.run_rfc_revise(&["--title", "Newer Name", "--keep-filename", "1"]);
+    assert!(kept.status.success(), "{}", output_stderr(&kept));
+    assert!(!output_stderr(&kept).contains("--rename"));
+    assert!(workspace.path().join("rfc/0001-old-name.md").is_file());
+
+    let renamed = workspace.run_rfc_revise(&["--title", "Final Name", "--rename", "1"]);
+    assert!(renamed.status.success(), "{}", output_stderr(&renamed));
+    assert_eq!(output_stdout(&renamed).trim(), "rfc/0001-final-name.md");
+    assert!(!workspace.path().join("rfc/0001-old-name.md").exists());
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-final-name.md"))
+        .expect("failed to read renamed RFC");
+    assert!(content.contains("# RFC 0001: Final Name"));
+}
+
+#[test]
+fn rfc_changelog_orders_revisions_across_rfcs_by_date() {
+    let workspace = TestWorkspace::new("rfc-changelog");
+    fs::write(
+        workspace.path().join("rfc/0001-first.md"),
+        "+++\nrfc = \"0001\"\ntitle = \"First\"\n[[revision]]\ndate = \"2025-01-01T00:00:00Z\"\nchange = \"Initial draft\"\nby = \"Roger\"\n[[revision]]\ndate = \"2025-03-01T00:00:00Z\"\nchange = \"Revised\"\n+++\n\n# RFC 0001: First\n",
+    )
+    .expect("failed to write RFC");
+    fs::write(
+        workspace.path().join("rfc/0002-second.md"),
+        "+++\nrfc = \"0002\"\ntitle = \"Second\"\n[[revision]]\ndate = \"2025-02-01T00:00:00Z\"\nchange = \"Initial draft\"\n+++\n\n# RFC 0002: Second\n",
+    )
+    .expect("failed to write RFC");
+
+    let json = workspace.run_rfc(&["changelog", "--format", "json"]);
+    assert!(json.status.success(), "{}", output_stderr(&json));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&json)).expect("failed to parse JSON output");
+    let entries = payload["entries"]
+        .as_array()
+        .expect("entries should be array");
+    let order = entries
+        .iter()
+        .map(|entry| (entry["rfc"].as_u64(), entry["change"].as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        order,
+        vec![
+            (Some(1), Some("Initial draft")),
+            (Some(2), Some("Initial draft")),
+            (Some(1), Some("Revised")),
+        ]
+    );
+    assert_eq!(entries[0]["by"], "Roger");
+
+    let text = workspace.run_rfc(&["changelog", "--since", "2025-02-01"]);
+    assert!(text.status.success(), "{}", output_stderr(&text));
+    assert_eq!(
+        output_stdout(&text),
+        "# Changelog\n\n## 2025-02-01\n\n- RFC 0002: Second — Initial draft\n\n## 2025-03-01\n\n- RFC 0001: First — Revised\n"
+    );
+}
+
+#[test]
+fn rfc_set_status_transitions_rfcs_matching_query() {
+    let workspace = TestWorkspace::new("rfc-set-status");
+    for (args, title) in [
+        (&["--author", "Roger", "--draft"][..], "Roger draft"),
+        (&["--author", "Roger", "--final"][..], "Roger final"),
+        (&["--author", "Alice", "--draft"][..], "Alice draft"),
+        (&["--author", "Roger", "--draft"][..], "Another Roger draft"),
+    ] {
+        let mut args = args.to_vec();
+        args.push(title);
+        let create = workspace.run_rfc_new(&args);
+        assert!(create.status.success(), "{}", output_stderr(&create));
+    }
+    let rfc_dir = workspace.path().join("rfc");
+    let read = |name: &str| fs::read_to_string(rfc_dir.join(name)).expect("failed to read RFC");
+
+    let dry_run = workspace.run_rfc(&[
+        "set-status",
+        "accepted",
+        "--where",
+        "status=draft",
+        "--author",
+        "Roger",
+        "--dry-run",
+    ]);
+    assert!(dry_run.status.success(), "{}", output_stderr(&dry_run));
+    assert!(
+        output_stdout(&dry_run)
+            .contains("would set rfc/0001-roger-draft.md: `draft` -> `accepted`")
+    );
+    assert!(read("0001-roger-draft.md").contains("status = \"draft\""));
+
+    let apply = workspace.run_rfc(&[
+        "set-status",
+        "accepted",
+        "--where",
+        "status=draft",
+        "--author",
+        "Roger",
+    ]);
+    assert!(apply.status.success(), "{}", output_stderr(&apply));
+    assert_eq!(
+        output_stdout(&apply),
+        "rfc/0001-roger-draft.md\nrfc/0004-another-roger-draft.md\n"
+    );
+    let accepted = read("0001-roger-draft.md");
+    assert!(accepted.contains("status = \"accepted\""));
+    assert!(accepted.contains("change = \"Changed status from `draft` to `accepted`\""));
+    assert!(read("0002-roger-final.md").contains("status = \"final\""));
+    assert!(read("0003-alice-draft.md").contains("status = \"draft\""));
+
+    let unfiltered = workspace.run_rfc(&["set-status", "accepted"]);
+    assert!(
+        !unfiltered.status.success(),
+        "set-status unexpectedly succeeded"
+    );
+    assert!(output_stderr(&unfiltered).contains("pass --where or --author"));
+}
+
+#[test]
+fn rfc_env_prints_shell_escaped_metadata() {
+    let workspace = TestWorkspace::new("rfc-env");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "--draft", "Roger's parser plan"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+
+    let text = workspace.run_rfc(&["env", "0001"]);
+    assert!(text.status.success(), "{}", output_stderr(&text));
+    assert_eq!(
+        output_stdout(&text),
+        "AGX_RFC_ID=0001\nAGX_RFC_TITLE='Roger'\\''s parser plan'\nAGX_RFC_STATUS=draft\nAGX_RFC_AUTHORS=Roger\nAGX_RFC_PATH=rfc/0001-rogers-parser-plan.md\n"
+    );
+
+    let json = workspace.run_rfc(&["env", "latest", "--format", "json"]);
+    assert!(json.status.success(), "{}", output_stderr(&json));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&json)).expect("failed to parse JSON output");
+    assert_eq!(payload["variables"]["AGX_RFC_TITLE"], "Roger's parser plan");
+    assert_eq!(payload["variables"]["AGX_RFC_STATUS"], "draft");
+}
+
+#[test]
+fn latest_selector_compares_ids_numerically_across_digit_widths() {
+    let workspace = TestWorkspace::new("latest-digit-boundary");
+    let root = workspace.path();
+    write_rfc(root, "9999-old.md", "9999", "Old", "", "");
+    write_rfc(root, "10000-new.md", "10000", "New", "", "");
+
+    let env = workspace.run_rfc(&["env", "latest"]);
+    assert!(env.status.success(), "{}", output_stderr(&env));
+    assert!(
+        output_stdout(&env).contains("AGX_RFC_PATH=rfc/10000-new.md\n"),
+        "{}",
+        output_stdout(&env)
+    );
+
+    let revise = workspace.run_rfc_revise(&["--abstract", "Newest.", "latest"]);
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+    let newest = fs::read_to_string(root.join("rfc/10000-new.md")).expect("failed to read RFC");
+    assert!(newest.contains("Newest."), "{newest}");
+
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--no-template",
+        "--supersedes",
+        "latest",
+        "Newer",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let created =
+        fs::read_to_string(root.join("rfc/10001-newer.md")).expect("failed to read created RFC");
+    assert!(created.contains("supersedes = [10000]\n"), "{created}");
+}
+
+#[test]
+fn rfc_show_prints_metadata_and_body() {
+    let workspace = TestWorkspace::new("rfc-show");
+    let first = workspace.run_rfc_new(&["--author", "Roger", "Base parser"]);
+    assert!(first.status.success(), "{}", output_stderr(&first));
+    let second = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--agent",
+        "codex",
+        "--prerequisite",
+        "1",
+        "Parser extensions",
+    ]);
+    assert!(second.status.success(), "{}", output_stderr(&second));
+
+    let text = workspace.run_rfc(&["show", "2"]);
+    assert!(text.status.success(), "{}", output_stderr(&text));
+    let stdout = output_stdout(&text);
+    assert!(stdout.starts_with("rfc: 0002\ntitle: Parser extensions\n"));
+    assert!(stdout.contains("\nauthors: Roger\n"));
+    assert!(stdout.contains("\nprerequisite: 1\n"));
+    assert!(stdout.contains("revisions:\n  "));
+    assert!(stdout.contains("  Initial draft (Roger)\n"));
+    assert!(stdout.contains("\n\n# RFC 0002: Parser extensions\n"));
+
+    let json = workspace.run_rfc(&["show", "0002-parser-extensions.md", "--format", "json"]);
+    assert!(json.status.success(), "{}", output_stderr(&json));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&json)).expect("failed to parse JSON output");
+    assert_eq!(payload["schema_version"], 1);
+    assert_eq!(payload["metadata"]["agents"][0], "codex");
+    assert_eq!(payload["metadata"]["prerequisite"][0], 1);
+    assert_eq!(
+        payload["metadata"]["revision"][0]["change"],
+        "Initial draft"
+    );
+    assert!(
+        payload["body"]
+            .as_str()
+            .is_some_and(|body| body.starts_with("# RFC 0002: Parser extensions\n"))
+    );
+
+    let missing = workspace.run_rfc(&["show", "9"]);
+    assert!(!missing.status.success(), "command unexpectedly succeeded");
+}
+
+#[test]
+fn supersedes_latest_resolves_to_highest_existing_id() {
+    let workspace = TestWorkspace::new("supersedes-latest");
+    for title in ["First parser", "Second parser"] {
+        let create = workspace.run_rfc_new(&["--author", "Roger", title]);
+        assert!(create.status.success(), "{}", output_stderr(&create));
+    }
+
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--no-template",
+        "--supersedes",
+        "latest",
+        "Third parser",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let content = fs::read_to_string(workspace.path().join("rfc/0003-third-parser.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("supersedes = [2]\n"), "{content}");
+
+    let revise = workspace.run_rfc_revise(&["--prerequisite", "last", "1"]);
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-first-parser.md"))
+        .expect("failed to read revised RFC");
+    assert!(content.contains("prerequisite = [3]\n"), "{content}");
+}
+
+#[test]
+fn prerequisite_cycles_and_self_references_are_rejected() {
+    let workspace = TestWorkspace::new("prerequisite-cycles");
+    let first = workspace.run_rfc_new(&["--author", "Roger", "Base design"]);
+    assert!(first.status.success(), "{}", output_stderr(&first));
+    let second = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--prerequisite",
+        "1",
+        "Follow-up design",
+    ]);
+    assert!(second.status.success(), "{}", output_stderr(&second));
+
+    let cycle = workspace.run_rfc_revise(&["--prerequisite", "2", "1"]);
+    assert!(!cycle.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&cycle).contains("prerequisite cycle detected: 0001 -> 0002 -> 0001"));
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-base-design.md"))
+        .expect("failed to read RFC");
+    assert!(!content.contains("prerequisite"));
+
+    let own = workspace.run_rfc_revise(&["--prerequisite", "2", "2"]);
+    assert!(!own.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&own).contains("RFC 0002 cannot list itself as a prerequisite"));
+}
+
+#[test]
+fn link_back_adds_superseded_by_to_superseded_rfcs() {
+    let workspace = TestWorkspace::new("link-back");
+    for title in ["Old parser", "Old lexer"] {
+        let create = workspace.run_rfc_new(&["--author", "Roger", title]);
+        assert!(create.status.success(), "{}", output_stderr(&create));
+    }
+    let preset = workspace.run_rfc_revise(&["--allow-dangling-refs", "--superseded_by", "3", "2"]);
+    assert!(preset.status.success(), "{}", output_stderr(&preset));
+    let lexer_path = workspace.path().join("rfc/0002-old-lexer.md");
+    let lexer_before = fs::read_to_string(&lexer_path).expect("failed to read RFC");
+
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--supersedes",
+        "1",
+        "--supersedes",
+        "2",
+        "--link-back",
+        "New front end",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    assert_eq!(
+        output_stdout(&create),
+        "rfc/0003-new-front-end.md\nrfc/0001-old-parser.md\n"
+    );
+
+    let parser = fs::read_to_string(workspace.path().join("rfc/0001-old-parser.md"))
+        .expect("failed to read RFC");
+    assert!(parser.contains("superseded_by = [3]\n"));
+    assert!(parser.contains("change = \"Superseded by RFC 0003\""));
+    let lexer_after = fs::read_to_string(&lexer_path).expect("failed to read RFC");
+    assert_eq!(lexer_after, lexer_before);
+}
+
+#[test]
+fn link_back_aborts_without_writing_on_cycles_or_missing_targets() {
+    let workspace = TestWorkspace::new("link-back-abort");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Old parser"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let preset = workspace.run_rfc_revise(&["--allow-dangling-refs", "--supersedes", "2", "1"]);
+    assert!(preset.status.success(), "{}", output_stderr(&preset));
+    let parser_path = workspace.path().join("rfc/0001-old-parser.md");
+    let parser_before = fs::read_to_string(&parser_path).expect("failed to read RFC");
+
+    let cycle = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--supersedes",
+        "1",
+        "--link-back",
+        "New parser",
+    ]);
+    assert!(!cycle.status.success(), "cycle unexpectedly succeeded");
+    assert!(
+        output_stderr(&cycle).contains("supersession cycle detected: 0002 -> 0001 -> 0002"),
+        "{}",
+        output_stderr(&cycle)
+    );
+
+    let missing = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--allow-dangling-refs",
+        "--supersedes",
+        "9",
+        "--link-back",
+        "New lexer",
+    ]);
+    assert!(
+        !missing.status.success(),
+        "missing target unexpectedly succeeded"
+    );
+    assert!(output_stderr(&missing).contains("cannot link back to RFC 0009"));
+
+    assert!(!workspace.path().join("rfc/0002-new-parser.md").exists());
+    assert!(!workspace.path().join("rfc/0002-new-lexer.md").exists());
+    assert_eq!(
+        fs::read_to_string(&parser_path).expect("failed to read RFC"),
+        parser_before
+    );
+}
+
+#[test]
+fn allow_duplicate_title_requires_distinct_slug() {
+    let workspace = TestWorkspace::new("allow-duplicate-title");
+    let first = workspace.run_rfc_new(&["--author", "Roger", "Parser support"]);
+    assert!(first.status.success(), "{}", output_stderr(&first));
+
+    let duplicate = workspace.run_rfc_new(&["--author", "Roger", "Parser support"]);
+    assert!(
+        !duplicate.status.success(),
+        "duplicate unexpectedly succeeded"
+    );
+    assert!(output_stderr(&duplicate).contains("already exists"));
+
+    let no_slug = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--allow-duplicate-title",
+        "Parser support",
+    ]);
+    assert!(
+        !no_slug.status.success(),
+        "missing --slug unexpectedly succeeded"
+    );
+    assert!(output_stderr(&no_slug).contains("--slug"));
+
+    let taken = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--allow-duplicate-title",
+        "--slug",
+        "parser-support",
+        "Parser support",
+    ]);
+    assert!(
+        !taken.status.success(),
+        "colliding slug unexpectedly succeeded"
+    );
+    assert!(output_stderr(&taken).contains("slug `parser-support` is already used"));
+
+    let allowed = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--allow-duplicate-title",
+        "--slug",
+        "parser-support-cli",
+        "Parser support",
+    ]);
+    assert!(allowed.status.success(), "{}", output_stderr(&allowed));
+    assert_eq!(output_stdout(&allowed), "rfc/0002-parser-support-cli.md\n");
+    let content = fs::read_to_string(workspace.path().join("rfc/0002-parser-support-cli.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("# RFC 0002: Parser support"));
+}
+
+#[test]
+fn slug_overrides_file_name_but_not_title() {
+    let workspace = TestWorkspace::new("explicit-slug");
+    let create = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--slug",
+        "async-io",
+        "Asynchronous I/O for the runtime and its plugins",
+    ]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    assert_eq!(output_stdout(&create), "rfc/0001-async-io.md\n");
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-async-io.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("title = \"Asynchronous I/O for the runtime and its plugins\"\n"));
+    assert!(content.contains("# RFC 0001: Asynchronous I/O for the runtime and its plugins\n"));
+
+    for slug in ["Async_IO", "async--io", ""] {
+        let invalid = workspace.run_rfc_new(&["--author", "Roger", "--slug", slug, "Other RFC"]);
+        assert!(
+            !invalid.status.success(),
+            "slug `{slug}` unexpectedly accepted"
+        );
+        assert!(output_stderr(&invalid).contains("invalid `--slug`"));
+    }
+    let taken = workspace.run_rfc_new(&["--author", "Roger", "--slug", "async-io", "Other RFC"]);
+    assert!(
+        !taken.status.success(),
+        "colliding slug unexpectedly succeeded"
+    );
+    assert!(
+        output_stderr(&taken).contains("slug `async-io` is already used by rfc/0001-async-io.md")
+    );
+}
+
+#[test]
+fn renumber_fixes_file_name_mismatches_with_dry_run_by_default() {
+    let workspace = TestWorkspace::new("renumber-mismatch");
+    let root = workspace.path();
+    write_rfc(root, "0001-alpha.md", "0001", "Alpha", "", "");
+    write_rfc(
+        root,
+        "0008-drifted.md",
+        "0007",
+        "Drifted",
+        "prerequisite = [1]\n",
+        "",
+    );
+
+    let dry_run = workspace.run_rfc(&["renumber"]);
+    assert!(dry_run.status.success(), "{}", output_stderr(&dry_run));
+    let stdout = output_stdout(&dry_run);
+    assert!(
+        stdout.contains("log: would change rfc/0008-drifted.md: rename to rfc/0007-drifted.md\n"),
+        "{stdout}"
+    );
+    assert!(stdout.contains("--apply"), "{stdout}");
+    assert!(root.join("rfc/0008-drifted.md").is_file());
+    assert!(!root.join("rfc/0007-drifted.md").exists());
+
+    let apply = workspace.run_rfc(&["renumber", "--apply"]);
+    assert!(apply.status.success(), "{}", output_stderr(&apply));
+    assert!(output_stdout(&apply).contains("rfc/0007-drifted.md\n"));
+    assert!(!root.join("rfc/0008-drifted.md").exists());
+    let content =
+        fs::read_to_string(root.join("rfc/0007-drifted.md")).expect("failed to read renamed RFC");
+    assert!(content.contains("rfc = \"0007\"\n"));
+    assert!(content.contains("prerequisite = [1]\n"));
+    assert!(
+        content.contains("change = \"Renamed file to match RFC id\"\n"),
+        "{content}"
+    );
+    let alpha = fs::read_to_string(root.join("rfc/0001-alpha.md")).expect("failed to read RFC");
+    assert!(!alpha.contains("[[revision]]"), "{alpha}");
+
+    let again = workspace.run_rfc(&["renumber", "--apply"]);
+    assert!(again.status.success(), "{}", output_stderr(&again));
+    assert!(output_stdout(&again).contains("RFC ids and file names already match"));
+}
+
+#[test]
+fn renumber_apply_failure_keeps_original_files() {
+    let workspace = TestWorkspace::new("renumber-failure");
+    let root = workspace.path();
+    write_rfc(root, "0001-alpha.md", "0001", "Alpha", "", "");
+    write_rfc(root, "0003-b.md", "0002", "B", "revision = []\n", "");
+    let original = fs::read_to_string(root.join("rfc/0003-b.md")).expect("failed to read RFC");
+
+    let apply = workspace.run_rfc(&["renumber", "--apply"]);
+    assert!(!apply.status.success());
+    assert!(
+        output_stderr(&apply).contains("`revision` exists but is not an array of tables"),
+        "{}",
+        output_stderr(&apply)
+    );
+    let content = fs::read_to_string(root.join("rfc/0003-b.md")).expect("original RFC was removed");
+    assert_eq!(content, original);
+    assert!(!root.join("rfc/0002-b.md").exists());
+    let leftovers = fs::read_dir(root.join("rfc"))
+        .expect("failed to read rfc dir")
+        .filter_map(|entry| Some(entry.ok()?.file_name().to_string_lossy().into_owned()))
+        .filter(|name| name.starts_with('.'))
+        .collect::<Vec<_>>();
+    assert!(leftovers.is_empty(), "{leftovers:?}");
+}
+
+#[test]
+fn renumber_close_gaps_rewrites_ids_and_cross_references() {
+    let workspace = TestWorkspace::new("renumber-gaps");
+    let root = workspace.path();
+    write_rfc(root, "0001-alpha.md", "0001", "Alpha", "", "");
+    write_rfc(
+        root,
+        "0003-beta.md",
+        "0003",
+        "Beta",
+        "prerequisite = [1]\nsuperseded_by = [5]\n",
+        "",
+    );
+    write_rfc(
+        root,
+        "0005-gamma.md",
+        "0005",
+        "Gamma",
+        "prerequisite = [1, 3]\nsupersedes = [3]\n",
+        "\nBuilds on [RFC 0003](0003-beta.md).\n\n```\n# RFC 0005: Sample\n```\n",
+    );
+    write_rfc(
+        root,
+        "0009-delta.md",
+        "0009",
+        "Delta",
+        "prerequisite = [5]\n",
+        "",
+    );
+
+    let apply = workspace.run_rfc(&["renumber", "--close-gaps", "--apply"]);
+    assert!(apply.status.success(), "{}", output_stderr(&apply));
+    let stdout = output_stdout(&apply);
+    assert!(
+        stdout.contains("log: rfc/0003-beta.md: rename to rfc/0002-beta.md, renumber RFC 0003 -> 0002, update references\n"),
+        "{stdout}"
+    );
+    for stale in ["0003-beta.md", "0005-gamma.md", "0009-delta.md"] {
+        assert!(
+            !root.join("rfc").join(stale).exists(),
+            "{stale} still exists"
+        );
+    }
+
+    let alpha = fs::read_to_string(root.join("rfc/0001-alpha.md")).expect("failed to read RFC");
+    assert!(!alpha.contains("[[revision]]"), "{alpha}");
+    let beta = fs::read_to_string(root.join("rfc/0002-beta.md")).expect("failed to read RFC");
+    assert!(beta.contains("rfc = \"0002\"\n"), "{beta}");
+    assert!(beta.contains("superseded_by = [3]\n"), "{beta}");
+    assert!(beta.contains("# RFC 0002: Beta\n"), "{beta}");
+    assert!(
+        beta.contains("change = \"Renumbered RFC 0003 -> 0002, 0005 -> 0003, 0009 -> 0004\"\n"),
+        "{beta}"
+    );
+    let gamma = fs::read_to_string(root.join("rfc/0003-gamma.md")).expect("failed to read RFC");
+    assert!(gamma.contains("prerequisite = [1, 2]\n"), "{gamma}");
+    assert!(gamma.contains("supersedes = [2]\n"), "{gamma}");
+    assert!(gamma.contains("# RFC 0003: Gamma\n"), "{gamma}");
+    assert!(
+        gamma.contains("Builds on [RFC 0002](0002-beta.md)."),
+        "{gamma}"
+    );
+    assert!(gamma.contains("```\n# RFC 0005: Sample\n```"), "{gamma}");
+    let delta = fs::read_to_string(root.join("rfc/0004-delta.md")).expect("failed to read RFC");
+    assert!(delta.contains("rfc = \"0004\"\n"), "{delta}");
+    assert!(delta.contains("prerequisite = [3]\n"), "{delta}");
+
+    write_sectionless_template(root);
+    let validate = workspace.run_rfc_validate(&[]);
+    assert!(validate.status.success(), "{}", output_stderr(&validate));
+}
+
+#[test]
+fn renumber_refuses_duplicate_ids_and_overwriting_unrelated_files() {
+    let workspace = TestWorkspace::new("renumber-conflicts");
+    let root = workspace.path();
+    write_rfc(root, "0001-alpha.md", "0001", "Alpha", "", "");
+    write_rfc(root, "0002-beta.md", "0001", "Beta", "", "");
+
+    let duplicate = workspace.run_rfc(&["renumber", "--apply"]);
+    assert!(
+        !duplicate.status.success(),
+        "duplicate ids unexpectedly renumbered"
+    );
+    assert!(
+        output_stderr(&duplicate)
+            .contains("RFC id 0001 is used by both rfc/0001-alpha.md and rfc/0002-beta.md"),
+        "{}",
+        output_stderr(&duplicate)
+    );
+    assert!(root.join("rfc/0002-beta.md").is_file());
+
+    let close_gaps = workspace.run_rfc(&["renumber", "--close-gaps", "--apply"]);
+    assert!(
+        close_gaps.status.success(),
+        "{}",
+        output_stderr(&close_gaps)
+    );
+    let beta = fs::read_to_string(root.join("rfc/0002-beta.md")).expect("failed to read RFC");
+    assert!(beta.contains("rfc = \"0002\"\n"), "{beta}");
+
+    write_rfc(root, "0005-gamma.md", "0003", "Gamma", "", "");
+    // A directory is not an RFC file, so it is never part of the plan.
+    fs::create_dir(root.join("rfc/0003-gamma.md")).expect("failed to create directory");
+    let blocked = workspace.run_rfc(&["renumber", "--apply"]);
+    assert!(
+        !blocked.status.success(),
+        "rename unexpectedly overwrote a file"
+    );
+    assert!(
+        output_stderr(&blocked).contains("cannot rename to rfc/0003-gamma.md: file already exists"),
+        "{}",
+        output_stderr(&blocked)
+    );
+    assert!(root.join("rfc/0005-gamma.md").is_file());
+}
+
+#[test]
+fn rfc_validate_reports_every_problem_as_json() {
+    let workspace = TestWorkspace::new("validate-json");
+    let root = workspace.path();
+    fs::write(
+        root.join(".agx.toml"),
+        "[rfc]\nsections = [\"Summary\", \"Motivation\"]\n",
+    )
+    .expect("failed to write config");
+    write_rfc(
+        root,
+        "0001-complete.md",
+        "0001",
+        "Complete",
+        "",
+        "\n## Summary\n\nText.\n\n## Motivation\n\nWhy.\n",
+    );
+    write_rfc(
+        root,
+        "0003-broken.md",
+        "0002",
+        "Broken",
+        "prerequisite = [9]\n",
+        "\n## Summary\n\nText.\n",
+    );
+    let broken = root.join("rfc/0003-broken.md");
+    let content = fs::read_to_string(&broken)
+        .expect("failed to read RFC")
+        .replace(
+            "authors = [\"Roger\"]\ncreated = \"2025-01-01T00:00:00Z\"\n",
+            "authors = []\n",
+        );
+    fs::write(&broken, content).expect("failed to write RFC");
+    fs::write(root.join("rfc/0004-unparsable.md"), "# no frontmatter\n")
+        .expect("failed to write RFC");
+
+    let validate = workspace.run_rfc_validate(&["--check-refs", "--format", "json"]);
+    assert!(!validate.status.success(), "validation unexpectedly passed");
+    let payload: Value = serde_json::from_str(&output_stdout(&validate)).expect("invalid JSON");
+    assert_eq!(payload["schema_version"], 1);
+    assert_eq!(payload["total"], 3);
+    assert_eq!(payload["passed"], 1);
+    assert_eq!(payload["failed"], 2);
+    let results = payload["results"]
+        .as_array()
+        .expect("results should be an array");
+    let result = |path: &str| {
+        results
+            .iter()
+            .find(|result| result["path"] == path)
+            .unwrap_or_else(|| panic!("missing result for {path}"))
+    };
+    assert_eq!(result("rfc/0001-complete.md")["ok"], true);
+    assert_eq!(
+        result("rfc/0001-complete.md")["problems"],
+        serde_json::json!([])
+    );
+    assert_eq!(result("rfc/0004-unparsable.md")["ok"], false);
+    let problems = result("rfc/0003-broken.md")["problems"]
+        .as_array()
+        .expect("problems should be an array")
+        .iter()
+        .map(|problem| problem.as_str().expect("problem should be a string"))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        problems,
+        [
+            "file name id `0003` does not match metadata `rfc = \"0002\"` (use --fix to rename the file, or --fix --id-source filename to rewrite `rfc`)",
+            "missing required `created` field (use --fix to backfill it)",
+            "`authors` must list at least one author",
+            "missing `## Motivation` section",
+            "`prerequisite` references missing RFC 0009",
+        ]
+    );
+
+    let text = workspace.run_rfc_validate(&[]);
+    assert!(!text.status.success(), "validation unexpectedly passed");
+    let stderr = output_stderr(&text);
+    assert!(stderr.contains("error: rfc/0003-broken.md: `authors` must list at least one author"));
+    assert!(stderr.contains("error: rfc/0003-broken.md: missing `## Motivation` section"));
+    assert!(output_stdout(&text).contains("log: ok rfc/0001-complete.md"));
+
+    let conflict = workspace.run_rfc_validate(&["--fix", "--format", "json"]);
+    assert!(
+        !conflict.status.success(),
+        "--fix with json unexpectedly accepted"
+    );
+}
+
+#[test]
+fn revise_preserves_frontmatter_comments() {
+    let workspace = TestWorkspace::new("revise-comments");
+    let path = workspace.path().join("rfc/0001-commented.md");
+    fs::write(
+        &path,
+        "+++\n# owner: x\nrfc = \"0001\"\n# the title\ntitle = \"Commented\"  # keep me\nauthors = [\"Roger\"] # who\n\n# standalone comment\n\nstatus = \"draft\" # lifecycle\ncreated = \"2025-01-01T00:00:00Z\"\nlast_updated = \"2025-01-01T00:00:00Z\"\n\n# revisions below\n[[revision]]\ndate = \"2025-01-01T00:00:00Z\"\nchange = \"Initial draft\"\n+++\n\n# RFC 0001: Commented\n",
+    )
+    .expect("failed to write RFC");
+
+    let output = workspace.run_rfc_revise(&[
+        "--author",
+        "Alice",
+        "--status",
+        "proposed",
+        "--title",
+        "Still commented",
+        "--keep-filename",
+        "0001",
+    ]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+
+    let content = fs::read_to_string(&path).expect("failed to read RFC");
+    let timestamp = last_updated_timestamp(&content).expect("last_updated should be set");
+    assert_eq!(
+        content,
+        format!(
+            "+++\n# owner: x\nrfc = \"0001\"\n# the title\ntitle = \"Still commented\"  # keep me\nauthors = [\"Roger\", \"Alice\"] # who\n\n# standalone comment\n\nstatus = \"proposed\" # lifecycle\ncreated = \"2025-01-01T00:00:00Z\"\nlast_updated = \"{timestamp}\"\nupdated_by = \"Alice\"\n\n# revisions below\n[[revision]]\ndate = \"2025-01-01T00:00:00Z\"\nchange = \"Initial draft\"\n\n[[revision]]\ndate = \"{timestamp}\"\nchange = \"Revised\"\nby = \"Alice\"\n+++\n\n# RFC 0001: Still commented\n"
+        )
+    );
+}
+
+#[test]
+fn rfc_list_sorts_by_id_and_skips_malformed_files() {
+    let workspace = TestWorkspace::new("rfc-list");
+    for title in ["First proposal", "Second proposal"] {
+        let create = workspace.run_rfc_new(&["--author", "Roger", title]);
+        assert!(create.status.success(), "{}", output_stderr(&create));
+    }
+    fs::write(
+        workspace.path().join("rfc/0003-broken.md"),
+        "no frontmatter here\n",
+    )
+    .expect("failed to write malformed RFC");
+
+    let text = workspace.run_rfc(&["list"]);
+    assert!(text.status.success(), "{}", output_stderr(&text));
+    let stdout = output_stdout(&text);
+    let lines = stdout.lines().collect::<Vec<_>>();
+    assert_eq!(lines[0], "rfc\ttitle\tauthors\tlast_updated\tupdated_by");
+    assert!(lines[1].starts_with("0001\tFirst proposal\tRoger\t"));
+    assert!(lines[2].starts_with("0002\tSecond proposal\tRoger\t"));
+    assert_eq!(lines.len(), 3);
+    let stderr = output_stderr(&text);
+    assert!(
+        stderr.contains("skipping") && stderr.contains("0003-broken.md"),
+        "{stderr}"
+    );
+
+    let json = workspace.run_rfc(&["list", "--format", "json"]);
+    assert!(json.status.success(), "{}", output_stderr(&json));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&json)).expect("failed to parse JSON output");
+    assert_eq!(payload["schema_version"], 1);
+    let rfcs = payload["rfcs"].as_array().expect("rfcs should be array");
+    assert_eq!(rfcs.len(), 2);
+    assert_eq!(rfcs[1]["rfc"], 2);
+    assert_eq!(rfcs[1]["authors"], serde_json::json!(["Roger"]));
+    assert!(rfcs[1]["last_updated"].is_string());
+}
+
+#[test]
+fn rfc_board_checks_final_rfcs_and_leaves_drafts_open() {
+    let workspace = TestWorkspace::new("rfc-board");
+    for (flag, title) in [
+        ("--final", "Shipped parser"),
+        ("--draft", "Open question"),
+        ("--final", "Another shipped"),
+    ] {
+        let create = workspace.run_rfc_new(&["--author", "Roger", flag, title]);
+        assert!(create.status.success(), "{}", output_stderr(&create));
+    }
+
+    let board = workspace.run_rfc(&["board"]);
+    assert!(board.status.success(), "{}", output_stderr(&board));
+    assert_eq!(
+        output_stdout(&board),
+        "## draft\n\n- [ ] 0002: Open question\n\n## final\n\n- [x] 0001: Shipped parser\n- [x] 0003: Another shipped\n"
+    );
+
+    let json = workspace.run_rfc(&["board", "--format", "json"]);
+    assert!(json.status.success(), "{}", output_stderr(&json));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&json)).expect("failed to parse JSON output");
+    assert_eq!(payload["groups"][0]["status"], "draft");
+    assert_eq!(payload["groups"][0]["rfcs"][0]["done"], false);
+    assert_eq!(payload["groups"][1]["rfcs"][0]["done"], true);
+}
+
+#[test]
+fn rfc_board_orders_columns_by_lifecycle_and_unknown_statuses_last() {
+    let workspace = TestWorkspace::new("rfc-board-order");
+    let root = workspace.path();
+    write_rfc(
+        root,
+        "0001-a.md",
+        "0001",
+        "Custom",
+        "status = \"review\"\n",
+        "",
+    );
+    write_rfc(
+        root,
+        "0002-b.md",
+        "0002",
+        "Closed",
+        "status = \"withdrawn\"\n",
+        "",
+    );
+    write_rfc(
+        root,
+        "0003-c.md",
+        "0003",
+        "Pitched",
+        "status = \"proposed\"\n",
+        "",
+    );
+
+    let board = workspace.run_rfc(&["board", "--format", "json"]);
+    assert!(board.status.success(), "{}", output_stderr(&board));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&board)).expect("failed to parse JSON output");
+    let statuses: Vec<&str> = payload["groups"]
+        .as_array()
+        .expect("groups should be an array")
+        .iter()
+        .map(|group| group["status"].as_str().expect("status should be a string"))
+        .collect();
+    assert_eq!(statuses, ["proposed", "withdrawn", "review"]);
+}
+
+#[test]
+fn link_inline_registers_bracketed_ids_as_prerequisites() {
+    let workspace = TestWorkspace::new("link-inline");
+    let base = workspace.run_rfc_new(&["--author", "Roger", "Base parser"]);
+    assert!(base.status.success(), "{}", output_stderr(&base));
+
+    let args = [
+        "--author",
+        "Roger",
+        "--link-inline",
+        "--abstract",
+        "Builds on [[0001]] and [[0003]].",
+        "Replace [[0003]] parser",
+    ];
+    let dangling = workspace.run_rfc_new(&args);
+    assert!(!dangling.status.success(), "command unexpectedly succeeded");
+    assert!(
+        output_stderr(&dangling).contains("`prerequisite` references unknown RFC 0003"),
+        "{}",
+        output_stderr(&dangling)
+    );
+    assert!(
+        !workspace
+            .path()
+            .join("rfc/0002-replace-0003-parser.md")
+            .exists()
+    );
+
+    let create = workspace.run_rfc_new(&[&["--allow-dangling-refs"], &args[..]].concat());
+    assert!(create.status.success(), "{}", output_stderr(&create));
+
+    let content = fs::read_to_string(workspace.path().join("rfc/0002-replace-0003-parser.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("title = \"Replace [[0003]] parser\""));
+    assert!(content.contains("prerequisite = [3, 1]"));
+    assert!(content.contains("Builds on [RFC 0001](0001-base-parser.md) and RFC 0003."));
+
+    let conflict = workspace.run_rfc_revise(&[
+        "--strict",
+        "--link-inline",
+        "--supersedes",
+        "1",
+        "--abstract",
+        "Replaces [[0001]].",
+        "2",
+    ]);
+    assert!(!conflict.status.success(), "command unexpectedly succeeded");
+    assert!(
+        output_stderr(&conflict)
+            .contains("RFC 0001 is listed in both `prerequisite` and `supersedes`"),
+        "{}",
+        output_stderr(&conflict)
+    );
+}
+
+#[test]
+fn unknown_reference_ids_are_rejected_unless_allowed() {
+    let workspace = TestWorkspace::new("dangling-refs");
+    let base = workspace.run_rfc_new(&["--author", "Roger", "Base"]);
+    assert!(base.status.success(), "{}", output_stderr(&base));
+
+    let known = workspace.run_rfc_new(&["--author", "Roger", "--prerequisite", "1", "Known"]);
+    assert!(known.status.success(), "{}", output_stderr(&known));
+
+    let unknown = workspace.run_rfc_new(&["--author", "Roger", "--prerequisite", "42", "Unknown"]);
+    assert!(!unknown.status.success());
+    assert!(output_stderr(&unknown).contains("`prerequisite` references unknown RFC 0042"));
+    assert!(!workspace.path().join("rfc/0003-unknown.md").exists());
+
+    let revise = workspace.run_rfc_revise(&["--supersedes", "9", "2"]);
+    assert!(!revise.status.success());
+    assert!(output_stderr(&revise).contains("`supersedes` references unknown RFC 0009"));
+
+    let allowed = workspace.run_rfc_revise(&["--allow-dangling-refs", "--supersedes", "9", "2"]);
+    assert!(allowed.status.success(), "{}", output_stderr(&allowed));
+    let content =
+        fs::read_to_string(workspace.path().join("rfc/0002-known.md")).expect("failed to read RFC");
+    assert!(content.contains("supersedes = [9]"));
+}
+
+#[test]
+fn graph_prints_dot_and_mermaid_edges_by_relation() {
+    let workspace = TestWorkspace::new("graph");
+    for args in [
+        &["--author", "Roger", "Base"][..],
+        &["--author", "Roger", "--prerequisite", "1", "Parser"],
+        &["--author", "Roger", "--supersedes", "2", "New \"Parser\""],
+    ] {
+        let create = workspace.run_rfc_new(args);
+        assert!(create.status.success(), "{}", output_stderr(&create));
+    }
+
+    let dot = workspace.run_rfc(&["graph"]);
+    assert!(dot.status.success(), "{}", output_stderr(&dot));
+    let dot = output_stdout(&dot);
+    assert!(dot.starts_with("digraph rfcs {"));
+    assert!(dot.contains("rfc0001 [label=\"0001: Base\"];"));
+    assert!(dot.contains("rfc0003 [label=\"0003: New \\\"Parser\\\"\"];"));
+    assert!(dot.contains("rfc0002 -> rfc0001 [label=\"prerequisite\"];"));
+    assert!(dot.contains("rfc0003 -> rfc0002 [label=\"supersedes\", style=dashed];"));
+
+    let mermaid = workspace.run_rfc(&["graph", "--format", "mermaid"]);
+    assert!(mermaid.status.success(), "{}", output_stderr(&mermaid));
+    let mermaid = output_stdout(&mermaid);
+    assert!(mermaid.starts_with("graph LR\n"));
+    assert!(mermaid.contains("rfc0002[\"0002: Parser\"]"));
+    assert!(mermaid.contains("rfc0002 -->|prerequisite| rfc0001"));
+    assert!(mermaid.contains("rfc0003 -.->|supersedes| rfc0002"));
+}
+
+#[test]
+fn infer_prereq_from_supersedes_copies_superseded_ids() {
+    let workspace = TestWorkspace::new("infer-prereq");
+    let plain = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--allow-dangling-refs",
+        "--supersedes",
+        "7",
+        "Plain",
+    ]);
+    assert!(plain.status.success(), "{}", output_stderr(&plain));
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-plain.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("supersedes = [7]"));
+    assert!(!content.contains("prerequisite"));
+
+    let inferred = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--allow-dangling-refs",
+        "--prerequisite",
+        "3",
+        "--supersedes",
+        "7",
+        "--infer-prereq-from-supersedes",
+        "Inferred",
+    ]);
+    assert!(inferred.status.success(), "{}", output_stderr(&inferred));
+    assert!(!output_stderr(&inferred).contains("warning"));
+    let content = fs::read_to_string(workspace.path().join("rfc/0002-inferred.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("prerequisite = [3, 7]"));
+    assert!(content.contains("supersedes = [7]"));
+}
+
+#[test]
+fn revision_mode_preserves_created_timestamp() {
+    let workspace = TestWorkspace::new("revision-preserves-created");
+    fs::write(
+        workspace.path().join("rfc/0001-existing.md"),
+        "+++\nrfc = \"0001\"\ntitle = \"Existing\"\nauthors = [\"Roger\"]\ncreated = \"2025-01-01T00:00:00Z\"\nlast_updated = \"2025-01-01T00:00:00Z\"\n[[revision]]\ndate = \"2025-01-01T00:00:00Z\"\nchange = \"Initial draft\"\n+++\n\n# RFC 0001: Existing\n",
+    )
+    .expect("failed to write RFC");
+
+    let revise = workspace.run_rfc_revise(&["--force-revision", "1"]);
+    assert!(
+        revise.status.success(),
+        "revision failed:\n{}",
+        output_stderr(&revise)
+    );
+
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-existing.md"))
+        .expect("failed to read revised RFC");
+    assert!(content.contains("created = \"2025-01-01T00:00:00Z\""));
+    assert_ne!(
+        last_updated_timestamp(&content).as_deref(),
+        Some("2025-01-01T00:00:00Z")
+    );
+}
+
+#[test]
+fn revision_mode_backfills_missing_created_from_first_revision() {
+    let workspace = TestWorkspace::new("revision-backfills-created");
+    fs::write(
+        workspace.path().join("rfc/0001-external.md"),
+        "+++\nrfc = \"0001\"\ntitle = \"External\"\nauthors = [\"Roger\"]\n[[revision]]\ndate = \"2025-03-04T05:06:07Z\"\nchange = \"Imported\"\n+++\n\n# RFC 0001: External\n",
+    )
+    .expect("failed to write RFC");
+
+    let revise = workspace.run_rfc_revise(&["1"]);
+    assert!(
+        revise.status.success(),
+        "revision failed:\n{}",
+        output_stderr(&revise)
+    );
+
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-external.md"))
+        .expect("failed to read revised RFC");
+    assert!(content.contains("created = \"2025-03-04T05:06:07Z\""));
+    assert!(last_updated_timestamp(&content).is_some());
+    assert_eq!(
+        latest_revision_timestamp(&content),
+        last_updated_timestamp(&content)
+    );
+}
+
+#[test]
+fn revision_mode_refuses_frozen_status_without_override() {
+    let workspace = TestWorkspace::new("revision-frozen");
+    let file = workspace.path().join("rfc/0001-accepted.md");
+    fs::write(
+        &file,
+        "+++\nrfc = \"0001\"\ntitle = \"Accepted\"\nstatus = \"accepted\"\nauthors = [\"Roger\"]\ncreated = \"2025-01-01T00:00:00Z\"\nlast_updated = \"2025-01-01T00:00:00Z\"\n+++\n\n# RFC 0001: Accepted\n",
+    )
+    .expect("failed to write RFC");
+
+    let refused = workspace.run_rfc_revise(&["--discussion", "DISC-1", "1"]);
+    assert!(!refused.status.success(), "revision unexpectedly succeeded");
+    assert!(output_stderr(&refused).contains("frozen status `accepted`"));
+    let content = fs::read_to_string(&file).expect("failed to read RFC");
+    assert!(!content.contains("DISC-1"));
+
+    let allowed = workspace.run_rfc_revise(&["--discussion", "DISC-1", "--allow-frozen", "1"]);
+    assert!(
+        allowed.status.success(),
+        "revision failed:\n{}",
+        output_stderr(&allowed)
+    );
+    let content = fs::read_to_string(&file).expect("failed to read RFC");
+    assert!(content.contains("discussion = \"DISC-1\""));
+    assert!(content.contains("change = \"Revised (override frozen status `accepted`)\""));
+}
+
+#[test]
+fn revision_mode_accepts_numeric_selector_as_rfc_id() {
+    let workspace = TestWorkspace::new("revision-id-selector");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "Original RFC"]);
+    assert!(
+        create.status.success(),
+        "initial create failed:\n{}",
+        output_stderr(&create)
+    );
+
+    let revise = workspace.run_rfc_revise(&["--force-revision", "1"]);
+    assert!(
+        revise.status.success(),
+        "revision failed:\n{}",
+        output_stderr(&revise)
+    );
+    assert_eq!(output_stdout(&revise).trim(), "rfc/0001-original-rfc.md");
+
+    let file = workspace.path().join("rfc/0001-original-rfc.md");
+    let content = fs::read_to_string(file).expect("failed to read revised RFC");
+    assert!(content.contains("change = \"Revised\""));
+}
+
+#[test]
+fn rfc_validate_check_refs_flags_and_prunes_deleted_references() {
+    let workspace = TestWorkspace::new("validate-check-refs");
+    for title in ["First RFC", "Second RFC"] {
+        let create = workspace.run_rfc_new(&["--author", "Roger", title]);
+        assert!(create.status.success(), "{}", output_stderr(&create));
+    }
+    let dependent = workspace.run_rfc_new(&[
+        "--author",
+        "Roger",
+        "--prerequisite",
+        "1",
+        "--prerequisite",
+        "2",
+        "Third RFC",
+    ]);
+    assert!(dependent.status.success(), "{}", output_stderr(&dependent));
+    fs::remove_file(workspace.path().join("rfc/0002-second-rfc.md"))
+        .expect("failed to delete RFC 0002");
+
+    let check = workspace.run_rfc_validate(&["--check-refs"]);
+    assert!(!check.status.success(), "validate unexpectedly succeeded");
+    let stderr = output_stderr(&check);
+    assert!(stderr.contains("rfc/0003-third-rfc.md"));
+    assert!(stderr.contains("`prerequisite` references missing RFC 0002"));
+
+    let fix = workspace.run_rfc_validate(&["--check-refs", "--fix"]);
+    assert!(
+        fix.status.success(),
+        "validate --fix failed:\n{}",
+        output_stderr(&fix)
+    );
+    let content = fs::read_to_string(workspace.path().join("rfc/0003-third-rfc.md"))
+        .expect("failed to read fixed RFC");
+    assert!(content.contains("prerequisite = [1]"));
+    assert!(content.contains("change = \"Removed dangling references to RFC 0002\""));
+
+    let recheck = workspace.run_rfc_validate(&["--check-refs"]);
+    assert!(recheck.status.success(), "{}", output_stderr(&recheck));
+}
+
+#[test]
+fn rfc_validate_checks_references_and_sections_without_flags() {
+    let workspace = TestWorkspace::new("validate-defaults");
+    let root = workspace.path();
+    write_rfc(
+        root,
+        "0001-bare.md",
+        "0001",
+        "Bare",
+        "prerequisite = [9]\n",
+        "\n## Summary\n\nBody.\n",
+    );
+
+    let check = workspace.run_rfc_validate(&[]);
+    assert!(!check.status.success(), "validate unexpectedly succeeded");
+    let stderr = output_stderr(&check);
+    assert!(
+        stderr.contains("`prerequisite` references missing RFC 0009"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("missing `## Motivation` section"),
+        "{stderr}"
+    );
+    assert!(!stderr.contains("missing `## Summary` section"), "{stderr}");
+
+    fs::write(root.join(".agx.toml"), "[rfc]\nsections = [\"Summary\"]\n")
+        .expect("failed to write .agx.toml");
+    let configured = workspace.run_rfc_validate(&[]);
+    let stderr = output_stderr(&configured);
+    assert!(!stderr.contains("## Motivation"), "{stderr}");
+    assert!(stderr.contains("references missing RFC 0009"), "{stderr}");
+}
+
+#[test]
+fn rfc_validate_fix_keeps_references_to_rfcs_with_metadata_errors() {
+    let workspace = TestWorkspace::new("validate-refs-unparsed");
+    let root = workspace.path();
+    fs::write(
+        root.join("rfc/0001-untitled.md"),
+        "+++\nrfc = \"0001\"\nauthors = [\"Roger\"]\ncreated = \"2025-01-01T00:00:00Z\"\n+++\n\n# RFC 0001\n",
+    )
+    .expect("failed to write RFC");
+    write_rfc(root, "0002-b.md", "0002", "B", "prerequisite = [1]\n", "");
+
+    let fix = workspace.run_rfc_validate(&["--check-refs", "--fix"]);
+    assert!(!fix.status.success(), "validate unexpectedly succeeded");
+    let stderr = output_stderr(&fix);
+    assert!(stderr.contains("rfc/0001-untitled.md"), "{stderr}");
+    assert!(!stderr.contains("references missing RFC"), "{stderr}");
+    let content = fs::read_to_string(root.join("rfc/0002-b.md")).expect("failed to read RFC");
+    assert!(content.contains("prerequisite = [1]\n"), "{content}");
+    assert!(!content.contains("[[revision]]"), "{content}");
+}
+
+#[test]
+fn rfc_validate_flags_and_normalizes_malformed_timestamps() {
+    let workspace = TestWorkspace::new("validate-timestamps");
+    let file = workspace.path().join("rfc/0001-dates.md");
+    fs::write(
+        &file,
+        "+++\nrfc = \"0001\"\ntitle = \"Dates\"\nauthors = [\"Roger\"]\ncreated = \"2025-01-02 03:04:05\"\nlast_updated = \"2025-01-02T03:04:05Z\"\n[[revision]]\ndate = \"not-a-date\"\nchange = \"Initial draft\"\n+++\n\n# RFC 0001: Dates\n",
+    )
+    .expect("failed to write RFC");
+
+    let check = workspace.run_rfc_validate(&[]);
+    assert!(!check.status.success(), "validate unexpectedly succeeded");
+    let stderr = output_stderr(&check);
+    assert!(stderr.contains("`created` timestamp `2025-01-02 03:04:05` is not RFC 3339"));
+    assert!(stderr.contains("`revision[0].date` has invalid timestamp `not-a-date`"));
+
+    let fix = workspace.run_rfc_validate(&["--fix"]);
+    assert!(!fix.status.success(), "unparseable dates cannot be fixed");
+    let content = fs::read_to_string(&file).expect("failed to read RFC");
+    assert!(content.contains("created = \"2025-01-02T03:04:05Z\""));
+    assert!(content.contains("date = \"not-a-date\""));
+}
+
+#[test]
+fn rfc_validate_detects_and_fixes_mismatched_heading() {
+    let workspace = TestWorkspace::new("validate-heading");
+    write_sectionless_template(workspace.path());
+    let file = workspace.path().join("rfc/0002-drifted.md");
+    fs::write(
+        &file,
+        "+++\nrfc = \"0002\"\ntitle = \"Drifted\"\nauthors = [\"Roger\"]\n+++\n\n# RFC 0001: Old Title\n\n## Summary\n\nBody.\n",
+    )
+    .expect("failed to write RFC");
+
+    let check = workspace.run_rfc_validate(&[]);
+    assert!(!check.status.success(), "validate unexpectedly succeeded");
+    assert!(output_stderr(&check).contains(
+        "heading `# RFC 0001: Old Title` does not match metadata (expected `# RFC 0002: Drifted`"
+    ));
+
+    let fix = workspace.run_rfc_validate(&["--fix"]);
+    assert!(
+        fix.status.success(),
+        "validate --fix failed:\n{}",
+        output_stderr(&fix)
+    );
+    let content = fs::read_to_string(&file).expect("failed to read fixed RFC");
+    assert!(content.contains("\n# RFC 0002: Drifted\n\n## Summary\n\nBody.\n"));
+    assert!(!content.contains("Old Title"));
+}
+
+#[test]
+fn rfc_validate_detects_and_fixes_file_name_id_mismatch() {
+    let workspace = TestWorkspace::new("validate-file-id");
+    write_sectionless_template(workspace.path());
+    let orphan = |dir: &Path, name: &str| {
+        fs::write(
+            dir.join(name),
+            "+++\nrfc = \"0007\"\ntitle = \"Orphan\"\nauthors = [\"Roger\"]\n+++\n\n# RFC 0007: Orphan\n",
+        )
+        .expect("failed to write RFC");
+    };
+    let rfc_dir = workspace.path().join("rfc");
+    orphan(&rfc_dir, "0005-orphan.md");
+
+    let check = workspace.run_rfc_validate(&[]);
+    assert!(!check.status.success(), "validate unexpectedly succeeded");
+    assert!(
+        output_stderr(&check)
+            .contains("file name id `0005` does not match metadata `rfc = \"0007\"`")
+    );
+
+    let fix = workspace.run_rfc_validate(&["--fix"]);
+    assert!(fix.status.success(), "{}", output_stderr(&fix));
+    assert!(!rfc_dir.join("0005-orphan.md").exists());
+    let renamed = fs::read_to_string(rfc_dir.join("0007-orphan.md")).expect("renamed RFC");
+    assert!(renamed.contains("rfc = \"0007\""));
+    let show = workspace.run_rfc(&["show", "7"]);
+    assert!(show.status.success(), "{}", output_stderr(&show));
+
+    fs::remove_file(rfc_dir.join("0007-orphan.md")).expect("failed to remove RFC");
+    orphan(&rfc_dir, "0005-orphan.md");
+    let fix = workspace.run_rfc_validate(&["--fix", "--id-source", "filename"]);
+    assert!(fix.status.success(), "{}", output_stderr(&fix));
+    let content = fs::read_to_string(rfc_dir.join("0005-orphan.md")).expect("fixed RFC");
+    assert!(content.contains("rfc = \"0005\""));
+    assert!(content.contains("\n# RFC 0005: Orphan\n"));
+}
+
+#[test]
+fn rfc_stats_per_rfc_json_reports_word_and_section_counts() {
+    let workspace = TestWorkspace::new("rfc-stats");
+    fs::write(
+        workspace.path().join("rfc/0001-sized.md"),
+        "+++\nrfc = \"0001\"\ntitle = \"Sized\"\nauthors = [\"Roger\"]\n+++\n\n# RFC 0001: Sized\n\n## Summary\n\none two three four five\n\n## Motivation\n\nsix seven\n\n~~~markdown\n## Sample heading\nsample words\n~~~\n\n```\n## Other sample\n```\n",
+    )
+    .expect("failed to write RFC");
+
+    let output = workspace.run_rfc(&["stats", "--per-rfc", "--format", "json"]);
+    assert!(
+        output.status.success(),
+        "rfc stats failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&output)).expect("failed to parse JSON output");
+    assert_eq!(payload["schema_version"].as_u64(), Some(1));
+    assert_eq!(payload["summary"]["rfc_count"].as_u64(), Some(1));
+    assert_eq!(payload["rfcs"][0]["rfc"].as_u64(), Some(1));
+    assert_eq!(payload["rfcs"][0]["word_count"].as_u64(), Some(12));
+    assert_eq!(payload["rfcs"][0]["section_count"].as_u64(), Some(2));
+}
+
+#[test]
+fn rfc_referrers_groups_referencing_rfcs_by_relation() {
+    let workspace = TestWorkspace::new("rfc-referrers");
+    let rfcs = [
+        ("0001-base.md", "rfc = \"0001\"\ntitle = \"Base\""),
+        (
+            "0002-extension.md",
+            "rfc = \"0002\"\ntitle = \"Extension\"\nprerequisite = [1]",
+        ),
+        (
+            "0003-replacement.md",
+            "rfc = \"0003\"\ntitle = \"Replacement\"\nsupersedes = [1]",
+        ),
+        (
+            "0004-unrelated.md",
+            "rfc = \"0004\"\ntitle = \"Unrelated\"\nprerequisite = [2]",
+        ),
+    ];
+    for (file, metadata) in rfcs {
+        fs::write(
+            workspace.path().join("rfc").join(file),
+            format!("+++\n{metadata}\n+++\n\n# RFC\n"),
+        )
+        .expect("failed to write RFC");
+    }
+
+    let output = workspace.run_rfc(&["referrers", "Base", "--format", "json"]);
+    assert!(
+        output.status.success(),
+        "rfc referrers failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&output)).expect("failed to parse JSON output");
+    assert_eq!(payload["rfc"].as_u64(), Some(1));
+    let relations = payload["relations"]
+        .as_array()
+        .expect("relations should be an array");
+    assert_eq!(relations.len(), 2);
+    assert_eq!(relations[0]["relation"], "prerequisite");
+    assert_eq!(relations[0]["referrers"][0]["rfc"].as_u64(), Some(2));
+    assert_eq!(relations[1]["relation"], "supersedes");
+    assert_eq!(relations[1]["referrers"][0]["rfc"].as_u64(), Some(3));
+
+    let text = workspace.run_rfc(&["referrers", "1"]);
+    let stdout = output_stdout(&text);
+    assert!(stdout.contains("prerequisite:\n  0002\tExtension"));
+    assert!(stdout.contains("supersedes:\n  0003\tReplacement"));
+    assert!(!stdout.contains("Unrelated"));
+}
+
+#[test]
+fn rfc_index_links_each_rfc_by_relative_path() {
+    let workspace = TestWorkspace::new("rfc-index");
+    for title in ["First RFC", "Second RFC"] {
+        let create = workspace.run_rfc_new(&["--author", "Roger", title]);
+        assert!(create.status.success(), "{}", output_stderr(&create));
+    }
+
+    let output = workspace.run_rfc(&["index"]);
+    assert!(
+        output.status.success(),
+        "rfc index failed:\n{}",
+        output_stderr(&output)
+    );
+    let index_path = workspace.path().join("rfc/README.md");
+    let index = fs::read_to_string(&index_path).expect("failed to read rfc/README.md");
+    assert!(index.contains("| 0001 | [First RFC](0001-first-rfc.md) | draft | Roger |\n"));
+    assert!(index.contains("| 0002 | [Second RFC](0002-second-rfc.md) | draft | Roger |\n"));
+    assert!(!index.contains("0000-template.md"));
+
+    let rerun = workspace.run_rfc(&["index", "--stdout"]);
+    assert!(rerun.status.success(), "{}", output_stderr(&rerun));
+    assert_eq!(output_stdout(&rerun), index);
+
+    let fresh = workspace.run_rfc(&["index", "--check"]);
+    assert!(fresh.status.success(), "{}", output_stderr(&fresh));
+}
+
+#[test]
+fn rfc_index_preserves_preamble_and_check_detects_stale_index() {
+    let workspace = TestWorkspace::new("rfc-index-check");
+    let create = workspace.run_rfc_new(&["--author", "Roger", "First RFC"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+
+    let missing = workspace.run_rfc(&["index", "--check"]);
+    assert!(!missing.status.success());
+    assert!(output_stderr(&missing).contains("is out of date"));
+
+    let index = workspace.run_rfc(&["index"]);
+    assert!(index.status.success(), "{}", output_stderr(&index));
+    let index_path = workspace.path().join("rfc/README.md");
+    let generated = fs::read_to_string(&index_path).expect("failed to read rfc/README.md");
+    let customized = generated.replacen(
+        "# RFC Index\n",
+        "# Project RFCs\n\nSee CONTRIBUTING.md before opening an RFC.\n",
+        1,
+    );
+    fs::write(&index_path, &customized).expect("failed to customize index");
+
+    let create = workspace.run_rfc_new(&["--author", "Alice", "Second RFC"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let stale = workspace.run_rfc(&["index", "--check"]);
+    assert!(!stale.status.success());
+    assert_eq!(
+        fs::read_to_string(&index_path).expect("failed to read rfc/README.md"),
+        customized
+    );
+
+    let regenerate = workspace.run_rfc(&["index"]);
+    assert!(
+        regenerate.status.success(),
+        "{}",
+        output_stderr(&regenerate)
+    );
+    let regenerated = fs::read_to_string(&index_path).expect("failed to read rfc/README.md");
+    assert!(regenerated.starts_with(
+        "# Project RFCs\n\nSee CONTRIBUTING.md before opening an RFC.\n\n<!-- Generated"
+    ));
+    assert!(!regenerated.contains("# RFC Index"));
+    assert!(regenerated.contains("| 0002 | [Second RFC](0002-second-rfc.md) | draft | Alice |\n"));
+
+    let fresh = workspace.run_rfc(&["index", "--check"]);
+    assert!(fresh.status.success(), "{}", output_stderr(&fresh));
+}
+
+#[test]
+fn create_mode_requires_some_title_input() {
+    let workspace = TestWorkspace::new("missing-title");
+    let output = workspace.run_rfc_new(&["--author", "Roger"]);
+
+    assert!(!output.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&output).contains("missing <title>"));
+}
+
+#[test]
+fn rfc_new_help_output_includes_command_docs_and_examples() {
+    let workspace = TestWorkspace::new("help-output");
+    let output = workspace.run_rfc(&["new", "--help"]);
+
+    assert!(
+        output.status.success(),
+        "help command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let help = output_stdout(&output);
+    assert!(help.contains("Create a new RFC markdown file with TOML metadata."));
+    assert!(help.contains("Creates a new RFC file from `rfc/0000-template.md`"));
+    assert!(help.contains("Add an author to metadata."));
+    assert!(help.contains("List prerequisite RFC references (id, title, or `latest`)"));
+    assert!(help.contains("Examples:"));
+    assert!(help.contains("agx rfc new --author Roger --title \"Add parser support\""));
+}
+
+#[test]
+fn root_help_lists_rfc_init_and_skill_subcommands() {
+    let workspace = TestWorkspace::new("root-help-output");
+    let output = workspace.run_cli(&["--help"]);
+
+    assert!(
+        output.status.success(),
+        "help command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let help = output_stdout(&output);
+    assert!(help.contains("Manage agent workflow tooling"));
+    assert!(help.contains("\n  rfc "));
+    assert!(help.contains("\n  skill "));
+    assert!(!help.contains("\n  init "));
+}
+
+#[test]
+fn strict_template_rejects_undefined_condition_variables() {
+    let workspace = TestWorkspace::new("strict-template");
+    fs::write(
+        workspace.path().join("Cargo.toml"),
+        "[package]\nname = \"strict-template\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .expect("failed to write crate manifest");
+    write_template(
+        &workspace.path().join("rfc/0000-template.md"),
+        "before{% if titel %}Typo: {{ titel }}{% endif %}after",
+    );
+
+    let strict = workspace.run_rfc_new(&["--author", "Roger", "--strict-template", "Strict RFC"]);
+    assert!(!strict.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&strict).contains("template references undefined variable(s): `titel`"));
+    assert!(!workspace.path().join("rfc/0001-strict-rfc.md").exists());
+
+    let lenient = workspace.run_rfc_new(&["--author", "Roger", "Lenient RFC"]);
+    assert!(lenient.status.success(), "{}", output_stderr(&lenient));
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-lenient-rfc.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("\nbeforeafter\n"));
+
+    fs::remove_file(workspace.path().join("rfc/0000-template.md")).expect("failed to remove");
+    let embedded = workspace.run_rfc_new(&["--author", "Roger", "--strict-template", "Embedded"]);
+    assert!(embedded.status.success(), "{}", output_stderr(&embedded));
+}
+
+#[test]
+fn create_from_copies_source_body_with_fresh_metadata() {
+    let workspace = TestWorkspace::new("create-from");
+    fs::write(
+        workspace.path().join("Cargo.toml"),
+        "[package]\nname = \"create-from\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .expect("failed to write crate manifest");
+    write_template(
+        &workspace.path().join("rfc/0000-template.md"),
+        "TEMPLATE-BODY",
+    );
+
+    let source = workspace.run_rfc_new(&["--author", "Roger", "Parser v1"]);
+    assert!(source.status.success(), "{}", output_stderr(&source));
+    let source_path = workspace.path().join("rfc/0001-parser-v1.md");
+    let content = fs::read_to_string(&source_path).expect("failed to read RFC");
+    fs::write(
+        &source_path,
+        content.replace("TEMPLATE-BODY", "## Motivation\n\nSource-only text."),
+    )
+    .expect("failed to edit RFC");
+    let revise = workspace.run_rfc_revise(&["--author", "Alice", "1"]);
+    assert!(revise.status.success(), "{}", output_stderr(&revise));
+
+    let create = workspace.run_rfc_new(&["--author", "Bob", "--from", "1", "Parser v2"]);
+    assert!(create.status.success(), "{}", output_stderr(&create));
+    let content = fs::read_to_string(workspace.path().join("rfc/0002-parser-v2.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("rfc = \"0002\"\ntitle = \"Parser v2\"\n"));
+    assert!(content.contains("# RFC 0002: Parser v2\n\n## Motivation\n\nSource-only text.\n"));
+    assert!(!content.contains("TEMPLATE-BODY"));
+    assert!(!content.contains("RFC 0001"));
+    assert_eq!(content.matches("[[revision]]").count(), 1);
+    assert!(!content.contains("Alice"));
+
+    let missing = workspace.run_rfc_new(&["--author", "Bob", "--from", "7", "Parser v3"]);
+    assert!(!missing.status.success());
+    assert!(output_stderr(&missing).contains("unable to locate RFC for selector `7`"));
+}
+
+#[test]
+fn create_mode_json_format_reports_created_rfc() {
+    let workspace = TestWorkspace::new("create-json");
+    let output =
+        workspace.run_rfc_new(&["--author", "Roger", "--format", "json", "Parser support"]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+    let payload: Value = serde_json::from_str(&output_stdout(&output)).expect("invalid JSON");
+    assert_eq!(
+        payload,
+        serde_json::json!({
+            "schema_version": 1,
+            "path": "rfc/0001-parser-support.md",
+            "rfc_id": "0001",
+            "title": "Parser support",
+        })
+    );
+    assert!(
+        workspace
+            .path()
+            .join("rfc/0001-parser-support.md")
+            .is_file()
+    );
+}
+
+#[test]
+fn create_mode_prefers_template_from_crate_root() {
+    let workspace = TestWorkspace::new("crate-template");
+    fs::write(
+        workspace.path().join("Cargo.toml"),
+        "[package]\nname = \"crate-template\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .expect("failed to write crate manifest");
+    write_template(
+        &workspace.path().join("rfc/0000-template.md"),
+        "crate-root-template",
+    );
+
+    let output = workspace.run_rfc_new(&["--author", "Roger", "Crate Template"]);
+    assert!(
+        output.status.success(),
+        "command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let file = workspace.path().join("rfc/0001-crate-template.md");
+    let content = fs::read_to_string(file).expect("failed to read created RFC");
+    assert!(content.contains("crate-root-template"));
+}
+
+#[test]
+fn create_mode_renders_project_name_in_template() {
+    let workspace = TestWorkspace::new("project-name-template");
+    fs::write(
+        workspace.path().join("Cargo.toml"),
+        "[package]\nname = \"parser-kit\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .expect("failed to write crate manifest");
+    write_template(
+        &workspace.path().join("rfc/0000-template.md"),
+        "Project: {{ project_name }}",
+    );
+
+    let output = workspace.run_rfc_new(&["--author", "Roger", "Named project"]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+    let content = fs::read_to_string(workspace.path().join("rfc/0001-named-project.md"))
+        .expect("failed to read created RFC");
+    assert!(content.contains("Project: parser-kit"), "{content}");
+
+    fs::write(
+        workspace.path().join("Cargo.toml"),
+        "[workspace]\nmembers = []\nresolver = \"2\"\n",
+    )
+    .expect("failed to write workspace manifest");
+    let output = workspace.run_rfc_new(&["--author", "Roger", "Virtual workspace"]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+    let content = fs::read_to_string(workspace.path().join("rfc/0002-virtual-workspace.md"))
+        .expect("failed to read created RFC");
+    let dir_name = workspace
+        .path()
+        .file_name()
+        .and_then(|name| name.to_str())
+        .expect("workspace should have a directory name");
+    assert!(
+        content.contains(&format!("Project: {dir_name}")),
+        "{content}"
+    );
+}
+
+#[test]
+fn skill_root_reports_workspace_root_from_member_crate() {
+    let workspace = TestWorkspace::new("skill-root");
+    fs::write(
+        workspace.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/member\"]\nresolver = \"2\"\n",
+    )
+    .expect("failed to write workspace manifest");
+    fs::create_dir_all(workspace.path().join("crates/member"))
+        .expect("failed to create member directory");
+    fs::write(
+        workspace.path().join("crates/member/Cargo.toml"),
+        "[package]\nname = \"member\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .expect("failed to write member manifest");
+
+    let output = workspace.run_cli_in("crates/member", &["skill", "root", "--format", "json"]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&output)).expect("failed to parse JSON output");
+    let root = workspace
+        .path()
+        .canonicalize()
+        .expect("failed to canonicalize workspace");
+    let reported = |key: &str| {
+        Path::new(payload[key].as_str().expect("root should be reported"))
+            .canonicalize()
+            .ok()
+    };
+    assert_eq!(payload["schema_version"], 1);
+    assert_eq!(reported("workspace_root"), Some(root.clone()));
+    assert_eq!(reported("crate_root"), Some(root.join("crates/member")));
+    let workspace_root = payload["workspace_root"].as_str().unwrap_or_default();
+    assert_eq!(
+        payload["skills_root"],
+        format!("{workspace_root}/.agents/skills")
+    );
+    assert_eq!(payload["search_roots"][0], ".agents/skills");
+
+    let text = workspace.run_cli_in("crates/member", &["skill", "root"]);
+    assert!(text.status.success(), "{}", output_stderr(&text));
+    assert!(output_stdout(&text).starts_with("workspace_root\t"));
+}
+
+#[test]
+fn create_mode_prefers_workspace_root_template_from_member_crate() {
+    let workspace = TestWorkspace::new("workspace-template");
+    fs::write(
+        workspace.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/member\"]\nresolver = \"2\"\n",
+    )
+    .expect("failed to write workspace manifest");
+    fs::create_dir_all(workspace.path().join("crates/member/rfc"))
+        .expect("failed to create member rfc directory");
+    fs::write(
+        workspace.path().join("crates/member/Cargo.toml"),
+        "[package]\nname = \"member\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .expect("failed to write member manifest");
+    write_template(
+        &workspace.path().join("rfc/0000-template.md"),
+        "workspace-root-template",
+    );
+
+    let output = workspace.run_rfc_new_in("crates/member", &["--author", "Roger", "Workspace RFC"]);
+    assert!(
+        output.status.success(),
+        "command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let file = workspace
+        .path()
+        .join("crates/member/rfc/0001-workspace-rfc.md");
+    let content = fs::read_to_string(file).expect("failed to read created RFC");
+    assert!(content.contains("workspace-root-template"));
+}
+
+#[test]
+fn member_crate_resolves_reference_titles_from_workspace_rfc_directory() {
+    let workspace = TestWorkspace::new("workspace-reference-resolution");
+    fs::write(
+        workspace.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/member\"]\nresolver = \"2\"\n",
+    )
+    .expect("failed to write workspace manifest");
+    fs::create_dir_all(workspace.path().join("crates/member/rfc"))
+        .expect("failed to create member rfc directory");
+    fs::write(
+        workspace.path().join("crates/member/Cargo.toml"),
+        "[package]\nname = \"member\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .expect("failed to write member manifest");
+
+    let base = workspace.run_rfc_new(&["--author", "Roger", "--title", "Workspace Base"]);
+    assert!(
+        base.status.success(),
+        "base create failed:\n{}",
+        output_stderr(&base)
+    );
+
+    let output = workspace.run_rfc_new_in(
+        "crates/member",
+        &[
+            "--author",
+            "Roger",
+            "--prerequisite",
+            "Workspace Base",
+            "--title",
+            "Member RFC",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let file = workspace
+        .path()
+        .join("crates/member/rfc/0001-member-rfc.md");
+    let content = fs::read_to_string(file).expect("failed to read created RFC");
+    assert!(content.contains("prerequisite = [1]"));
+}
+
+#[test]
+fn create_mode_prefers_workspace_template_over_member_template() {
+    let workspace = TestWorkspace::new("workspace-precedence");
+    fs::write(
+        workspace.path().join("Cargo.toml"),
+        "[workspace]\nmembers = [\"crates/member\"]\nresolver = \"2\"\n",
+    )
+    .expect("failed to write workspace manifest");
+    fs::create_dir_all(workspace.path().join("crates/member/rfc"))
+        .expect("failed to create member rfc directory");
+    fs::write(
+        workspace.path().join("crates/member/Cargo.toml"),
+        "[package]\nname = \"member\"\nversion = \"0.1.0\"\nedition = \"2024\"\n",
+    )
+    .expect("failed to write member manifest");
+    write_template(
+        &workspace.path().join("rfc/0000-template.md"),
+        "workspace-root-template",
+    );
+    write_template(
+        &workspace.path().join("crates/member/rfc/0000-template.md"),
+        "member-template",
+    );
+
+    let output =
+        workspace.run_rfc_new_in("crates/member", &["--author", "Roger", "Workspace Wins"]);
+    assert!(
+        output.status.success(),
+        "command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let file = workspace
+        .path()
+        .join("crates/member/rfc/0001-workspace-wins.md");
+    let content = fs::read_to_string(file).expect("failed to read created RFC");
+    assert!(content.contains("workspace-root-template"));
+    assert!(!content.contains("member-template"));
+}
+
+#[test]
+fn create_mode_falls_back_to_embedded_template_when_project_template_missing() {
+    let workspace = TestWorkspace::new("embedded-template");
+    fs::remove_file(workspace.path().join("rfc/0000-template.md"))
+        .expect("failed to remove seeded template");
+
+    let output = workspace.run_rfc_new(&["--author", "Roger", "Embedded Template"]);
+    assert!(
+        output.status.success(),
+        "command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let file = workspace.path().join("rfc/0001-embedded-template.md");
+    let content = fs::read_to_string(file).expect("failed to read created RFC");
+    assert!(content.contains("## Future possibilities"));
+}
+
+#[test]
+fn rfc_init_requires_skills_root_and_hints_skill_dump() {
+    let workspace = TestWorkspace::new("init-subcommand-requires-skills");
+    fs::remove_dir_all(workspace.path().join("rfc")).expect("failed to remove rfc directory");
+    assert!(!workspace.path().join(".agents").exists());
+    assert!(!workspace.path().join(".agents/skills").exists());
+
+    let output = workspace.run_rfc_init();
+    assert!(!output.status.success(), "rfc init unexpectedly succeeded");
+
+    let stderr = output_stderr(&output);
+    assert!(stderr.contains(".agents/skills"));
+    assert!(stderr.contains("agx skill dump --all"));
+    assert!(!workspace.path().join("rfc").exists());
+}
+
+#[test]
+fn rfc_init_succeeds_when_skills_root_exists() {
+    let workspace = TestWorkspace::new("init-subcommand-success");
+    fs::remove_dir_all(workspace.path().join("rfc")).expect("failed to remove rfc directory");
+    fs::create_dir_all(workspace.path().join(".agents/skills"))
+        .expect("failed to create skills root");
+
+    let output = workspace.run_rfc_init();
+    assert!(
         output.status.success(),
-        "command failed:\n{}",
+        "rfc init command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    assert!(workspace.path().join("rfc").is_dir());
+    assert!(
+        workspace.path().join("rfc/0000-template.md").is_file(),
+        "rfc init should materialize the embedded template"
+    );
+    let template = fs::read_to_string(workspace.path().join("rfc/0000-template.md"))
+        .expect("failed to read materialized template");
+    assert!(template.contains("## Future possibilities"));
+    assert!(workspace.path().join(".agents/skills").is_dir());
+    assert!(
+        !workspace
+            .path()
+            .join(".agents/skills/create-rfc/SKILL.md")
+            .exists()
+    );
+    assert_eq!(output_stdout(&output).trim(), "rfc");
+}
+
+#[test]
+fn rfc_init_does_not_overwrite_existing_template() {
+    let workspace = TestWorkspace::new("init-subcommand-no-overwrite-template");
+    fs::create_dir_all(workspace.path().join(".agents/skills"))
+        .expect("failed to create skills root");
+    fs::write(
+        workspace.path().join("rfc/0000-template.md"),
+        "+++\ncustom = true\n+++\n\n# custom template\n",
+    )
+    .expect("failed to write custom template");
+
+    let output = workspace.run_rfc_init();
+    assert!(
+        output.status.success(),
+        "rfc init command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let template = fs::read_to_string(workspace.path().join("rfc/0000-template.md"))
+        .expect("failed to read template");
+    assert!(template.contains("custom template"));
+    assert!(!template.contains("## Future possibilities"));
+}
+
+#[test]
+fn skill_init_creates_skills_root_and_seeds_builtins() {
+    let workspace = TestWorkspace::new("skill-init");
+    assert!(!workspace.path().join(".agents").exists());
+
+    let output = workspace.run_skill_init();
+    assert!(
+        output.status.success(),
+        "skill init command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    assert!(workspace.path().join(".agents/skills").is_dir());
+    assert!(
+        workspace
+            .path()
+            .join(".agents/skills/ask-user-question/SKILL.md")
+            .is_file()
+    );
+    assert!(
+        workspace
+            .path()
+            .join(".agents/skills/new-rfc-skill-creation-skill/SKILL.md")
+            .is_file()
+    );
+    assert!(
+        workspace
+            .path()
+            .join(".agents/skills/new-rfc-skill-creation-skill/references/rfc-skill-template.md")
+            .is_file()
+    );
+
+    let stdout = output_stdout(&output);
+    assert!(stdout.contains("use the code agent"));
+    assert!(stdout.contains("RFC skills"));
+    assert!(stdout.contains("recommended prompt"));
+    assert!(stdout.contains("> Use $new-rfc-skill-creation-skill"));
+    assert!(stdout.contains("named `new-rfc`"));
+    assert!(stdout.contains("feedback"));
+    assert!(stdout.contains("copied recommended prompt to clipboard"));
+}
+
+#[test]
+fn skill_init_no_dump_creates_only_skills_root() {
+    let workspace = TestWorkspace::new("skill-init-no-dump");
+    assert!(!workspace.path().join(".agents").exists());
+
+    let output = workspace.run_skill(&["init", "--no-dump"]);
+    assert!(
+        output.status.success(),
+        "skill init --no-dump command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    assert!(workspace.path().join(".agents/skills").is_dir());
+    assert!(
+        !workspace
+            .path()
+            .join(".agents/skills/ask-user-question/SKILL.md")
+            .exists()
+    );
+    assert!(
+        !workspace
+            .path()
+            .join(".agents/skills/new-rfc-skill-creation-skill/SKILL.md")
+            .exists()
+    );
+
+    let stdout = output_stdout(&output);
+    assert!(stdout.contains("use the code agent"));
+    assert!(stdout.contains("RFC skills"));
+    assert!(stdout.contains("recommended prompt"));
+    assert!(stdout.contains("> Use $new-rfc-skill-creation-skill"));
+    assert!(stdout.contains("named `new-rfc`"));
+    assert!(stdout.contains("feedback"));
+    assert!(stdout.contains("copied recommended prompt to clipboard"));
+}
+
+#[test]
+fn skill_new_scaffolds_named_skill() {
+    let workspace = TestWorkspace::new("skill-new");
+
+    let output = workspace.run_skill_new("ask-user-question");
+    assert!(
+        output.status.success(),
+        "skill new command failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let skill_dir = workspace.path().join(".agents/skills/ask-user-question");
+    assert!(skill_dir.is_dir());
+    assert!(skill_dir.join("agents").is_dir());
+
+    let skill_md = fs::read_to_string(skill_dir.join("SKILL.md")).expect("failed to read SKILL.md");
+    assert!(skill_md.contains("name: ask-user-question"));
+    assert!(skill_md.contains("description:"));
+
+    let openai_yaml = fs::read_to_string(skill_dir.join("agents/openai.yaml"))
+        .expect("failed to read openai.yaml");
+    assert!(openai_yaml.contains("interface:"));
+}
+
+#[test]
+fn skill_new_scaffolds_requested_agent_formats_and_validates_each() {
+    let workspace = TestWorkspace::new("skill-agent-formats");
+    let output = workspace.run_skill(&[
+        "new",
+        "multi-agent",
+        "--agent-format",
+        "claude",
+        "--agent-format",
+        "cursor",
+        "--agent-format",
+        "claude",
+    ]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+
+    let agents_dir = workspace.path().join(".agents/skills/multi-agent/agents");
+    assert!(!agents_dir.join("openai.yaml").exists());
+    for config in ["claude.yaml", "cursor.yaml"] {
+        let text = fs::read_to_string(agents_dir.join(config)).expect("failed to read config");
+        assert!(text.starts_with("interface:\n"), "{config}: {text}");
+        assert!(text.contains("default_prompt: \"Use $multi-agent to help with this task.\""));
+    }
+    let strict = workspace.run_skill(&["validate", "multi-agent", "--strict"]);
+    assert!(strict.status.success(), "{}", output_stderr(&strict));
+
+    fs::write(agents_dir.join("cursor.yaml"), "rules: []\n").expect("failed to write config");
+    let invalid = workspace.run_skill_validate(Some("multi-agent"));
+    assert!(
+        !invalid.status.success(),
+        "invalid cursor.yaml unexpectedly passed"
+    );
+    assert!(
+        output_stderr(&invalid).contains("agents/cursor.yaml`: missing `interface` mapping"),
+        "{}",
+        output_stderr(&invalid)
+    );
+}
+
+#[test]
+fn skill_new_suggests_normalized_name_for_invalid_input() {
+    let workspace = TestWorkspace::new("skill-new-normalize");
+
+    let rejected = workspace.run_skill_new("Ask User Question");
+    assert!(
+        !rejected.status.success(),
+        "skill new unexpectedly succeeded"
+    );
+    assert!(output_stderr(&rejected).contains("did you mean `ask-user-question`?"));
+    assert!(
+        !workspace
+            .path()
+            .join(".agents/skills/Ask User Question")
+            .exists()
+    );
+
+    let normalized = workspace.run_skill(&["new", "Ask User Question", "--normalize"]);
+    assert!(
+        normalized.status.success(),
+        "skill new --normalize failed:\n{}",
+        output_stderr(&normalized)
+    );
+    assert!(
+        workspace
+            .path()
+            .join(".agents/skills/ask-user-question/SKILL.md")
+            .is_file()
+    );
+}
+
+#[test]
+fn skill_remove_requires_force_without_a_terminal() {
+    let workspace = TestWorkspace::new("skill-remove");
+    let new_skill = workspace.run_skill_new("old-skill");
+    assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+    let skill_dir = workspace.path().join(".agents/skills/old-skill");
+
+    let unconfirmed = workspace.run_skill(&["remove", "old-skill"]);
+    assert!(
+        !unconfirmed.status.success(),
+        "removal ran without confirmation"
+    );
+    assert!(output_stderr(&unconfirmed).contains("pass --force"));
+    assert!(skill_dir.join("SKILL.md").is_file());
+
+    let removed = workspace.run_skill(&["remove", "old-skill", "--force"]);
+    assert!(removed.status.success(), "{}", output_stderr(&removed));
+    assert_eq!(output_stdout(&removed), ".agents/skills/old-skill\n");
+    assert!(!skill_dir.exists());
+
+    let missing = workspace.run_skill(&["remove", "old-skill", "--force"]);
+    assert!(
+        !missing.status.success(),
+        "missing skill unexpectedly removed"
+    );
+    assert!(output_stderr(&missing).contains("skill `old-skill` was not found"));
+
+    let traversal = workspace.run_skill(&["remove", "../../rfc", "--force"]);
+    assert!(
+        !traversal.status.success(),
+        "traversal unexpectedly accepted"
+    );
+    assert!(output_stderr(&traversal).contains("skill name must contain only"));
+}
+
+#[cfg(unix)]
+#[test]
+fn skill_remove_refuses_symlinks_outside_skills_root() {
+    let workspace = TestWorkspace::new("skill-remove-symlink");
+    let init = workspace.run_skill(&["init", "--no-dump"]);
+    assert!(init.status.success(), "{}", output_stderr(&init));
+    let outside = workspace.path().join("outside-skill");
+    fs::create_dir(&outside).expect("failed to create outside skill");
+    fs::write(outside.join("SKILL.md"), "---\nname: linked\n---\n")
+        .expect("failed to write SKILL.md");
+    std::os::unix::fs::symlink(&outside, workspace.path().join(".agents/skills/linked"))
+        .expect("failed to create symlink");
+
+    let refused = workspace.run_skill(&["remove", "linked", "--force"]);
+    assert!(
+        !refused.status.success(),
+        "symlinked skill unexpectedly removed"
+    );
+    assert!(
+        output_stderr(&refused).contains("outside `.agents/skills`"),
+        "{}",
+        output_stderr(&refused)
+    );
+    assert!(outside.join("SKILL.md").is_file());
+}
+
+#[test]
+fn skill_rename_moves_directory_and_updates_metadata() {
+    let workspace = TestWorkspace::new("skill-rename");
+    let new_skill = workspace.run_skill_new("triage");
+    assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+    let other = workspace.run_skill_new("sorter");
+    assert!(other.status.success(), "{}", output_stderr(&other));
+
+    let taken = workspace.run_skill(&["rename", "triage", "sorter"]);
+    assert!(
+        !taken.status.success(),
+        "rename onto an existing skill succeeded"
+    );
+    assert!(output_stderr(&taken).contains("already exists"));
+    let invalid = workspace.run_skill(&["rename", "triage", "Issue Triage"]);
+    assert!(
+        !invalid.status.success(),
+        "invalid name unexpectedly accepted"
+    );
+
+    let renamed = workspace.run_skill(&["rename", "triage", "issue-triage"]);
+    assert!(renamed.status.success(), "{}", output_stderr(&renamed));
+    assert!(output_stdout(&renamed).contains(".agents/skills/issue-triage\n"));
+    let skills = workspace.path().join(".agents/skills");
+    assert!(!skills.join("triage").exists());
+    let skill_md =
+        fs::read_to_string(skills.join("issue-triage/SKILL.md")).expect("failed to read SKILL.md");
+    assert!(
+        skill_md.starts_with("---\nname: issue-triage\n"),
+        "{skill_md}"
+    );
+    let openai_yaml = fs::read_to_string(skills.join("issue-triage/agents/openai.yaml"))
+        .expect("failed to read openai.yaml");
+    assert!(
+        openai_yaml.contains("display_name: \"Issue Triage\""),
+        "{openai_yaml}"
+    );
+    assert!(
+        openai_yaml.contains("default_prompt: \"Use $issue-triage to help with this task.\""),
+        "{openai_yaml}"
+    );
+
+    let validate = workspace.run_skill_validate(Some("issue-triage"));
+    assert!(validate.status.success(), "{}", output_stderr(&validate));
+}
+
+#[cfg(unix)]
+#[test]
+fn skill_edit_opens_skill_md_in_editor() {
+    use std::os::unix::fs::PermissionsExt;
+
+    let workspace = TestWorkspace::new("skill-edit");
+    let new_skill = workspace.run_skill_new("ask-user-question");
+    assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+
+    let editor = workspace.path().join("stub-editor.sh");
+    fs::write(&editor, "#!/bin/sh\nprintf '%s' \"$@\" > editor-args.txt\n")
+        .expect("failed to write stub editor");
+    fs::set_permissions(&editor, fs::Permissions::from_mode(0o755))
+        .expect("failed to mark stub editor executable");
+
+    let output = workspace.run_cli_with_env(
+        &["skill", "edit", "ask-user-question"],
+        &[(
+            "EDITOR",
+            editor.to_str().expect("editor path should be UTF-8"),
+        )],
+    );
+    assert!(
+        output.status.success(),
+        "skill edit failed:\n{}",
         output_stderr(&output)
     );
 
-    let file = workspace.path().join("rfc/0001-embedded-template.md");
-    let content = fs::read_to_string(file).expect("failed to read created RFC");
-    assert!(content.contains("## Future possibilities"));
+    let invoked = fs::read_to_string(workspace.path().join("editor-args.txt"))
+        .expect("stub editor was not invoked");
+    assert_eq!(invoked, ".agents/skills/ask-user-question/SKILL.md");
 }
 
 #[test]
-fn rfc_init_requires_skills_root_and_hints_skill_dump() {
-    let workspace = TestWorkspace::new("init-subcommand-requires-skills");
-    fs::remove_dir_all(workspace.path().join("rfc")).expect("failed to remove rfc directory");
-    assert!(!workspace.path().join(".agents").exists());
-    assert!(!workspace.path().join(".agents/skills").exists());
+fn skill_version_bumps_patch_in_frontmatter_and_agent_config() {
+    let workspace = TestWorkspace::new("skill-version");
+    let skill_dir = workspace.path().join(".agents/skills/versioned");
+    fs::create_dir_all(skill_dir.join("agents")).expect("failed to create skill dir");
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: versioned\ndescription: Versioned skill.\nversion: 1.2.3\n---\n\n# Versioned\n",
+    )
+    .expect("failed to write SKILL.md");
+    fs::write(
+        skill_dir.join("agents/openai.yaml"),
+        "interface:\n  display_name: \"Versioned\"\n  default_prompt: \"Use $versioned.\"\n  version: \"1.2.3\"\n",
+    )
+    .expect("failed to write openai.yaml");
 
-    let output = workspace.run_rfc_init();
-    assert!(!output.status.success(), "rfc init unexpectedly succeeded");
+    let output = workspace.run_skill(&["version", "versioned", "--bump", "patch"]);
+    assert!(
+        output.status.success(),
+        "skill version failed:\n{}",
+        output_stderr(&output)
+    );
+    assert!(output_stdout(&output).contains("1.2.3 -> 1.2.4"));
 
-    let stderr = output_stderr(&output);
-    assert!(stderr.contains(".agents/skills"));
-    assert!(stderr.contains("agx skill dump --all"));
-    assert!(!workspace.path().join("rfc").exists());
+    let skill_md = fs::read_to_string(skill_dir.join("SKILL.md")).expect("failed to read SKILL.md");
+    assert!(skill_md.contains("\nversion: 1.2.4\n---\n\n# Versioned\n"));
+    let openai =
+        fs::read_to_string(skill_dir.join("agents/openai.yaml")).expect("failed to read yaml");
+    assert!(openai.contains("  version: \"1.2.4\"\n"));
+
+    let validate = workspace.run_skill_validate(Some("versioned"));
+    assert!(validate.status.success(), "{}", output_stderr(&validate));
 }
 
 #[test]
-fn rfc_init_succeeds_when_skills_root_exists() {
-    let workspace = TestWorkspace::new("init-subcommand-success");
-    fs::remove_dir_all(workspace.path().join("rfc")).expect("failed to remove rfc directory");
-    fs::create_dir_all(workspace.path().join(".agents/skills"))
-        .expect("failed to create skills root");
+fn skill_which_reports_workspace_skill_shadowing_builtin() {
+    let workspace = TestWorkspace::new("skill-which");
+    let builtin = workspace.run_skill(&["which", "ask-user-question"]);
+    assert!(builtin.status.success(), "{}", output_stderr(&builtin));
+    assert_eq!(
+        output_stdout(&builtin).trim(),
+        "ask-user-question\tbuiltin\t-"
+    );
 
-    let output = workspace.run_rfc_init();
+    let created = workspace.run_skill_new("ask-user-question");
+    assert!(created.status.success(), "{}", output_stderr(&created));
+    let output = workspace.run_skill(&["which", "ask-user-question", "--format", "json"]);
     assert!(
         output.status.success(),
-        "rfc init command failed:\n{}",
+        "skill which failed:\n{}",
         output_stderr(&output)
     );
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&output)).expect("failed to parse JSON output");
+    assert_eq!(payload["name"], "ask-user-question");
+    assert_eq!(payload["resolved_from"], "workspace");
+    assert_eq!(payload["path"], ".agents/skills/ask-user-question");
 
-    assert!(workspace.path().join("rfc").is_dir());
-    assert!(
-        workspace.path().join("rfc/0000-template.md").is_file(),
-        "rfc init should materialize the embedded template"
+    let missing = workspace.run_skill(&["which", "no-such-skill"]);
+    assert!(!missing.status.success(), "command unexpectedly succeeded");
+}
+
+#[test]
+fn skill_list_json_reports_declared_capabilities() {
+    let workspace = TestWorkspace::new("skill-capabilities");
+    let skill_dir = workspace.path().join(".agents/skills/capable-skill");
+    fs::create_dir_all(&skill_dir).expect("failed to create skill directory");
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: capable-skill\ndescription: Declares capabilities.\ncapabilities:\n  - shell\n  - web-search\n---\n\n# Capable Skill\n",
+    )
+    .expect("failed to write SKILL.md");
+
+    let output = workspace.run_skill(&["list", "--format", "json"]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&output)).expect("failed to parse JSON output");
+    let skills = payload["skills"]
+        .as_array()
+        .expect("skills should be array");
+    let capable = skills
+        .iter()
+        .find(|skill| skill["name"] == "capable-skill")
+        .expect("workspace skill should be listed");
+    assert_eq!(
+        capable["capabilities"],
+        serde_json::json!(["shell", "web-search"])
     );
-    let template = fs::read_to_string(workspace.path().join("rfc/0000-template.md"))
-        .expect("failed to read materialized template");
-    assert!(template.contains("## Future possibilities"));
-    assert!(workspace.path().join(".agents/skills").is_dir());
-    assert!(
-        !workspace
-            .path()
-            .join(".agents/skills/create-rfc/SKILL.md")
-            .exists()
+    let builtin = skills
+        .iter()
+        .find(|skill| skill["name"] == "ask-user-question")
+        .expect("builtin skill should be listed");
+    assert_eq!(
+        builtin["capabilities"],
+        serde_json::json!(["requirements-interview"])
     );
-    assert_eq!(output_stdout(&output).trim(), "rfc");
+
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: capable-skill\ndescription: Declares capabilities.\ncapabilities: [\"\"]\n---\n\n# Capable Skill\n",
+    )
+    .expect("failed to write SKILL.md");
+    let invalid = workspace.run_skill_validate(Some("capable-skill"));
+    assert!(!invalid.status.success(), "validate unexpectedly succeeded");
+    assert!(output_stderr(&invalid).contains("`capabilities` must contain non-empty strings"));
 }
 
 #[test]
-fn rfc_init_does_not_overwrite_existing_template() {
-    let workspace = TestWorkspace::new("init-subcommand-no-overwrite-template");
-    fs::create_dir_all(workspace.path().join(".agents/skills"))
-        .expect("failed to create skills root");
+fn skill_list_lang_prefers_localized_description() {
+    let workspace = TestWorkspace::new("skill-list-lang");
+    let skills_root = workspace.path().join(".agents/skills");
+    for (name, frontmatter) in [
+        (
+            "localized-skill",
+            "description: Plans work.\ndescription.fr: Planifie le travail.\n",
+        ),
+        ("plain-skill", "description: Stays in English.\n"),
+    ] {
+        let skill_dir = skills_root.join(name);
+        fs::create_dir_all(&skill_dir).expect("failed to create skill directory");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            format!("---\nname: {name}\n{frontmatter}---\n\n# Skill\n"),
+        )
+        .expect("failed to write SKILL.md");
+    }
+
+    let describe = |args: &[&str]| {
+        let output = workspace.run_skill(args);
+        assert!(output.status.success(), "{}", output_stderr(&output));
+        let payload: Value =
+            serde_json::from_str(&output_stdout(&output)).expect("failed to parse JSON output");
+        payload["skills"]
+            .as_array()
+            .expect("skills should be array")
+            .iter()
+            .map(|skill| {
+                (
+                    skill["name"].as_str().unwrap_or_default().to_owned(),
+                    skill["description"].as_str().unwrap_or_default().to_owned(),
+                )
+            })
+            .collect::<std::collections::BTreeMap<_, _>>()
+    };
+
+    let french = describe(&["list", "--format", "json", "--lang", "fr"]);
+    assert_eq!(french["localized-skill"], "Planifie le travail.");
+    assert_eq!(french["plain-skill"], "Stays in English.");
+    let default = describe(&["list", "--format", "json"]);
+    assert_eq!(default["localized-skill"], "Plans work.");
+
+    let valid = workspace.run_skill_validate(Some("localized-skill"));
+    assert!(valid.status.success(), "{}", output_stderr(&valid));
+}
+
+#[test]
+fn skill_list_marks_deprecated_skills_with_migration_hints() {
+    let workspace = TestWorkspace::new("skill-deprecated");
+    let skill_dir = workspace.path().join(".agents/skills/old-skill");
+    fs::create_dir_all(&skill_dir).expect("failed to create skill directory");
     fs::write(
-        workspace.path().join("rfc/0000-template.md"),
-        "+++\ncustom = true\n+++\n\n# custom template\n",
+        skill_dir.join("SKILL.md"),
+        "---\nname: old-skill\ndescription: Legacy flow.\ndeprecated: true\ndeprecation_note: Folded into ask-user-question.\nreplacement: ask-user-question\n---\n\n# Old Skill\n",
     )
-    .expect("failed to write custom template");
+    .expect("failed to write SKILL.md");
 
-    let output = workspace.run_rfc_init();
+    let json = workspace.run_skill(&["list", "--format", "json"]);
+    assert!(json.status.success(), "{}", output_stderr(&json));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&json)).expect("failed to parse JSON output");
+    let skills = payload["skills"]
+        .as_array()
+        .expect("skills should be array");
+    let old = skills
+        .iter()
+        .find(|skill| skill["name"] == "old-skill")
+        .expect("deprecated skill should be listed");
+    assert_eq!(old["deprecated"], true);
+    assert_eq!(old["deprecation"]["replacement"], "ask-user-question");
+    assert_eq!(old["deprecation"]["note"], "Folded into ask-user-question.");
+    let current = skills
+        .iter()
+        .find(|skill| skill["name"] == "ask-user-question")
+        .expect("builtin skill should be listed");
+    assert_eq!(current["deprecated"], false);
+    assert!(current.get("deprecation").is_none());
+
+    let text = workspace.run_skill(&["list"]);
+    assert!(text.status.success(), "{}", output_stderr(&text));
+    assert!(output_stdout(&text).contains("[deprecated] Legacy flow."));
+
+    let install = workspace.run_skill_install(&["ask-user-question"]);
+    assert!(install.status.success(), "{}", output_stderr(&install));
+    assert!(!output_stderr(&install).contains("deprecated"));
+}
+
+#[test]
+fn skill_list_sort_manifest_keeps_builtin_manifest_order() {
+    let workspace = TestWorkspace::new("skill-list-sort");
+    let skill_dir = workspace.path().join(".agents/skills/aaa-local");
+    fs::create_dir_all(&skill_dir).expect("failed to create skill directory");
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: aaa-local\ndescription: Workspace only.\n---\n\n# Local\n",
+    )
+    .expect("failed to write SKILL.md");
+    let manifest = fs::read_to_string(
+        Path::new(env!("CARGO_MANIFEST_DIR")).join(".agents/skills/builtin-manifest.toml"),
+    )
+    .expect("failed to read builtin manifest");
+    let manifest_names = manifest
+        .split('"')
+        .skip(1)
+        .step_by(2)
+        .map(str::to_owned)
+        .collect::<Vec<_>>();
+
+    let list = |args: &[&str]| {
+        let output = workspace.run_skill(args);
+        assert!(output.status.success(), "{}", output_stderr(&output));
+        let payload: Value =
+            serde_json::from_str(&output_stdout(&output)).expect("failed to parse JSON output");
+        payload["skills"]
+            .as_array()
+            .expect("skills should be array")
+            .clone()
+    };
+
+    let default = list(&["list", "--format", "json"]);
+    assert_eq!(default[0]["name"], "aaa-local");
+
+    let curated = list(&["list", "--format", "json", "--sort", "manifest"]);
+    let names = curated
+        .iter()
+        .map(|skill| skill["name"].as_str().unwrap_or_default().to_owned())
+        .collect::<Vec<_>>();
+    let mut expected = manifest_names.clone();
+    expected.push("aaa-local".to_owned());
+    assert_eq!(names, expected);
+    for (index, skill) in curated.iter().take(manifest_names.len()).enumerate() {
+        assert_eq!(skill["order"], index);
+    }
+    assert!(curated[manifest_names.len()].get("order").is_none());
+}
+
+#[test]
+fn skill_list_searches_skills_path_roots_in_precedence_order() {
+    let workspace = TestWorkspace::new("skill-search-path");
+    let skills = [
+        ("shared-root/shared", "shared", "From shared root."),
+        ("local-root/shared", "shared", "From local root."),
+        (
+            "shared-root/only-shared",
+            "only-shared",
+            "Only in shared root.",
+        ),
+    ];
+    for (dir, name, description) in skills {
+        let skill_dir = workspace.path().join(dir);
+        fs::create_dir_all(&skill_dir).expect("failed to create skill dir");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            format!("---\nname: {name}\ndescription: {description}\n---\n\n# Skill\n"),
+        )
+        .expect("failed to write SKILL.md");
+    }
+    let search_path = std::env::join_paths(["local-root", "shared-root"])
+        .expect("failed to join skills path")
+        .into_string()
+        .expect("skills path should be UTF-8");
+    let envs = [("AGX_SKILLS_PATH", search_path.as_str())];
+
+    let output = workspace.run_cli_with_env(
+        &["skill", "list", "--origin", "workspace", "--format", "json"],
+        &envs,
+    );
     assert!(
         output.status.success(),
-        "rfc init command failed:\n{}",
+        "skill list failed:\n{}",
         output_stderr(&output)
     );
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&output)).expect("failed to parse JSON output");
+    let skills = payload["skills"]
+        .as_array()
+        .expect("skills should be array");
+    assert_eq!(skills.len(), 2);
+    assert_eq!(skills[0]["name"], "only-shared");
+    assert_eq!(skills[0]["workspace_root"], "shared-root");
+    assert_eq!(skills[1]["name"], "shared");
+    assert_eq!(skills[1]["description"], "From local root.");
+    assert_eq!(skills[1]["workspace_root"], "local-root");
 
-    let template = fs::read_to_string(workspace.path().join("rfc/0000-template.md"))
-        .expect("failed to read template");
-    assert!(template.contains("custom template"));
-    assert!(!template.contains("## Future possibilities"));
+    let validate = workspace.run_cli_with_env(&["skill", "validate"], &envs);
+    assert!(validate.status.success(), "{}", output_stderr(&validate));
+    assert!(output_stdout(&validate).contains("validated 2 skill(s), 0 failed"));
 }
 
 #[test]
-fn skill_init_creates_skills_root_and_seeds_builtins() {
-    let workspace = TestWorkspace::new("skill-init");
-    assert!(!workspace.path().join(".agents").exists());
+fn skill_list_reads_description_from_referenced_file() {
+    let workspace = TestWorkspace::new("skill-description-file");
+    let skill_dir = workspace.path().join(".agents/skills/long-description");
+    fs::create_dir_all(&skill_dir).expect("failed to create skill dir");
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: long-description\ndescription: \"@description.md\"\n---\n\n# Long\n",
+    )
+    .expect("failed to write SKILL.md");
+    fs::write(
+        skill_dir.join("description.md"),
+        "Loaded from a sibling description file.\n",
+    )
+    .expect("failed to write description.md");
 
-    let output = workspace.run_skill_init();
+    let output = workspace.run_skill_list(&["--origin", "workspace", "--format", "json"]);
     assert!(
         output.status.success(),
-        "skill init command failed:\n{}",
+        "skill list failed:\n{}",
         output_stderr(&output)
     );
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&output)).expect("failed to parse JSON output");
+    assert_eq!(
+        payload["skills"][0]["description"],
+        "Loaded from a sibling description file."
+    );
+}
 
-    assert!(workspace.path().join(".agents/skills").is_dir());
+#[test]
+fn skill_new_prompt_expands_name_and_title_placeholders() {
+    let workspace = TestWorkspace::new("skill-new-prompt");
+    let output = workspace.run_skill(&[
+        "new",
+        "bug-triage",
+        "--prompt",
+        "Use ${name} to run {title} on \"new\" issues.",
+    ]);
     assert!(
+        output.status.success(),
+        "skill new failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let openai = fs::read_to_string(
         workspace
             .path()
-            .join(".agents/skills/ask-user-question/SKILL.md")
-            .is_file()
+            .join(".agents/skills/bug-triage/agents/openai.yaml"),
+    )
+    .expect("failed to read openai.yaml");
+    assert!(
+        openai.contains(
+            "default_prompt: \"Use $bug-triage to run Bug Triage on \\\"new\\\" issues.\""
+        )
     );
+
+    let empty = workspace.run_skill(&["new", "empty-prompt", "--prompt", "  "]);
     assert!(
-        workspace
-            .path()
-            .join(".agents/skills/new-rfc-skill-creation-skill/SKILL.md")
-            .is_file()
+        !empty.status.success(),
+        "empty prompt unexpectedly accepted"
     );
+    assert!(output_stderr(&empty).contains("`--prompt` cannot be empty"));
+}
+
+#[test]
+fn skill_validate_succeeds_for_initialized_skill() {
+    let workspace = TestWorkspace::new("skill-validate-ok");
+
+    let new_skill = workspace.run_skill_new("ask-user-question");
+    assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+
+    let validate = workspace.run_skill_validate(None);
     assert!(
-        workspace
-            .path()
-            .join(".agents/skills/new-rfc-skill-creation-skill/references/rfc-skill-template.md")
-            .is_file()
+        validate.status.success(),
+        "skill validate command failed:\n{}",
+        output_stderr(&validate)
     );
 
-    let stdout = output_stdout(&output);
-    assert!(stdout.contains("use the code agent"));
-    assert!(stdout.contains("RFC skills"));
-    assert!(stdout.contains("recommended prompt"));
-    assert!(stdout.contains("> Use $new-rfc-skill-creation-skill"));
-    assert!(stdout.contains("named `new-rfc`"));
-    assert!(stdout.contains("feedback"));
-    assert!(stdout.contains("copied recommended prompt to clipboard"));
+    let stdout = output_stdout(&validate);
+    assert!(stdout.contains("ok .agents/skills/ask-user-question"));
+    assert!(stdout.contains("validated 1 skill(s), 0 failed"));
 }
 
 #[test]
-fn skill_init_no_dump_creates_only_skills_root() {
-    let workspace = TestWorkspace::new("skill-init-no-dump");
-    assert!(!workspace.path().join(".agents").exists());
+fn skill_validate_check_skill_refs_warns_on_unknown_reference() {
+    let workspace = TestWorkspace::new("skill-validate-skill-refs");
+    let new_skill = workspace.run_skill_new("referrer");
+    assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+    fs::write(
+        workspace.path().join(".agents/skills/referrer/SKILL.md"),
+        "---\nname: referrer\ndescription: Refers to other skills.\n---\n\n# Referrer\n\nUse $ask-user-question first, then $missing-skill.\n",
+    )
+    .expect("failed to write SKILL.md");
 
-    let output = workspace.run_skill(&["init", "--no-dump"]);
+    let output = workspace.run_skill(&["validate", "--check-skill-refs"]);
     assert!(
         output.status.success(),
-        "skill init --no-dump command failed:\n{}",
+        "skill validate failed:\n{}",
         output_stderr(&output)
     );
 
-    assert!(workspace.path().join(".agents/skills").is_dir());
+    let stderr = output_stderr(&output);
+    assert!(stderr.contains("references unknown skill `$missing-skill`"));
+    assert!(!stderr.contains("$ask-user-question"));
+}
+
+#[test]
+fn skill_validate_check_requires_reports_missing_tools() {
+    let workspace = TestWorkspace::new("skill-validate-requires");
+    let skill_dir = workspace.path().join(".agents/skills/needs-tools");
+    fs::create_dir_all(&skill_dir).expect("failed to create skill directory");
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: needs-tools\ndescription: Needs external tools.\nrequires:\n  - agx-definitely-missing-tool\n---\n\n# Needs Tools\n",
+    )
+    .expect("failed to write SKILL.md");
+
+    let plain = workspace.run_skill_validate(Some("needs-tools"));
     assert!(
-        !workspace
-            .path()
-            .join(".agents/skills/ask-user-question/SKILL.md")
-            .exists()
+        plain.status.success(),
+        "requires should only be checked under the flag:\n{}",
+        output_stderr(&plain)
     );
+
+    let checked = workspace.run_skill(&["validate", "needs-tools", "--check-requires"]);
+    assert!(!checked.status.success(), "validate unexpectedly succeeded");
+    assert!(output_stderr(&checked).contains("`agx-definitely-missing-tool`"));
+}
+
+#[test]
+fn skill_validate_summary_counts_failures_in_mixed_run() {
+    let workspace = TestWorkspace::new("skill-validate-summary");
+    let new_skill = workspace.run_skill_new("good-skill");
+    assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+    let bad_skill = workspace.path().join(".agents/skills/bad-skill");
+    fs::create_dir_all(&bad_skill).expect("failed to create bad skill directory");
+    fs::write(
+        bad_skill.join("SKILL.md"),
+        "---\nname: bad-skill\n---\n\n# Bad Skill\n",
+    )
+    .expect("failed to write bad SKILL.md");
+
+    let text = workspace.run_skill_validate(None);
+    assert!(!text.status.success(), "validate unexpectedly succeeded");
+    assert!(output_stdout(&text).contains("validated 2 skill(s), 1 failed"));
+
+    let json = workspace.run_skill(&["validate", "--format", "json"]);
+    assert!(!json.status.success(), "validate unexpectedly succeeded");
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&json)).expect("failed to parse JSON output");
+    assert_eq!(payload["total"].as_u64(), Some(2));
+    assert_eq!(payload["passed"].as_u64(), Some(1));
+    assert_eq!(payload["failed"].as_u64(), Some(1));
+    assert_eq!(payload["results"][0]["skill"], "bad-skill");
+    assert_eq!(payload["results"][0]["ok"], false);
     assert!(
-        !workspace
-            .path()
-            .join(".agents/skills/new-rfc-skill-creation-skill/SKILL.md")
-            .exists()
+        payload["results"][0]["errors"][0]
+            .as_str()
+            .is_some_and(|error| error.contains("description"))
     );
+}
 
-    let stdout = output_stdout(&output);
-    assert!(stdout.contains("use the code agent"));
-    assert!(stdout.contains("RFC skills"));
-    assert!(stdout.contains("recommended prompt"));
-    assert!(stdout.contains("> Use $new-rfc-skill-creation-skill"));
-    assert!(stdout.contains("named `new-rfc`"));
-    assert!(stdout.contains("feedback"));
-    assert!(stdout.contains("copied recommended prompt to clipboard"));
+#[test]
+fn skill_list_and_validate_ndjson_emit_one_object_per_line() {
+    let workspace = TestWorkspace::new("skill-ndjson");
+    for name in ["first-skill", "second-skill"] {
+        let created = workspace.run_skill_new(name);
+        assert!(created.status.success(), "{}", output_stderr(&created));
+    }
+
+    let list = workspace.run_skill(&["list", "--origin", "workspace", "--format", "ndjson"]);
+    assert!(list.status.success(), "{}", output_stderr(&list));
+    let lines = output_stdout(&list)
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).expect("each line should be JSON"))
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0]["name"], "first-skill");
+    assert_eq!(lines[1]["name"], "second-skill");
+    assert_eq!(lines[2]["schema_version"].as_u64(), Some(1));
+    assert_eq!(lines[2]["total"].as_u64(), Some(2));
+
+    let validate = workspace.run_skill(&["validate", "--format", "ndjson"]);
+    assert!(validate.status.success(), "{}", output_stderr(&validate));
+    let lines = output_stdout(&validate)
+        .lines()
+        .map(|line| serde_json::from_str::<Value>(line).expect("each line should be JSON"))
+        .collect::<Vec<_>>();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(lines[0]["skill"], "first-skill");
+    assert_eq!(lines[0]["ok"], true);
+    assert_eq!(lines[0]["errors"], serde_json::json!([]));
+    assert_eq!(lines[2]["passed"].as_u64(), Some(2));
+    assert!(lines[2].get("results").is_none());
+}
+
+#[test]
+fn skill_validate_schema_flags_missing_required_key() {
+    let workspace = TestWorkspace::new("skill-validate-schema");
+    for (name, extra) in [("owned-skill", "owner: platform\n"), ("orphan-skill", "")] {
+        let skill_dir = workspace.path().join(".agents/skills").join(name);
+        fs::create_dir_all(&skill_dir).expect("failed to create skill directory");
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            format!("---\nname: {name}\ndescription: Schema checked.\n{extra}---\n\n# Skill\n"),
+        )
+        .expect("failed to write SKILL.md");
+    }
+    fs::write(
+        workspace.path().join("skill-schema.json"),
+        r#"{"type": "object", "required": ["name", "description", "owner"], "properties": {"owner": {"type": "string"}}}"#,
+    )
+    .expect("failed to write schema");
+
+    let output = workspace.run_skill(&[
+        "validate",
+        "--schema",
+        "skill-schema.json",
+        "--format",
+        "json",
+    ]);
+    assert!(!output.status.success(), "validate unexpectedly succeeded");
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&output)).expect("failed to parse JSON output");
+    assert_eq!(payload["failed"].as_u64(), Some(1));
+    let results = payload["results"]
+        .as_array()
+        .expect("results should be array");
+    let orphan = results
+        .iter()
+        .find(|result| result["skill"] == "orphan-skill")
+        .expect("orphan-skill should be reported");
+    assert_eq!(orphan["ok"], false);
+    assert!(
+        orphan["errors"][0]
+            .as_str()
+            .is_some_and(|error| error.contains("`/`") && error.contains("owner"))
+    );
+    let owned = results
+        .iter()
+        .find(|result| result["skill"] == "owned-skill")
+        .expect("owned-skill should be reported");
+    assert_eq!(owned["ok"], true);
+}
+
+#[test]
+fn skill_validate_accepts_crlf_and_check_eol_flags_it() {
+    let workspace = TestWorkspace::new("skill-validate-crlf");
+    let skill_dir = workspace.path().join(".agents/skills/windows-skill");
+    fs::create_dir_all(&skill_dir).expect("failed to create skill directory");
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\r\nname: windows-skill\r\ndescription: Authored with CRLF.\r\n---\r\n\r\n# Windows Skill\r\n",
+    )
+    .expect("failed to write SKILL.md");
+
+    let plain = workspace.run_skill_validate(Some("windows-skill"));
+    assert!(
+        plain.status.success(),
+        "CRLF SKILL.md should validate:\n{}",
+        output_stderr(&plain)
+    );
+
+    let checked = workspace.run_skill(&["validate", "windows-skill", "--check-eol"]);
+    assert!(!checked.status.success(), "validate unexpectedly succeeded");
+    assert!(output_stderr(&checked).contains("CRLF line endings found in `SKILL.md`"));
 }
 
 #[test]
-fn skill_new_scaffolds_named_skill() {
-    let workspace = TestWorkspace::new("skill-new");
+fn skill_validate_check_links_reports_missing_files_with_line_numbers() {
+    let workspace = TestWorkspace::new("skill-validate-links");
+    let skill_dir = workspace.path().join(".agents/skills/linked-skill");
+    fs::create_dir_all(skill_dir.join("references")).expect("failed to create skill directory");
+    fs::write(skill_dir.join("references/guide.md"), "# Guide\n").expect("failed to write guide");
+    fs::write(
+        skill_dir.join("SKILL.md"),
+        "---\nname: linked-skill\ndescription: Links to local files.\n---\n\n\
+# Linked Skill\n\n\
+Read [the guide](references/guide.md#setup) and [the spec](https://example.com/spec).\n\
+See [missing notes](references/notes.md) and [escape](../other/SKILL.md).\n\n\
+```md\n[ignored](references/example.md)\n```\n\n\
+[script]: scripts/run.sh\n",
+    )
+    .expect("failed to write SKILL.md");
 
-    let output = workspace.run_skill_new("ask-user-question");
+    let plain = workspace.run_skill_validate(Some("linked-skill"));
     assert!(
-        output.status.success(),
-        "skill new command failed:\n{}",
-        output_stderr(&output)
+        plain.status.success(),
+        "links are only checked with --check-links:\n{}",
+        output_stderr(&plain)
     );
 
-    let skill_dir = workspace.path().join(".agents/skills/ask-user-question");
-    assert!(skill_dir.is_dir());
-    assert!(skill_dir.join("agents").is_dir());
+    let checked = workspace.run_skill(&["validate", "linked-skill", "--check-links"]);
+    assert!(!checked.status.success(), "validate unexpectedly succeeded");
+    let stderr = output_stderr(&checked);
+    assert!(
+        stderr.contains("line 9: missing file `references/notes.md`"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("line 9: `../other/SKILL.md` points outside the skill directory"),
+        "{stderr}"
+    );
+    assert!(
+        stderr.contains("line 15: missing file `scripts/run.sh`"),
+        "{stderr}"
+    );
+    assert!(!stderr.contains("guide.md"), "{stderr}");
+    assert!(!stderr.contains("example"), "{stderr}");
+}
 
-    let skill_md = fs::read_to_string(skill_dir.join("SKILL.md")).expect("failed to read SKILL.md");
-    assert!(skill_md.contains("name: ask-user-question"));
-    assert!(skill_md.contains("description:"));
+#[test]
+fn skill_validate_json_collects_every_error_per_skill() {
+    let workspace = TestWorkspace::new("skill-validate-json-errors");
+    let new_skill = workspace.run_skill_new("messy-skill");
+    assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+    let skill_dir = workspace.path().join(".agents/skills/messy-skill");
+    fs::write(skill_dir.join("agents/notes.txt"), "scratch notes\r\n")
+        .expect("failed to write stray agents file");
 
-    let openai_yaml = fs::read_to_string(skill_dir.join("agents/openai.yaml"))
-        .expect("failed to read openai.yaml");
-    assert!(openai_yaml.contains("interface:"));
+    let output = workspace.run_skill(&["validate", "--check-eol", "--strict", "--format", "json"]);
+    assert!(!output.status.success(), "validate unexpectedly succeeded");
+    let payload: serde_json::Value =
+        serde_json::from_str(&output_stdout(&output)).expect("stdout should be JSON");
+    assert_eq!(payload["schema_version"], 1);
+    let result = &payload["results"][0];
+    assert_eq!(result["skill"], "messy-skill");
+    assert_eq!(result["ok"], false);
+    let errors = result["errors"]
+        .as_array()
+        .expect("errors should be an array");
+    assert_eq!(errors.len(), 2, "{errors:?}");
+    assert!(errors[0].as_str().unwrap().contains("CRLF line endings"));
+    assert!(errors[1].as_str().unwrap().contains("`notes.txt`"));
 }
 
 #[test]
-fn skill_validate_succeeds_for_initialized_skill() {
-    let workspace = TestWorkspace::new("skill-validate-ok");
-
-    let new_skill = workspace.run_skill_new("ask-user-question");
+fn skill_validate_strict_rejects_unknown_agent_files() {
+    let workspace = TestWorkspace::new("skill-validate-strict");
+    let new_skill = workspace.run_skill_new("tidy-skill");
     assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+    fs::write(
+        workspace
+            .path()
+            .join(".agents/skills/tidy-skill/agents/notes.txt"),
+        "scratch notes\n",
+    )
+    .expect("failed to write stray agents file");
 
-    let validate = workspace.run_skill_validate(None);
+    let plain = workspace.run_skill_validate(Some("tidy-skill"));
     assert!(
-        validate.status.success(),
-        "skill validate command failed:\n{}",
-        output_stderr(&validate)
+        plain.status.success(),
+        "non-strict validate should ignore extras:\n{}",
+        output_stderr(&plain)
     );
 
-    let stdout = output_stdout(&validate);
-    assert!(stdout.contains("ok .agents/skills/ask-user-question"));
-    assert!(stdout.contains("validated 1 skill(s)"));
+    let strict = workspace.run_skill(&["validate", "tidy-skill", "--strict"]);
+    assert!(
+        !strict.status.success(),
+        "strict validate unexpectedly succeeded"
+    );
+    assert!(output_stderr(&strict).contains("unexpected file(s) in `agents/`: `notes.txt`"));
 }
 
 #[test]
@@ -725,6 +4017,32 @@ fn skill_validate_rejects_invalid_skill() {
     assert!(stderr.contains("missing required `description`"));
 }
 
+#[test]
+fn skill_list_table_pads_columns_to_align() {
+    let workspace = TestWorkspace::new("skill-list-table");
+    let output = workspace.run_skill_list(&["--origin", "builtin", "--format", "table"]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+    let stdout = output_stdout(&output);
+    assert!(!stdout.contains('\t'));
+
+    let lines = stdout.lines().collect::<Vec<_>>();
+    assert!(lines[0].starts_with("name  "), "{stdout}");
+    let origin_column = lines[0]
+        .find("preferred_origin")
+        .expect("header should name every column");
+    assert!(lines[0].contains("  builtin_available  workspace_path  description"));
+    let row = lines
+        .iter()
+        .find(|line| line.starts_with("ask-user-question "))
+        .expect("ask-user-question row");
+    assert_eq!(row.find("builtin ").expect("origin column"), origin_column);
+    assert!(
+        lines[1..]
+            .iter()
+            .all(|line| line[origin_column..].starts_with("builtin "))
+    );
+}
+
 #[test]
 fn skill_list_builtin_json_includes_schema_and_expected_entries() {
     let workspace = TestWorkspace::new("skill-list-builtin-json");
@@ -786,6 +4104,86 @@ fn skill_list_all_prefers_workspace_when_name_collides() {
     );
 }
 
+fn listed_skill_names(output: &std::process::Output) -> Vec<String> {
+    let payload: Value =
+        serde_json::from_str(&output_stdout(output)).expect("failed to parse JSON output");
+    payload["skills"]
+        .as_array()
+        .expect("skills must be an array")
+        .iter()
+        .map(|entry| entry["name"].as_str().expect("name").to_owned())
+        .collect()
+}
+
+#[test]
+fn skill_list_installed_only_keeps_workspace_skills() {
+    let workspace = TestWorkspace::new("skill-list-installed-only");
+    for name in ["ask-user-question", "local-only"] {
+        let new_skill = workspace.run_skill_new(name);
+        assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+    }
+
+    let output =
+        workspace.run_skill_list(&["--origin", "all", "--installed-only", "--format", "json"]);
+    assert!(
+        output.status.success(),
+        "skill list command failed:\n{}",
+        output_stderr(&output)
+    );
+    assert_eq!(
+        listed_skill_names(&output),
+        vec!["ask-user-question", "local-only"]
+    );
+}
+
+#[test]
+fn skill_list_installed_only_matches_builtins_by_name() {
+    let workspace = TestWorkspace::new("skill-list-installed-builtin");
+    let new_skill = workspace.run_skill_new("ask-user-question");
+    assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+
+    let installed = workspace.run_skill_list(&[
+        "--origin",
+        "builtin",
+        "--installed-only",
+        "--format",
+        "json",
+    ]);
+    assert!(installed.status.success(), "{}", output_stderr(&installed));
+    assert_eq!(listed_skill_names(&installed), vec!["ask-user-question"]);
+
+    let available = workspace.run_skill_list(&[
+        "--origin",
+        "builtin",
+        "--available-only",
+        "--format",
+        "json",
+    ]);
+    assert!(available.status.success(), "{}", output_stderr(&available));
+    assert!(!listed_skill_names(&available).contains(&"ask-user-question".to_owned()));
+}
+
+#[test]
+fn skill_list_available_only_keeps_uninstalled_builtins() {
+    let workspace = TestWorkspace::new("skill-list-available-only");
+    for name in ["ask-user-question", "local-only"] {
+        let new_skill = workspace.run_skill_new(name);
+        assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+    }
+
+    let output =
+        workspace.run_skill_list(&["--origin", "all", "--available-only", "--format", "json"]);
+    assert!(
+        output.status.success(),
+        "skill list command failed:\n{}",
+        output_stderr(&output)
+    );
+    assert_eq!(
+        listed_skill_names(&output),
+        vec!["new-rfc-skill-creation-skill"]
+    );
+}
+
 #[test]
 fn skill_dump_all_writes_to_default_agents_skills_path() {
     let workspace = TestWorkspace::new("skill-dump-default");
@@ -861,6 +4259,26 @@ fn skill_install_json_outputs_installed_paths() {
     );
 }
 
+#[test]
+fn skill_install_print_prompts_emits_default_prompt_hints() {
+    let workspace = TestWorkspace::new("skill-install-print-prompts");
+    let output = workspace.run_skill_install(&[
+        "ask-user-question",
+        "--to",
+        "installed-skills",
+        "--print-prompts",
+    ]);
+
+    assert!(
+        output.status.success(),
+        "skill install command failed:\n{}",
+        output_stderr(&output)
+    );
+    assert!(output_stdout(&output).contains(
+        "hint: ask-user-question: Use $ask-user-question to ask me one focused question at a time"
+    ));
+}
+
 #[test]
 fn skill_install_refuses_conflict_without_force() {
     let workspace = TestWorkspace::new("skill-install-conflict");
@@ -883,6 +4301,120 @@ fn skill_install_refuses_conflict_without_force() {
     );
 }
 
+#[test]
+fn skill_install_respects_builtin_pins_unless_forced() {
+    let workspace = TestWorkspace::new("skill-install-pins");
+    let install = workspace.run_skill_install(&["ask-user-question"]);
+    assert!(install.status.success(), "{}", output_stderr(&install));
+    let skills_root = workspace.path().join(".agents/skills");
+    let pins_path = skills_root.join("builtin-pins.toml");
+    let pins = fs::read_to_string(&pins_path).expect("failed to read pins");
+    assert!(pins.contains("[ask-user-question]"));
+    assert!(pins.contains("checksum = \"sha256:"));
+
+    let validate = workspace.run_skill_validate(None);
+    assert!(validate.status.success(), "{}", output_stderr(&validate));
+
+    fs::write(
+        &pins_path,
+        "[ask-user-question]\nversion = \"0.0.1\"\nchecksum = \"sha256:old\"\n",
+    )
+    .expect("failed to write pins");
+    fs::remove_dir_all(skills_root.join("ask-user-question")).expect("failed to remove skill");
+
+    let pinned = workspace.run_skill_install(&["ask-user-question"]);
+    assert!(!pinned.status.success(), "install unexpectedly succeeded");
+    let stderr = output_stderr(&pinned);
+    assert!(stderr.contains("refusing to replace pinned skill(s) without --force"));
+    assert!(stderr.contains("`ask-user-question` is pinned to 0.0.1 (sha256:old)"));
+    assert!(!skills_root.join("ask-user-question").exists());
+
+    let forced = workspace.run_skill_install(&["ask-user-question", "--force"]);
+    assert!(forced.status.success(), "{}", output_stderr(&forced));
+    let pins = fs::read_to_string(&pins_path).expect("failed to read pins");
+    assert!(!pins.contains("sha256:old"));
+}
+
+#[test]
+fn skill_global_flag_targets_user_config_skills_dir() {
+    let workspace = TestWorkspace::new("skill-global");
+    let home = workspace.path().join("home");
+    fs::create_dir_all(&home).expect("failed to create home");
+    let home_str = home.to_str().expect("home should be UTF-8");
+    let envs = [("HOME", home_str), ("XDG_CONFIG_HOME", "")];
+    let global_root = if cfg!(target_os = "macos") {
+        home.join("Library/Application Support/agx/skills")
+    } else {
+        home.join(".config/agx/skills")
+    };
+
+    let install = workspace.run_cli_with_env(
+        &["skill", "install", "ask-user-question", "--global"],
+        &envs,
+    );
+    assert!(install.status.success(), "{}", output_stderr(&install));
+    assert!(global_root.join("ask-user-question/SKILL.md").is_file());
+    assert!(
+        !workspace
+            .path()
+            .join(".agents/skills/ask-user-question")
+            .exists()
+    );
+
+    let new_skill =
+        workspace.run_cli_with_env(&["skill", "new", "personal-notes", "--global"], &envs);
+    assert!(new_skill.status.success(), "{}", output_stderr(&new_skill));
+    assert!(global_root.join("personal-notes/SKILL.md").is_file());
+
+    let project_skill = workspace.run_skill_new("personal-notes");
+    assert!(
+        project_skill.status.success(),
+        "{}",
+        output_stderr(&project_skill)
+    );
+
+    let list = workspace.run_cli_with_env(
+        &[
+            "skill",
+            "list",
+            "--global",
+            "--origin",
+            "workspace",
+            "--format",
+            "json",
+        ],
+        &envs,
+    );
+    assert!(list.status.success(), "{}", output_stderr(&list));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&list)).expect("failed to parse JSON output");
+    let skills = payload["skills"]
+        .as_array()
+        .expect("skills should be array");
+    let path_of = |name: &str| {
+        skills
+            .iter()
+            .find(|skill| skill["name"] == name)
+            .and_then(|skill| skill["workspace_path"].as_str())
+            .map(ToOwned::to_owned)
+            .unwrap_or_else(|| panic!("`{name}` should be listed"))
+    };
+    assert_eq!(
+        path_of("ask-user-question"),
+        global_root.join("ask-user-question").to_string_lossy()
+    );
+    assert_eq!(path_of("personal-notes"), ".agents/skills/personal-notes");
+
+    let conflicting = workspace.run_cli_with_env(
+        &["skill", "dump", "--all", "--global", "--to", "elsewhere"],
+        &envs,
+    );
+    assert!(
+        !conflicting.status.success(),
+        "--global with --to unexpectedly succeeded"
+    );
+}
+
 #[test]
 fn skill_export_writes_tarball_with_expected_layout() {
     let workspace = TestWorkspace::new("skill-export");
@@ -938,3 +4470,320 @@ fn skill_export_writes_tarball_with_expected_layout() {
         "expected bundled reference file in archive layout"
     );
 }
+
+#[test]
+fn skill_selftest_round_trips_shipped_catalog() {
+    let workspace = TestWorkspace::new("skill-selftest");
+    let output = workspace.run_skill(&["selftest"]);
+    assert!(
+        output.status.success(),
+        "skill selftest failed:\n{}",
+        output_stderr(&output)
+    );
+    assert!(output_stdout(&output).contains("through dump and export"));
+    assert!(!workspace.path().join(".agents/skills").exists());
+}
+
+#[test]
+fn skill_catalog_digest_is_stable_and_matches_json() {
+    let workspace = TestWorkspace::new("skill-catalog");
+    let first = workspace.run_skill(&["catalog", "--digest"]);
+    assert!(first.status.success(), "{}", output_stderr(&first));
+    let second = workspace.run_skill(&["catalog", "--digest"]);
+    let digest = output_stdout(&first).trim().to_owned();
+    assert_eq!(digest, output_stdout(&second).trim());
+    assert!(
+        digest.starts_with("sha256:") && digest.len() == 71,
+        "{digest}"
+    );
+
+    let json = workspace.run_skill(&["catalog", "--format", "json"]);
+    assert!(json.status.success(), "{}", output_stderr(&json));
+    let payload: Value = serde_json::from_str(&output_stdout(&json)).expect("invalid JSON");
+    assert_eq!(payload["schema_version"], 1);
+    assert_eq!(payload["digest"], digest.as_str());
+    let names = payload["skills"]
+        .as_array()
+        .expect("skills array")
+        .iter()
+        .map(|skill| skill["name"].as_str().expect("skill name"))
+        .collect::<Vec<_>>();
+    assert!(names.contains(&"ask-user-question"));
+    assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+}
+
+#[test]
+fn skill_export_since_manifest_skips_unchanged_skills() {
+    let workspace = TestWorkspace::new("skill-export-since");
+    let manifest = workspace.run_skill_export(&["--manifest-only", "--output", "dist/prior.json"]);
+    assert!(manifest.status.success(), "{}", output_stderr(&manifest));
+
+    let output = workspace.run_skill_export(&[
+        "--since",
+        "dist/prior.json",
+        "--output",
+        "dist/changed.tar.gz",
+    ]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+    let stdout = output_stdout(&output);
+    assert!(
+        stdout.contains("unchanged since dist/prior.json: ask-user-question, "),
+        "{stdout}"
+    );
+    let archive_file = fs::File::open(workspace.path().join("dist/changed.tar.gz"))
+        .expect("failed to open archive");
+    let mut archive = Archive::new(GzDecoder::new(archive_file));
+    assert_eq!(
+        archive
+            .entries()
+            .expect("failed to read archive entries")
+            .count(),
+        0
+    );
+
+    let prior_path = workspace.path().join("dist/prior.json");
+    let mut prior: Value =
+        serde_json::from_slice(&fs::read(&prior_path).expect("failed to read manifest"))
+            .expect("failed to parse manifest");
+    prior["skills"][0]["files"][0]["sha256"] = Value::from("0".repeat(64));
+    let changed_name = prior["skills"][0]["name"]
+        .as_str()
+        .expect("skill name")
+        .to_owned();
+    fs::write(&prior_path, prior.to_string()).expect("failed to write manifest");
+
+    let output = workspace.run_skill_export(&[
+        "--since",
+        "dist/prior.json",
+        "--manifest-only",
+        "--output",
+        "dist/changed.json",
+    ]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+    let changed: Value = serde_json::from_slice(
+        &fs::read(workspace.path().join("dist/changed.json")).expect("failed to read manifest"),
+    )
+    .expect("failed to parse manifest");
+    let skills = changed["skills"].as_array().expect("skills array");
+    assert_eq!(skills.len(), 1);
+    assert_eq!(skills[0]["name"], changed_name.as_str());
+}
+
+#[test]
+fn skill_export_tag_filters_to_tagged_builtins() {
+    let workspace = TestWorkspace::new("skill-export-tag");
+    let output = workspace.run_skill_export(&["--tag", "rfc", "--output", "dist/rfc.tar.gz"]);
+    assert!(output.status.success(), "{}", output_stderr(&output));
+
+    let archive_file =
+        fs::File::open(workspace.path().join("dist/rfc.tar.gz")).expect("failed to open archive");
+    let mut archive = Archive::new(GzDecoder::new(archive_file));
+    let skills = archive
+        .entries()
+        .expect("failed to read archive entries")
+        .filter_map(|entry| {
+            let entry = entry.expect("failed to read archive entry");
+            let path = entry
+                .path()
+                .expect("entry path should be valid")
+                .into_owned();
+            path.strip_prefix(".agents/skills")
+                .ok()
+                .and_then(|relative| relative.iter().next())
+                .map(|name| name.to_string_lossy().into_owned())
+        })
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(
+        skills.into_iter().collect::<Vec<_>>(),
+        vec!["new-rfc-skill-creation-skill"]
+    );
+
+    let list = workspace.run_skill_list(&["--origin", "builtin", "--format", "json"]);
+    assert!(list.status.success(), "{}", output_stderr(&list));
+    let payload: Value =
+        serde_json::from_str(&output_stdout(&list)).expect("failed to parse JSON output");
+    let tags = |name: &str| {
+        payload["skills"]
+            .as_array()
+            .and_then(|skills| skills.iter().find(|skill| skill["name"] == name))
+            .map(|skill| skill["tags"].clone())
+    };
+    assert_eq!(
+        tags("new-rfc-skill-creation-skill"),
+        Some(serde_json::json!(["rfc", "authoring"]))
+    );
+    assert_eq!(
+        tags("ask-user-question"),
+        Some(serde_json::json!(["planning"]))
+    );
+
+    let missing = workspace.run_skill_export(&["--tag", "nope", "--output", "dist/none.tar.gz"]);
+    assert!(!missing.status.success(), "command unexpectedly succeeded");
+    assert!(output_stderr(&missing).contains("no built-in skills are tagged with `nope`"));
+}
+
+#[test]
+fn skill_export_manifest_only_lists_builtins_without_contents() {
+    let workspace = TestWorkspace::new("skill-export-manifest");
+    let output = workspace.run_skill_export(&["--manifest-only", "--output", "dist/manifest.json"]);
+    assert!(
+        output.status.success(),
+        "skill export --manifest-only failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let manifest: Value = serde_json::from_slice(
+        &fs::read(workspace.path().join("dist/manifest.json")).expect("failed to read manifest"),
+    )
+    .expect("failed to parse manifest");
+    assert_eq!(manifest["schema_version"].as_u64(), Some(1));
+    let names = manifest["skills"]
+        .as_array()
+        .expect("skills should be an array")
+        .iter()
+        .map(|skill| skill["name"].as_str().expect("name should be a string"))
+        .collect::<Vec<_>>();
+
+    let list = workspace.run_skill(&["list", "--origin", "builtin", "--format", "json"]);
+    let builtins: Value =
+        serde_json::from_str(&output_stdout(&list)).expect("failed to parse JSON output");
+    let builtin_names = builtins["skills"]
+        .as_array()
+        .expect("skills should be an array")
+        .iter()
+        .map(|skill| skill["name"].as_str().expect("name should be a string"))
+        .collect::<Vec<_>>();
+    assert_eq!(names, builtin_names);
+
+    for skill in manifest["skills"]
+        .as_array()
+        .expect("skills should be an array")
+    {
+        for file in skill["files"].as_array().expect("files should be an array") {
+            let file = file.as_object().expect("file should be an object");
+            assert!(!file.contains_key("content"));
+            assert!(
+                file["path"]
+                    .as_str()
+                    .is_some_and(|path| path.starts_with(".agents/skills/"))
+            );
+            assert_eq!(file["sha256"].as_str().map(str::len), Some(64));
+        }
+    }
+}
+
+#[test]
+fn skill_export_oci_writes_index_and_referenced_blobs() {
+    let workspace = TestWorkspace::new("skill-export-oci");
+    let output = workspace.run_skill_export(&["--format", "oci", "--output", "dist/oci"]);
+    assert!(
+        output.status.success(),
+        "skill export --format oci failed:\n{}",
+        output_stderr(&output)
+    );
+
+    let layout = workspace.path().join("dist/oci");
+    assert!(layout.join("oci-layout").is_file());
+    let blob = |digest: &str| {
+        let hex = digest
+            .strip_prefix("sha256:")
+            .expect("digest should be sha256");
+        let path = layout.join("blobs/sha256").join(hex);
+        assert!(path.is_file(), "missing blob {digest}");
+        fs::read(path).expect("failed to read blob")
+    };
+
+    let index: Value = serde_json::from_slice(
+        &fs::read(layout.join("index.json")).expect("failed to read index.json"),
+    )
+    .expect("failed to parse index.json");
+    assert_eq!(index["schemaVersion"].as_u64(), Some(2));
+    let manifests = index["manifests"]
+        .as_array()
+        .expect("manifests should be an array");
+    assert!(manifests.iter().any(|descriptor| {
+        descriptor["annotations"]["org.opencontainers.image.ref.name"] == "ask-user-question"
+    }));
+
+    for descriptor in manifests {
+        let bytes = blob(
+            descriptor["digest"]
+                .as_str()
+                .expect("digest should be a string"),
+        );
+        assert_eq!(descriptor["size"].as_u64(), Some(bytes.len() as u64));
+        let manifest: Value = serde_json::from_slice(&bytes).expect("failed to parse manifest");
+        blob(
+            manifest["config"]["digest"]
+                .as_str()
+                .expect("config digest"),
+        );
+        let layers = manifest["layers"]
+            .as_array()
+            .expect("layers should be an array");
+        assert_eq!(layers.len(), 1);
+        let layer = blob(layers[0]["digest"].as_str().expect("layer digest"));
+        let mut archive = Archive::new(GzDecoder::new(layer.as_slice()));
+        assert!(
+            archive
+                .entries()
+                .expect("failed to read layer entries")
+                .any(|entry| entry
+                    .expect("failed to read layer entry")
+                    .path()
+                    .expect("entry path should be valid")
+                    .ends_with("SKILL.md"))
+        );
+    }
+}
+
+#[test]
+fn skill_export_split_writes_one_archive_per_skill() {
+    let workspace = TestWorkspace::new("skill-export-split");
+    let output = workspace.run_skill_export(&["--split", "--output-dir", "dist/skills"]);
+
+    assert!(
+        output.status.success(),
+        "skill export --split failed:\n{}",
+        output_stderr(&output)
+    );
+
+    for name in ["ask-user-question", "new-rfc-skill-creation-skill"] {
+        let archive_path = workspace.path().join(format!("dist/skills/{name}.tar.gz"));
+        assert!(archive_path.is_file(), "missing archive for {name}");
+
+        let archive_file = fs::File::open(&archive_path).expect("failed to open archive");
+        let mut archive = Archive::new(GzDecoder::new(archive_file));
+        let paths = archive
+            .entries()
+            .expect("failed to read archive entries")
+            .map(|entry| {
+                entry
+                    .expect("failed to read archive entry")
+                    .path()
+                    .expect("entry path should be valid")
+                    .to_string_lossy()
+                    .into_owned()
+            })
+            .collect::<Vec<_>>();
+
+        let prefix = format!(".agents/skills/{name}/");
+        assert!(paths.contains(&format!("{prefix}SKILL.md")));
+        assert!(paths.iter().all(|path| path.starts_with(&prefix)));
+    }
+}
+
+#[test]
+fn skill_export_split_conflicts_with_single_output() {
+    let workspace = TestWorkspace::new("skill-export-split-conflict");
+    let output = workspace.run_skill_export(&[
+        "--split",
+        "--output-dir",
+        "dist/skills",
+        "--output",
+        "dist/all.tar.gz",
+    ]);
+
+    assert!(!output.status.success(), "export unexpectedly succeeded");
+    assert!(output_stderr(&output).contains("cannot be used with"));
+}